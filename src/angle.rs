@@ -0,0 +1,237 @@
+use crate::math;
+use crate::math::{ cos, sin, tan, PI, TAU };
+
+// Typed angles. Bare `f32` angles elsewhere in the crate are radians; rotation constructors
+// take `impl Into<Radians>`, so they accept `Radians`, `Degrees` or a plain `f32`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Radians(pub f32);
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Degrees(pub f32);
+
+// Wraps `value` into [0, period). Rounding can land exactly on `period` for tiny negative
+// inputs, which is folded back to 0.
+#[inline(always)]
+fn wrap_positive(value: f32, period: f32) -> f32
+{
+    let wrapped = value - period * math::floor(value / period);
+    if wrapped >= period { 0.0 } else { wrapped }
+}
+
+// Wraps `value` into (-period / 2, period / 2].
+#[inline(always)]
+fn wrap_signed(value: f32, period: f32) -> f32
+{
+    let wrapped = wrap_positive(value, period);
+    if wrapped > period * 0.5 { wrapped - period } else { wrapped }
+}
+
+impl Radians
+{
+    pub const PI: Radians = Radians(PI);
+    pub const TAU: Radians = Radians(TAU);
+
+    #[inline(always)]
+    pub fn new(value: f32) -> Radians
+    {
+        Radians(value)
+    }
+
+    #[inline(always)]
+    pub fn to_degrees(&self) -> Degrees
+    {
+        Degrees(math::radians_to_degrees(self.0))
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Radians) -> Radians
+    {
+        Radians(self.0 + other.0)
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, other: &Radians) -> Radians
+    {
+        Radians(self.0 - other.0)
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Radians
+    {
+        Radians(self.0 * scale)
+    }
+
+    #[inline(always)]
+    pub fn div_scale(&self, scale: f32) -> Radians
+    {
+        Radians(self.0 / scale)
+    }
+
+    #[inline(always)]
+    pub fn neg(&self) -> Radians
+    {
+        Radians(-self.0)
+    }
+
+    // The equivalent angle in (-pi, pi].
+    #[inline(always)]
+    pub fn wrap_signed(&self) -> Radians
+    {
+        Radians(wrap_signed(self.0, TAU))
+    }
+
+    // The equivalent angle in [0, 2pi).
+    #[inline(always)]
+    pub fn wrap_positive(&self) -> Radians
+    {
+        Radians(wrap_positive(self.0, TAU))
+    }
+
+    // The signed rotation in (-pi, pi] that takes `self` to `to` the short way round.
+    #[inline(always)]
+    pub fn shortest_difference(&self, to: &Radians) -> Radians
+    {
+        to.sub(self).wrap_signed()
+    }
+
+    // Interpolates along the shorter arc; the result is wrapped to (-pi, pi].
+    #[inline(always)]
+    pub fn lerp_short(&self, to: &Radians, t: f32) -> Radians
+    {
+        self.add(&self.shortest_difference(to).mul_scale(t)).wrap_signed()
+    }
+
+    #[inline(always)]
+    pub fn sin(&self) -> f32
+    {
+        sin(self.0)
+    }
+
+    #[inline(always)]
+    pub fn cos(&self) -> f32
+    {
+        cos(self.0)
+    }
+
+    #[inline(always)]
+    pub fn tan(&self) -> f32
+    {
+        tan(self.0)
+    }
+}
+
+impl Degrees
+{
+    #[inline(always)]
+    pub fn new(value: f32) -> Degrees
+    {
+        Degrees(value)
+    }
+
+    #[inline(always)]
+    pub fn to_radians(&self) -> Radians
+    {
+        Radians(math::degrees_to_radians(self.0))
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Degrees) -> Degrees
+    {
+        Degrees(self.0 + other.0)
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, other: &Degrees) -> Degrees
+    {
+        Degrees(self.0 - other.0)
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Degrees
+    {
+        Degrees(self.0 * scale)
+    }
+
+    #[inline(always)]
+    pub fn div_scale(&self, scale: f32) -> Degrees
+    {
+        Degrees(self.0 / scale)
+    }
+
+    #[inline(always)]
+    pub fn neg(&self) -> Degrees
+    {
+        Degrees(-self.0)
+    }
+
+    // The equivalent angle in (-180, 180].
+    #[inline(always)]
+    pub fn wrap_signed(&self) -> Degrees
+    {
+        Degrees(wrap_signed(self.0, 360.0))
+    }
+
+    // The equivalent angle in [0, 360).
+    #[inline(always)]
+    pub fn wrap_positive(&self) -> Degrees
+    {
+        Degrees(wrap_positive(self.0, 360.0))
+    }
+
+    #[inline(always)]
+    pub fn shortest_difference(&self, to: &Degrees) -> Degrees
+    {
+        to.sub(self).wrap_signed()
+    }
+
+    #[inline(always)]
+    pub fn lerp_short(&self, to: &Degrees, t: f32) -> Degrees
+    {
+        self.add(&self.shortest_difference(to).mul_scale(t)).wrap_signed()
+    }
+
+    #[inline(always)]
+    pub fn sin(&self) -> f32
+    {
+        self.to_radians().sin()
+    }
+
+    #[inline(always)]
+    pub fn cos(&self) -> f32
+    {
+        self.to_radians().cos()
+    }
+
+    #[inline(always)]
+    pub fn tan(&self) -> f32
+    {
+        self.to_radians().tan()
+    }
+}
+
+impl From<f32> for Radians
+{
+    #[inline(always)]
+    fn from(value: f32) -> Radians
+    {
+        Radians(value)
+    }
+}
+
+impl From<Degrees> for Radians
+{
+    #[inline(always)]
+    fn from(degrees: Degrees) -> Radians
+    {
+        degrees.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees
+{
+    #[inline(always)]
+    fn from(radians: Radians) -> Degrees
+    {
+        radians.to_degrees()
+    }
+}
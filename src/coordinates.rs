@@ -0,0 +1,253 @@
+use crate::error::VectoriaError;
+use crate::geometry::{ Point, Point3D };
+use crate::math::{ atan2_f64, cos_f64, sin_f64, sqrt_f64 };
+use crate::vector::{ Vec2, Vec3 };
+
+// Angular coordinate systems. All of them are stored in f64 so converting from `Point` and
+// `Point3D` is lossless, and angles are in radians.
+//
+// Conventions, with z up:
+// - azimuth (and the polar `angle`) is measured in the x-y plane from +x towards +y and
+//   lies in (-pi, pi];
+// - elevation is measured from the x-y plane towards +z and lies in [-pi/2, pi/2].
+//
+// At singular points the undefined angles are reported as 0: the polar angle and the
+// azimuth at the origin and on the z axis, the elevation at the origin.
+
+// Azimuth of (x, y), defined as 0 at the origin so that -0.0 inputs do not produce -pi.
+#[inline(always)]
+fn azimuth(x: f64, y: f64) -> f64
+{
+    if x == 0.0 && y == 0.0 { 0.0 } else { atan2_f64(y, x) }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Polar
+{
+    pub radius: f64,
+    pub angle: f64,
+}
+
+impl Polar
+{
+    pub fn new(radius: f64, angle: f64) -> Polar
+    {
+        Polar { radius, angle }
+    }
+
+    pub fn from_cartesian(x: f64, y: f64) -> Polar
+    {
+        Polar::new(sqrt_f64(x * x + y * y), azimuth(x, y))
+    }
+
+    pub fn to_cartesian(&self) -> (f64, f64)
+    {
+        (self.radius * cos_f64(self.angle), self.radius * sin_f64(self.angle))
+    }
+
+    pub fn from_vec2(v: &Vec2) -> Polar
+    {
+        Polar::from_cartesian(v.x as f64, v.y as f64)
+    }
+
+    pub fn to_vec2(&self) -> Vec2
+    {
+        let (x, y) = self.to_cartesian();
+        Vec2::new(x as f32, y as f32)
+    }
+
+    pub fn from_point(p: &Point) -> Polar
+    {
+        Polar::from_cartesian(p.x, p.y)
+    }
+
+    pub fn to_point(&self) -> Point
+    {
+        let (x, y) = self.to_cartesian();
+        Point::new(x, y)
+    }
+
+    // d(x, y) / d(radius, angle); rows are x and y.
+    pub fn jacobian_to_cartesian(&self) -> [[f64; 2]; 2]
+    {
+        let (s, c) = (sin_f64(self.angle), cos_f64(self.angle));
+        [
+            [c, -self.radius * s],
+            [s, self.radius * c],
+        ]
+    }
+
+    // d(radius, angle) / d(x, y); undefined at the origin.
+    pub fn jacobian_from_cartesian(&self) -> Result<[[f64; 2]; 2], VectoriaError>
+    {
+        if self.radius == 0.0
+        {
+            return Err(VectoriaError::Singular);
+        }
+        let (s, c) = (sin_f64(self.angle), cos_f64(self.angle));
+        Ok([
+            [c, s],
+            [-s / self.radius, c / self.radius],
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spherical
+{
+    pub radius: f64,
+    pub azimuth: f64,
+    pub elevation: f64,
+}
+
+impl Spherical
+{
+    pub fn new(radius: f64, azimuth: f64, elevation: f64) -> Spherical
+    {
+        Spherical { radius, azimuth, elevation }
+    }
+
+    pub fn from_cartesian(x: f64, y: f64, z: f64) -> Spherical
+    {
+        let planar = sqrt_f64(x * x + y * y);
+        let elevation = if planar == 0.0 && z == 0.0 { 0.0 } else { atan2_f64(z, planar) };
+        Spherical::new(sqrt_f64(x * x + y * y + z * z), azimuth(x, y), elevation)
+    }
+
+    pub fn to_cartesian(&self) -> (f64, f64, f64)
+    {
+        let planar = self.radius * cos_f64(self.elevation);
+        (
+            planar * cos_f64(self.azimuth),
+            planar * sin_f64(self.azimuth),
+            self.radius * sin_f64(self.elevation),
+        )
+    }
+
+    pub fn from_vec3(v: &Vec3) -> Spherical
+    {
+        Spherical::from_cartesian(v.x as f64, v.y as f64, v.z as f64)
+    }
+
+    pub fn to_vec3(&self) -> Vec3
+    {
+        let (x, y, z) = self.to_cartesian();
+        Vec3::new(x as f32, y as f32, z as f32)
+    }
+
+    pub fn from_point3d(p: &Point3D) -> Spherical
+    {
+        Spherical::from_cartesian(p.x, p.y, p.z)
+    }
+
+    pub fn to_point3d(&self) -> Point3D
+    {
+        let (x, y, z) = self.to_cartesian();
+        Point3D::new(x, y, z)
+    }
+
+    // d(x, y, z) / d(radius, azimuth, elevation); rows are x, y and z.
+    pub fn jacobian_to_cartesian(&self) -> [[f64; 3]; 3]
+    {
+        let (sa, ca) = (sin_f64(self.azimuth), cos_f64(self.azimuth));
+        let (se, ce) = (sin_f64(self.elevation), cos_f64(self.elevation));
+        let r = self.radius;
+        [
+            [ce * ca, -r * ce * sa, -r * se * ca],
+            [ce * sa, r * ce * ca, -r * se * sa],
+            [se, 0.0, r * ce],
+        ]
+    }
+
+    // d(radius, azimuth, elevation) / d(x, y, z); undefined on the z axis.
+    pub fn jacobian_from_cartesian(&self) -> Result<[[f64; 3]; 3], VectoriaError>
+    {
+        let (sa, ca) = (sin_f64(self.azimuth), cos_f64(self.azimuth));
+        let (se, ce) = (sin_f64(self.elevation), cos_f64(self.elevation));
+        let r = self.radius;
+        let planar = r * ce;
+        if planar.abs() < f64::EPSILON * r.abs().max(1.0)
+        {
+            return Err(VectoriaError::Singular);
+        }
+        Ok([
+            [ce * ca, ce * sa, se],
+            [-sa / planar, ca / planar, 0.0],
+            [-se * ca / r, -se * sa / r, ce / r],
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylindrical
+{
+    pub radius: f64,
+    pub azimuth: f64,
+    pub height: f64,
+}
+
+impl Cylindrical
+{
+    pub fn new(radius: f64, azimuth: f64, height: f64) -> Cylindrical
+    {
+        Cylindrical { radius, azimuth, height }
+    }
+
+    pub fn from_cartesian(x: f64, y: f64, z: f64) -> Cylindrical
+    {
+        Cylindrical::new(sqrt_f64(x * x + y * y), azimuth(x, y), z)
+    }
+
+    pub fn to_cartesian(&self) -> (f64, f64, f64)
+    {
+        (self.radius * cos_f64(self.azimuth), self.radius * sin_f64(self.azimuth), self.height)
+    }
+
+    pub fn from_vec3(v: &Vec3) -> Cylindrical
+    {
+        Cylindrical::from_cartesian(v.x as f64, v.y as f64, v.z as f64)
+    }
+
+    pub fn to_vec3(&self) -> Vec3
+    {
+        let (x, y, z) = self.to_cartesian();
+        Vec3::new(x as f32, y as f32, z as f32)
+    }
+
+    pub fn from_point3d(p: &Point3D) -> Cylindrical
+    {
+        Cylindrical::from_cartesian(p.x, p.y, p.z)
+    }
+
+    pub fn to_point3d(&self) -> Point3D
+    {
+        let (x, y, z) = self.to_cartesian();
+        Point3D::new(x, y, z)
+    }
+
+    // d(x, y, z) / d(radius, azimuth, height); rows are x, y and z.
+    pub fn jacobian_to_cartesian(&self) -> [[f64; 3]; 3]
+    {
+        let (s, c) = (sin_f64(self.azimuth), cos_f64(self.azimuth));
+        [
+            [c, -self.radius * s, 0.0],
+            [s, self.radius * c, 0.0],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+
+    // d(radius, azimuth, height) / d(x, y, z); undefined on the z axis.
+    pub fn jacobian_from_cartesian(&self) -> Result<[[f64; 3]; 3], VectoriaError>
+    {
+        if self.radius == 0.0
+        {
+            return Err(VectoriaError::Singular);
+        }
+        let (s, c) = (sin_f64(self.azimuth), cos_f64(self.azimuth));
+        Ok([
+            [c, s, 0.0],
+            [-s / self.radius, c / self.radius, 0.0],
+            [0.0, 0.0, 1.0],
+        ])
+    }
+}
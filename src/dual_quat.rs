@@ -0,0 +1,207 @@
+use crate::error::{ check_len, VectoriaError };
+use crate::math;
+use crate::math::{ acos, cos, sin, EPSILON };
+use crate::matrix::{ Mat3, Mat4 };
+use crate::quat::Quat;
+use crate::vector::Vec3;
+
+// A dual quaternion `real + ε dual` describing a rigid transform. For a rotation `r`
+// followed by a translation `t` the parts are `real = r` and `dual = ½ t r`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DualQuat
+{
+    pub real: Quat,
+    pub dual: Quat,
+}
+
+impl DualQuat
+{
+    #[inline(always)]
+    pub fn new(real: Quat, dual: Quat) -> DualQuat
+    {
+        DualQuat { real, dual }
+    }
+
+    #[inline(always)]
+    pub fn identity() -> DualQuat
+    {
+        DualQuat::new(Quat::identity(), Quat::new(0.0, 0.0, 0.0, 0.0))
+    }
+
+    pub fn from_rotation_translation(rotation: &Quat, translation: &Vec3) -> DualQuat
+    {
+        let real = rotation.normalize();
+        let dual = Quat::from_vec3(translation).mul(&real).mul_scale(0.5);
+        DualQuat::new(real, dual)
+    }
+
+    #[inline(always)]
+    pub fn from_translation(translation: &Vec3) -> DualQuat
+    {
+        DualQuat::from_rotation_translation(&Quat::identity(), translation)
+    }
+
+    // Reads a rigid transform from `m`. The upper-left block must be a rotation; scale
+    // and shear cannot be represented and are rejected as `Singular`.
+    pub fn from_mat4(m: &Mat4) -> Result<DualQuat, VectoriaError>
+    {
+        let basis = Mat3::from_mat4(m);
+        let det = basis.determinant();
+        if !det.is_finite()
+        {
+            return Err(VectoriaError::NotFinite);
+        }
+        // Unit, pairwise orthogonal columns (`B^T B = I`) with a positive determinant; the
+        // determinant alone lets through scales and shears that preserve volume.
+        let orthonormal = (0..3).all(|i| {
+            (0..3).all(|j| {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (basis.column(i).dot(&basis.column(j)) - expected).abs() <= 1e-3
+            })
+        });
+        if !orthonormal || det <= 0.0
+        {
+            return Err(VectoriaError::Singular);
+        }
+        let translation = Vec3::new(m.data[0][3], m.data[1][3], m.data[2][3]);
+        Ok(DualQuat::from_rotation_translation(&Quat::from_mat3(&basis), &translation))
+    }
+
+    pub fn to_mat4(&self) -> Mat4
+    {
+        let mut m = Mat4::from_mat3(&self.rotation().to_mat3());
+        let t = self.translation();
+        m.data[0][3] = t.x;
+        m.data[1][3] = t.y;
+        m.data[2][3] = t.z;
+        m
+    }
+
+    #[inline(always)]
+    pub fn rotation(&self) -> Quat
+    {
+        self.real
+    }
+
+    #[inline(always)]
+    pub fn translation(&self) -> Vec3
+    {
+        self.dual.mul(&self.real.conjugate()).mul_scale(2.0).vector()
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &DualQuat) -> DualQuat
+    {
+        DualQuat::new(self.real.add(&other.real), self.dual.add(&other.dual))
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> DualQuat
+    {
+        DualQuat::new(self.real.mul_scale(scale), self.dual.mul_scale(scale))
+    }
+
+    // Composition; like matrices, `a.mul(&b)` applies `b` first, then `a`.
+    #[inline(always)]
+    pub fn mul(&self, other: &DualQuat) -> DualQuat
+    {
+        DualQuat::new(
+            self.real.mul(&other.real),
+            self.real.mul(&other.dual).add(&self.dual.mul(&other.real)),
+        )
+    }
+
+    // Inverse of a unit dual quaternion, which is its quaternion conjugate.
+    #[inline(always)]
+    pub fn inverse(&self) -> DualQuat
+    {
+        DualQuat::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    // Rescales to unit length and removes the part of `dual` that is not orthogonal to
+    // `real`, so the result is again a rigid transform.
+    pub fn normalize(&self) -> Result<DualQuat, VectoriaError>
+    {
+        let len = self.real.length();
+        if !len.is_finite()
+        {
+            return Err(VectoriaError::NotFinite);
+        }
+        if len < EPSILON
+        {
+            return Err(VectoriaError::ZeroLength);
+        }
+        let real = self.real.mul_scale(1.0 / len);
+        let dual = self.dual.mul_scale(1.0 / len);
+        let dual = dual.sub(&real.mul_scale(real.dot(&dual)));
+        Ok(DualQuat::new(real, dual))
+    }
+
+    #[inline(always)]
+    pub fn transform_point(&self, point: &Vec3) -> Vec3
+    {
+        self.real.rotate(point).add(&self.translation())
+    }
+
+    #[inline(always)]
+    pub fn transform_vector(&self, vector: &Vec3) -> Vec3
+    {
+        self.real.rotate(vector)
+    }
+
+    // Raises a unit dual quaternion to the power `t` through its screw parameters:
+    // a rotation angle and axis plus a pitch along the axis and the axis moment.
+    fn pow(&self, t: f32) -> DualQuat
+    {
+        let axis_sin = self.real.vector().length();
+        if axis_sin < EPSILON
+        {
+            // No rotation: scale the translation.
+            return DualQuat::from_translation(&self.translation().mul_scale(t));
+        }
+        let half_angle = acos(math::clamp(self.real.w, -1.0, 1.0));
+        let direction = self.real.vector().mul_scale(1.0 / axis_sin);
+        let pitch = -2.0 * self.dual.w / axis_sin;
+        let moment = self
+            .dual
+            .vector()
+            .sub(&direction.mul_scale(pitch * 0.5 * self.real.w))
+            .mul_scale(1.0 / axis_sin);
+
+        let half_angle = half_angle * t;
+        let pitch = pitch * t;
+        let (s, c) = (sin(half_angle), cos(half_angle));
+        let real = Quat::new(direction.x * s, direction.y * s, direction.z * s, c);
+        let dual_vector = moment.mul_scale(s).add(&direction.mul_scale(pitch * 0.5 * c));
+        let dual = Quat::new(dual_vector.x, dual_vector.y, dual_vector.z, -pitch * 0.5 * s);
+        DualQuat::new(real, dual)
+    }
+
+    // Screw linear interpolation: a constant-speed screw motion from `self` (t = 0) to
+    // `other` (t = 1) along the shortest path. Both inputs must be unit length.
+    pub fn sclerp(&self, other: &DualQuat, t: f32) -> DualQuat
+    {
+        let other = if self.real.dot(&other.real) < 0.0 { other.mul_scale(-1.0) } else { *other };
+        let diff = self.inverse().mul(&other);
+        self.mul(&diff.pow(t))
+    }
+
+    // Dual quaternion linear blending (Kavan et al.) of unit dual quaternions, as used for
+    // skinning. Inputs are flipped into the hemisphere of the first one before summing.
+    pub fn blend(transforms: &[DualQuat], weights: &[f32]) -> Result<DualQuat, VectoriaError>
+    {
+        check_len(transforms.len(), weights.len())?;
+        let first = match transforms.first()
+        {
+            Some(first) => first,
+            None => return Err(VectoriaError::ZeroLength),
+        };
+        let mut sum = DualQuat::new(Quat::new(0.0, 0.0, 0.0, 0.0), Quat::new(0.0, 0.0, 0.0, 0.0));
+        for (dq, &weight) in transforms.iter().zip(weights)
+        {
+            let weight = if dq.real.dot(&first.real) < 0.0 { -weight } else { weight };
+            sum = sum.add(&dq.mul_scale(weight));
+        }
+        sum.normalize()
+    }
+}
@@ -0,0 +1,47 @@
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectoriaError
+{
+    // The matrix has a (near) zero determinant and cannot be inverted.
+    Singular,
+    // The operation needs a direction but the vector has zero length.
+    ZeroLength,
+    // Input slices or buffers have incompatible lengths.
+    DimensionMismatch { expected: usize, found: usize },
+    // An input or intermediate value is infinite or NaN.
+    NotFinite,
+}
+
+impl fmt::Display for VectoriaError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            VectoriaError::Singular => write!(f, "matrix is singular"),
+            VectoriaError::ZeroLength => write!(f, "vector has zero length"),
+            VectoriaError::DimensionMismatch { expected, found } =>
+            {
+                write!(f, "dimension mismatch: expected {}, found {}", expected, found)
+            }
+            VectoriaError::NotFinite => write!(f, "value is not finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VectoriaError {}
+
+#[inline(always)]
+pub(crate) fn check_len(expected: usize, found: usize) -> Result<(), VectoriaError>
+{
+    if expected == found
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(VectoriaError::DimensionMismatch { expected, found })
+    }
+}
@@ -0,0 +1,190 @@
+use crate::angle::Radians;
+use crate::math::{ atan2, sqrt, EPSILON };
+use crate::matrix::{ Mat3, Mat4 };
+
+// Euler angle conventions for rotations built from the matrices of `Mat3::rotate_x/y/z`.
+//
+// Intrinsic orders rotate about the axes of the moving frame: `XYZ` with angles
+// `(a, b, c)` is `Rx(a) * Ry(b) * Rz(c)`. Extrinsic orders (`...Ex`) rotate about the
+// fixed world axes in the order given: `XYZEx` with `(a, b, c)` is `Rz(c) * Ry(b) * Rx(a)`.
+// The first six of each group are Tait-Bryan angles, the last six proper Euler angles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EulerRot
+{
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+    XYZEx,
+    XZYEx,
+    YXZEx,
+    YZXEx,
+    ZXYEx,
+    ZYXEx,
+    XYXEx,
+    XZXEx,
+    YXYEx,
+    YZYEx,
+    ZXZEx,
+    ZYZEx,
+}
+
+impl EulerRot
+{
+    pub const ALL: [EulerRot; 24] = [
+        EulerRot::XYZ, EulerRot::XZY, EulerRot::YXZ, EulerRot::YZX, EulerRot::ZXY, EulerRot::ZYX,
+        EulerRot::XYX, EulerRot::XZX, EulerRot::YXY, EulerRot::YZY, EulerRot::ZXZ, EulerRot::ZYZ,
+        EulerRot::XYZEx, EulerRot::XZYEx, EulerRot::YXZEx, EulerRot::YZXEx, EulerRot::ZXYEx, EulerRot::ZYXEx,
+        EulerRot::XYXEx, EulerRot::XZXEx, EulerRot::YXYEx, EulerRot::YZYEx, EulerRot::ZXZEx, EulerRot::ZYZEx,
+    ];
+
+    // Axis indices in the order they are named, and whether the order is extrinsic.
+    fn axes(self) -> ([usize; 3], bool)
+    {
+        match self
+        {
+            EulerRot::XYZ => ([0, 1, 2], false),
+            EulerRot::XZY => ([0, 2, 1], false),
+            EulerRot::YXZ => ([1, 0, 2], false),
+            EulerRot::YZX => ([1, 2, 0], false),
+            EulerRot::ZXY => ([2, 0, 1], false),
+            EulerRot::ZYX => ([2, 1, 0], false),
+            EulerRot::XYX => ([0, 1, 0], false),
+            EulerRot::XZX => ([0, 2, 0], false),
+            EulerRot::YXY => ([1, 0, 1], false),
+            EulerRot::YZY => ([1, 2, 1], false),
+            EulerRot::ZXZ => ([2, 0, 2], false),
+            EulerRot::ZYZ => ([2, 1, 2], false),
+            EulerRot::XYZEx => ([0, 1, 2], true),
+            EulerRot::XZYEx => ([0, 2, 1], true),
+            EulerRot::YXZEx => ([1, 0, 2], true),
+            EulerRot::YZXEx => ([1, 2, 0], true),
+            EulerRot::ZXYEx => ([2, 0, 1], true),
+            EulerRot::ZYXEx => ([2, 1, 0], true),
+            EulerRot::XYXEx => ([0, 1, 0], true),
+            EulerRot::XZXEx => ([0, 2, 0], true),
+            EulerRot::YXYEx => ([1, 0, 1], true),
+            EulerRot::YZYEx => ([1, 2, 1], true),
+            EulerRot::ZXZEx => ([2, 0, 2], true),
+            EulerRot::ZYZEx => ([2, 1, 2], true),
+        }
+    }
+
+    pub fn is_extrinsic(self) -> bool
+    {
+        self.axes().1
+    }
+}
+
+fn axis_rotation(axis: usize, angle: f32) -> Mat3
+{
+    match axis
+    {
+        0 => Mat3::rotate_x(angle),
+        1 => Mat3::rotate_y(angle),
+        _ => Mat3::rotate_z(angle),
+    }
+}
+
+// Decomposes `m` as R_i(a) * R_j(b) * R_k(c) for the intrinsic axis sequence `[i, j, k]`.
+// In gimbal lock the third angle is set to zero and the first absorbs the whole rotation.
+fn intrinsic_angles(m: &Mat3, axes: [usize; 3]) -> (f32, f32, f32)
+{
+    let d = &m.data;
+    let i = axes[0];
+    let j = axes[1];
+    // The remaining axis and the handedness of (i, j, k).
+    let k = 3 - i - j;
+    let s = if (i + 1) % 3 == j { 1.0 } else { -1.0 };
+
+    let (a, b, c);
+    if axes[2] == i
+    {
+        let sin_b = sqrt(d[i][j] * d[i][j] + d[i][k] * d[i][k]);
+        b = atan2(sin_b, d[i][i]);
+        if sin_b > EPSILON
+        {
+            a = atan2(d[j][i], -s * d[k][i]);
+            c = atan2(d[i][j], s * d[i][k]);
+        }
+        else
+        {
+            a = atan2(s * d[k][j], d[j][j]);
+            c = 0.0;
+        }
+    }
+    else
+    {
+        let cos_b = sqrt(d[i][i] * d[i][i] + d[i][j] * d[i][j]);
+        b = atan2(s * d[i][k], cos_b);
+        if cos_b > EPSILON
+        {
+            a = atan2(-s * d[j][k], d[k][k]);
+            c = atan2(-s * d[i][j], d[i][i]);
+        }
+        else
+        {
+            a = atan2(s * d[k][j], d[j][j]);
+            c = 0.0;
+        }
+    }
+    (a, b, c)
+}
+
+impl Mat3
+{
+    pub fn from_euler(order: EulerRot, a: impl Into<Radians>, b: impl Into<Radians>, c: impl Into<Radians>) -> Mat3
+    {
+        let (axes, extrinsic) = order.axes();
+        let ra = axis_rotation(axes[0], a.into().0);
+        let rb = axis_rotation(axes[1], b.into().0);
+        let rc = axis_rotation(axes[2], c.into().0);
+        if extrinsic
+        {
+            rc.mul(&rb).mul(&ra)
+        }
+        else
+        {
+            ra.mul(&rb).mul(&rc)
+        }
+    }
+
+    // Returns the angles `(a, b, c)` such that `from_euler(order, a, b, c)` reproduces `self`,
+    // which must be a rotation. The middle angle lies in [-pi/2, pi/2] for Tait-Bryan orders
+    // and in [0, pi] for proper Euler orders.
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32)
+    {
+        let (axes, extrinsic) = order.axes();
+        if extrinsic
+        {
+            let (c, b, a) = intrinsic_angles(self, [axes[2], axes[1], axes[0]]);
+            (a, b, c)
+        }
+        else
+        {
+            intrinsic_angles(self, axes)
+        }
+    }
+}
+
+impl Mat4
+{
+    pub fn from_euler(order: EulerRot, a: impl Into<Radians>, b: impl Into<Radians>, c: impl Into<Radians>) -> Mat4
+    {
+        Mat4::from_mat3(&Mat3::from_euler(order, a, b, c))
+    }
+
+    // Reads the angles from the upper-left rotation block; translation is ignored.
+    pub fn to_euler(&self, order: EulerRot) -> (f32, f32, f32)
+    {
+        Mat3::from_mat4(self).to_euler(order)
+    }
+}
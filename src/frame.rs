@@ -0,0 +1,138 @@
+use crate::error::VectoriaError;
+use crate::geometry::Point3D;
+use crate::matrix::{ Mat3, Mat4 };
+use crate::vector::Vec3;
+
+// A coordinate frame: an origin plus a right-handed orthonormal basis, all in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame3
+{
+    pub origin: Vec3,
+    pub x_axis: Vec3,
+    pub y_axis: Vec3,
+    pub z_axis: Vec3,
+}
+
+impl Frame3
+{
+    // The axes are taken as given and must already be orthonormal.
+    pub fn new(origin: Vec3, x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Frame3
+    {
+        Frame3 { origin, x_axis, y_axis, z_axis }
+    }
+
+    pub fn identity() -> Frame3
+    {
+        Frame3::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    // Builds a tangent frame whose z axis is `normal`.
+    pub fn from_normal(origin: Vec3, normal: &Vec3) -> Result<Frame3, VectoriaError>
+    {
+        let normal = normal.try_normalize().ok_or(VectoriaError::ZeroLength)?;
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        Ok(Frame3::new(origin, tangent, bitangent, normal))
+    }
+
+    // Uses the columns of `basis` as axes, orthonormalizing them first. A mirroring basis
+    // would give a left-handed frame and is rejected as `Singular`.
+    pub fn from_basis(origin: Vec3, basis: &Mat3) -> Result<Frame3, VectoriaError>
+    {
+        if basis.determinant() < 0.0
+        {
+            return Err(VectoriaError::Singular);
+        }
+        let basis = basis.orthonormalize()?;
+        Ok(Frame3::new(origin, basis.column(0), basis.column(1), basis.column(2)))
+    }
+
+    #[inline(always)]
+    pub fn basis(&self) -> Mat3
+    {
+        Mat3::from_columns(&self.x_axis, &self.y_axis, &self.z_axis)
+    }
+
+    #[inline(always)]
+    pub fn vector_to_local(&self, vector: &Vec3) -> Vec3
+    {
+        Vec3::new(vector.dot(&self.x_axis), vector.dot(&self.y_axis), vector.dot(&self.z_axis))
+    }
+
+    #[inline(always)]
+    pub fn vector_to_world(&self, vector: &Vec3) -> Vec3
+    {
+        self.x_axis
+            .mul_scale(vector.x)
+            .add(&self.y_axis.mul_scale(vector.y))
+            .add(&self.z_axis.mul_scale(vector.z))
+    }
+
+    #[inline(always)]
+    pub fn point_to_local(&self, point: &Vec3) -> Vec3
+    {
+        self.vector_to_local(&point.sub(&self.origin))
+    }
+
+    #[inline(always)]
+    pub fn point_to_world(&self, point: &Vec3) -> Vec3
+    {
+        self.origin.add(&self.vector_to_world(point))
+    }
+
+    // Point3D conversions are done in f64 so they do not lose the point's precision.
+    pub fn point3d_to_local(&self, point: &Point3D) -> Point3D
+    {
+        let dx = point.x - self.origin.x as f64;
+        let dy = point.y - self.origin.y as f64;
+        let dz = point.z - self.origin.z as f64;
+        let project = |axis: &Vec3| dx * axis.x as f64 + dy * axis.y as f64 + dz * axis.z as f64;
+        Point3D::new(project(&self.x_axis), project(&self.y_axis), project(&self.z_axis))
+    }
+
+    pub fn point3d_to_world(&self, point: &Point3D) -> Point3D
+    {
+        let component = |origin: f32, x: f32, y: f32, z: f32| {
+            origin as f64 + point.x * x as f64 + point.y * y as f64 + point.z * z as f64
+        };
+        Point3D::new(
+            component(self.origin.x, self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            component(self.origin.y, self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            component(self.origin.z, self.x_axis.z, self.y_axis.z, self.z_axis.z),
+        )
+    }
+
+    // Local to world transform: the axes are the columns and the origin is the translation.
+    pub fn to_model_matrix(&self) -> Mat4
+    {
+        let (x, y, z, o) = (&self.x_axis, &self.y_axis, &self.z_axis, &self.origin);
+        Mat4
+        {
+            data: [
+                [x.x, y.x, z.x, o.x],
+                [x.y, y.y, z.y, o.y],
+                [x.z, y.z, z.z, o.z],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    // World to local transform, the inverse of `to_model_matrix`.
+    pub fn to_view_matrix(&self) -> Mat4
+    {
+        let (x, y, z, o) = (&self.x_axis, &self.y_axis, &self.z_axis, &self.origin);
+        Mat4
+        {
+            data: [
+                [x.x, x.y, x.z, -x.dot(o)],
+                [y.x, y.y, y.z, -y.dot(o)],
+                [z.x, z.y, z.z, -z.dot(o)],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
@@ -1,4 +1,34 @@
-#[derive(Debug, Clone, Copy)]
+use crate::math::{ atan2_f64, cbrt_f64, sqrt_f64 };
+use crate::matrix::{ Mat3, Mat4 };
+use crate::vector::{ Vec2, Vec3 };
+
+pub mod boolean;
+pub mod delaunay;
+pub mod hull;
+pub mod intersection;
+pub mod offset;
+pub mod polygon;
+pub mod predicates;
+pub mod sweep;
+pub mod triangulation;
+
+pub use boolean::{ boolean_op, BooleanOp };
+pub use delaunay::Delaunay;
+pub use hull::{ ConvexHull, ConvexHull3D };
+pub use intersection::SegmentIntersection;
+pub use offset::JoinType;
+pub use polygon::{ Polygon, Polyline };
+pub use predicates::{ incircle, insphere, orient2d, orient3d };
+pub use sweep::{ segment_intersections, IntersectionPoint };
+
+// Points are positions and `Vec2`/`Vec3` are displacements: `point.sub(&other)` yields a
+// vector and `point.add_vector(&v)` moves a point. Points stay in f64; the f32 vector types
+// are widened on the way in, so only the displacement carries f32 precision.
+//
+// `Mat3` acts on 2D points as a homogeneous affine map (translation in the third column)
+// and `Mat4` on 3D points the same way as `Mat4::transform_point`.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point
 {
     pub x: f64,
@@ -14,7 +44,9 @@ impl Point
 
     pub fn distance(&self, other: &Point) -> f64
     {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        sqrt_f64(dx * dx + dy * dy)
     }
 
     pub fn translate(&mut self, dx: f64, dy: f64)
@@ -22,6 +54,74 @@ impl Point
         self.x += dx;
         self.y += dy;
     }
+
+    pub fn from_vec2(v: &Vec2) -> Self
+    {
+        Self::new(v.x as f64, v.y as f64)
+    }
+
+    pub fn to_vec2(&self) -> Vec2
+    {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+
+    // The displacement from `other` to `self`.
+    pub fn sub(&self, other: &Point) -> Vec2
+    {
+        Vec2::new((self.x - other.x) as f32, (self.y - other.y) as f32)
+    }
+
+    pub fn add_vector(&self, offset: &Vec2) -> Point
+    {
+        Point::new(self.x + offset.x as f64, self.y + offset.y as f64)
+    }
+
+    pub fn sub_vector(&self, offset: &Vec2) -> Point
+    {
+        Point::new(self.x - offset.x as f64, self.y - offset.y as f64)
+    }
+
+    pub fn lerp(&self, other: &Point, t: f64) -> Point
+    {
+        Point::new(self.x + (other.x - self.x) * t, self.y + (other.y - self.y) * t)
+    }
+
+    // The average of `points`, or `None` if there are none.
+    pub fn centroid(points: &[Point]) -> Option<Point>
+    {
+        if points.is_empty()
+        {
+            return None;
+        }
+        let (sx, sy) = points.iter().fold((0.0, 0.0), |(sx, sy), p| (sx + p.x, sy + p.y));
+        let n = points.len() as f64;
+        Some(Point::new(sx / n, sy / n))
+    }
+
+    pub fn transform(&self, m: &Mat3) -> Point
+    {
+        let d = &m.data;
+        Point::new(
+            d[0][0] as f64 * self.x + d[0][1] as f64 * self.y + d[0][2] as f64,
+            d[1][0] as f64 * self.x + d[1][1] as f64 * self.y + d[1][2] as f64,
+        )
+    }
+}
+
+impl From<Vec2> for Point
+{
+    fn from(v: Vec2) -> Self
+    {
+        Point::from_vec2(&v)
+    }
+}
+
+impl From<Point> for Vec2
+{
+    fn from(p: Point) -> Self
+    {
+        p.to_vec2()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -50,6 +150,17 @@ impl Line
             (self.start.y + self.end.y) / 2.0,
         )
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        self.start.translate(dx, dy);
+        self.end.translate(dx, dy);
+    }
+
+    pub fn transform(&self, m: &Mat3) -> Line
+    {
+        Line::new(self.start.transform(m), self.end.transform(m))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -68,18 +179,32 @@ impl Circle
 
     pub fn area(&self) -> f64
     {
-        std::f64::consts::PI * self.radius.powi(2)
+        core::f64::consts::PI * self.radius * self.radius
     }
 
     pub fn circumference(&self) -> f64
     {
-        2.0 * std::f64::consts::PI * self.radius
+        2.0 * core::f64::consts::PI * self.radius
     }
 
     pub fn contains(&self, point: &Point) -> bool
     {
         self.center.distance(point) <= self.radius
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        self.center.translate(dx, dy);
+    }
+
+    // Scales the radius by the uniform scale of `m`, so this is exact for similarity
+    // transforms; under a non-uniform scale or shear the result keeps the transformed area.
+    pub fn transform(&self, m: &Mat3) -> Circle
+    {
+        let d = &m.data;
+        let det = d[0][0] as f64 * d[1][1] as f64 - d[0][1] as f64 * d[1][0] as f64;
+        Circle::new(self.center.transform(m), self.radius * sqrt_f64(det.abs()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -114,6 +239,22 @@ impl Rectangle
             && point.y >= self.top_left.y
             && point.y <= self.top_left.y + self.height
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        self.top_left.translate(dx, dy);
+    }
+
+    // Rectangles stay axis-aligned, so this is the bounding box of the transformed corners.
+    pub fn transform(&self, m: &Mat3) -> Rectangle
+    {
+        let (x0, y0) = (self.top_left.x, self.top_left.y);
+        let (x1, y1) = (x0 + self.width, y0 + self.height);
+        let corners = [Point::new(x0, y0), Point::new(x1, y0), Point::new(x1, y1), Point::new(x0, y1)].map(|p| p.transform(m));
+        let (min_x, min_y) = corners.iter().fold((f64::INFINITY, f64::INFINITY), |(x, y), p| (x.min(p.x), y.min(p.y)));
+        let (max_x, max_y) = corners.iter().fold((f64::NEG_INFINITY, f64::NEG_INFINITY), |(x, y), p| (x.max(p.x), y.max(p.y)));
+        Rectangle::new(Point::new(min_x, min_y), max_x - min_x, max_y - min_y)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -133,16 +274,201 @@ impl Triangle
 
     pub fn area(&self) -> f64
     {
-        let s = self.perimeter() / 2.0;
-        (s * (s - self.a.distance(&self.b)) * (s - self.b.distance(&self.c)) * (s - self.c.distance(&self.a))).sqrt()
+        self.signed_area().abs()
     }
 
     pub fn perimeter(&self) -> f64 {
         self.a.distance(&self.b) + self.b.distance(&self.c) + self.c.distance(&self.a)
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        self.a.translate(dx, dy);
+        self.b.translate(dx, dy);
+        self.c.translate(dx, dy);
+    }
+
+    pub fn transform(&self, m: &Mat3) -> Triangle
+    {
+        Triangle::new(self.a.transform(m), self.b.transform(m), self.c.transform(m))
+    }
+
+    // Positive when `a -> b -> c` turns counter-clockwise with y pointing up (clockwise on
+    // screen, where `Rectangle` has y pointing down).
+    pub fn signed_area(&self) -> f64
+    {
+        0.5 * orient2d(&self.a, &self.b, &self.c)
+    }
+
+    pub fn winding(&self) -> Winding
+    {
+        let area = self.signed_area();
+        if area > 0.0
+        {
+            Winding::CounterClockwise
+        }
+        else if area < 0.0
+        {
+            Winding::Clockwise
+        }
+        else
+        {
+            Winding::Collinear
+        }
+    }
+
+    // Weights `(u, v, w)` with `point = u * a + v * b + w * c` and `u + v + w = 1`, or `None`
+    // for a degenerate triangle.
+    pub fn barycentric(&self, point: &Point) -> Option<(f64, f64, f64)>
+    {
+        let area = self.signed_area();
+        if area == 0.0
+        {
+            return None;
+        }
+        let v = Triangle::new(self.a, *point, self.c).signed_area() / area;
+        let w = Triangle::new(self.a, self.b, *point).signed_area() / area;
+        Some((1.0 - v - w, v, w))
+    }
+
+    // Points on an edge count as inside, for either winding. A degenerate triangle contains
+    // only the points of its longest edge.
+    pub fn contains(&self, point: &Point) -> bool
+    {
+        if self.signed_area() == 0.0
+        {
+            let edges = [(&self.a, &self.b), (&self.b, &self.c), (&self.c, &self.a)];
+            let (p, q) = edges.into_iter().max_by(|(p, q), (r, s)| p.distance(q).total_cmp(&r.distance(s))).unwrap();
+            return polygon::on_segment(point, p, q);
+        }
+        let d0 = Triangle::new(self.a, self.b, *point).signed_area();
+        let d1 = Triangle::new(self.b, self.c, *point).signed_area();
+        let d2 = Triangle::new(self.c, self.a, *point).signed_area();
+        let has_negative = d0 < 0.0 || d1 < 0.0 || d2 < 0.0;
+        let has_positive = d0 > 0.0 || d1 > 0.0 || d2 > 0.0;
+        !(has_negative && has_positive)
+    }
+
+    pub fn centroid(&self) -> Point
+    {
+        Point::new((self.a.x + self.b.x + self.c.x) / 3.0, (self.a.y + self.b.y + self.c.y) / 3.0)
+    }
+
+    // The centers below are `None` for degenerate (collinear) triangles.
+    pub fn circumcenter(&self) -> Option<Point>
+    {
+        let (bx, by) = (self.b.x - self.a.x, self.b.y - self.a.y);
+        let (cx, cy) = (self.c.x - self.a.x, self.c.y - self.a.y);
+        // Zero exactly when the vertices are collinear.
+        let d = 2.0 * orient2d(&self.a, &self.b, &self.c);
+        if d == 0.0
+        {
+            return None;
+        }
+        let b2 = bx * bx + by * by;
+        let c2 = cx * cx + cy * cy;
+        Some(Point::new(self.a.x + (cy * b2 - by * c2) / d, self.a.y + (bx * c2 - cx * b2) / d))
+    }
+
+    pub fn incenter(&self) -> Option<Point>
+    {
+        if self.signed_area() == 0.0
+        {
+            return None;
+        }
+        let wa = self.b.distance(&self.c);
+        let wb = self.c.distance(&self.a);
+        let wc = self.a.distance(&self.b);
+        let sum = wa + wb + wc;
+        Some(Point::new(
+            (wa * self.a.x + wb * self.b.x + wc * self.c.x) / sum,
+            (wa * self.a.y + wb * self.b.y + wc * self.c.y) / sum,
+        ))
+    }
+
+    // Uses the Euler line relation `H = A + B + C - 2O`.
+    pub fn orthocenter(&self) -> Option<Point>
+    {
+        let o = self.circumcenter()?;
+        Some(Point::new(
+            self.a.x + self.b.x + self.c.x - 2.0 * o.x,
+            self.a.y + self.b.y + self.c.y - 2.0 * o.y,
+        ))
+    }
+
+    pub fn circumcircle(&self) -> Option<Circle>
+    {
+        let center = self.circumcenter()?;
+        Some(Circle::new(center, center.distance(&self.a)))
+    }
+
+    pub fn incircle(&self) -> Option<Circle>
+    {
+        let center = self.incenter()?;
+        Some(Circle::new(center, 2.0 * self.signed_area().abs() / self.perimeter()))
+    }
+
+    // Interior angles at `a`, `b` and `c` in radians. atan2 stays accurate for very thin
+    // triangles, where the law of cosines does not.
+    pub fn angles(&self) -> (f64, f64, f64)
+    {
+        let angle = |p: &Point, q: &Point, r: &Point| {
+            let (ux, uy) = (q.x - p.x, q.y - p.y);
+            let (vx, vy) = (r.x - p.x, r.y - p.y);
+            atan2_f64((ux * vy - uy * vx).abs(), ux * vx + uy * vy)
+        };
+        (angle(&self.a, &self.b, &self.c), angle(&self.b, &self.c, &self.a), angle(&self.c, &self.a, &self.b))
+    }
+
+    // Compares the squared longest side against the sum of the other two, with a tolerance
+    // relative to its size so nearly-right triangles classify as `Right`.
+    pub fn classify(&self) -> TriangleKind
+    {
+        let mut sides = [
+            self.a.distance(&self.b),
+            self.b.distance(&self.c),
+            self.c.distance(&self.a),
+        ];
+        sides.sort_unstable_by(|x, y| x.total_cmp(y));
+        let [p, q, r] = sides.map(|side| side * side);
+        let tolerance = 1e-9 * r;
+        if r == 0.0 || self.signed_area().abs() <= tolerance
+        {
+            TriangleKind::Degenerate
+        }
+        else if (r - (p + q)).abs() <= tolerance
+        {
+            TriangleKind::Right
+        }
+        else if r < p + q
+        {
+            TriangleKind::Acute
+        }
+        else
+        {
+            TriangleKind::Obtuse
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding
+{
+    CounterClockwise,
+    Clockwise,
+    Collinear,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangleKind
+{
+    Acute,
+    Right,
+    Obtuse,
+    Degenerate,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point3D
 {
     pub x: f64,
@@ -159,7 +485,251 @@ impl Point3D
 
     pub fn distance(&self, other: &Point3D) -> f64
     {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)).sqrt()
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        sqrt_f64(dx * dx + dy * dy + dz * dz)
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64, dz: f64)
+    {
+        self.x += dx;
+        self.y += dy;
+        self.z += dz;
+    }
+
+    pub fn from_vec3(v: &Vec3) -> Self
+    {
+        Self::new(v.x as f64, v.y as f64, v.z as f64)
+    }
+
+    pub fn to_vec3(&self) -> Vec3
+    {
+        Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+
+    // The displacement from `other` to `self`.
+    pub fn sub(&self, other: &Point3D) -> Vec3
+    {
+        Vec3::new((self.x - other.x) as f32, (self.y - other.y) as f32, (self.z - other.z) as f32)
+    }
+
+    pub fn add_vector(&self, offset: &Vec3) -> Point3D
+    {
+        Point3D::new(self.x + offset.x as f64, self.y + offset.y as f64, self.z + offset.z as f64)
+    }
+
+    pub fn sub_vector(&self, offset: &Vec3) -> Point3D
+    {
+        Point3D::new(self.x - offset.x as f64, self.y - offset.y as f64, self.z - offset.z as f64)
+    }
+
+    pub fn lerp(&self, other: &Point3D, t: f64) -> Point3D
+    {
+        Point3D::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+            self.z + (other.z - self.z) * t,
+        )
+    }
+
+    pub fn centroid(points: &[Point3D]) -> Option<Point3D>
+    {
+        if points.is_empty()
+        {
+            return None;
+        }
+        let (sx, sy, sz) = points.iter().fold((0.0, 0.0, 0.0), |(sx, sy, sz), p| (sx + p.x, sy + p.y, sz + p.z));
+        let n = points.len() as f64;
+        Some(Point3D::new(sx / n, sy / n, sz / n))
+    }
+
+    pub fn transform(&self, m: &Mat4) -> Point3D
+    {
+        let d = &m.data;
+        let row = |i: usize| d[i][0] as f64 * self.x + d[i][1] as f64 * self.y + d[i][2] as f64 * self.z + d[i][3] as f64;
+        Point3D::new(row(0), row(1), row(2))
+    }
+}
+
+impl From<Vec3> for Point3D
+{
+    fn from(v: Vec3) -> Self
+    {
+        Point3D::from_vec3(&v)
+    }
+}
+
+impl From<Point3D> for Vec3
+{
+    fn from(p: Point3D) -> Self
+    {
+        p.to_vec3()
+    }
+}
+
+// Vector helpers for the f64 algorithms on `Point3D`, where going through the f32 `Vec3`
+// would throw away precision.
+fn sub3(a: &Point3D, b: &Point3D) -> [f64; 3]
+{
+    [a.x - b.x, a.y - b.y, a.z - b.z]
+}
+
+fn dot3(u: &[f64; 3], v: &[f64; 3]) -> f64
+{
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn cross3(u: &[f64; 3], v: &[f64; 3]) -> [f64; 3]
+{
+    [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]]
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Triangle3D
+{
+    pub a: Point3D,
+    pub b: Point3D,
+    pub c: Point3D,
+}
+
+impl Triangle3D
+{
+    pub fn new(a: Point3D, b: Point3D, c: Point3D) -> Self
+    {
+        Self { a, b, c }
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64, dz: f64)
+    {
+        self.a.translate(dx, dy, dz);
+        self.b.translate(dx, dy, dz);
+        self.c.translate(dx, dy, dz);
+    }
+
+    pub fn transform(&self, m: &Mat4) -> Triangle3D
+    {
+        Triangle3D::new(self.a.transform(m), self.b.transform(m), self.c.transform(m))
+    }
+
+    fn cross(&self) -> [f64; 3]
+    {
+        cross3(&sub3(&self.b, &self.a), &sub3(&self.c, &self.a))
+    }
+
+    // Half the cross product of the edges: its length is the area and it points along the
+    // right-handed normal of `a -> b -> c`.
+    pub fn area_vector(&self) -> Vec3
+    {
+        let [x, y, z] = self.cross();
+        Vec3::new((x * 0.5) as f32, (y * 0.5) as f32, (z * 0.5) as f32)
+    }
+
+    pub fn area(&self) -> f64
+    {
+        let n = self.cross();
+        0.5 * sqrt_f64(dot3(&n, &n))
+    }
+
+    // The unit normal, or `None` for a degenerate triangle.
+    pub fn normal(&self) -> Option<Vec3>
+    {
+        let n = self.cross();
+        let length = sqrt_f64(dot3(&n, &n));
+        if length == 0.0
+        {
+            return None;
+        }
+        Some(Vec3::new((n[0] / length) as f32, (n[1] / length) as f32, (n[2] / length) as f32))
+    }
+
+    pub fn perimeter(&self) -> f64
+    {
+        self.a.distance(&self.b) + self.b.distance(&self.c) + self.c.distance(&self.a)
+    }
+
+    pub fn centroid(&self) -> Point3D
+    {
+        Point3D::new(
+            (self.a.x + self.b.x + self.c.x) / 3.0,
+            (self.a.y + self.b.y + self.c.y) / 3.0,
+            (self.a.z + self.b.z + self.c.z) / 3.0,
+        )
+    }
+
+    // Barycentric weights of `point` projected onto the triangle's plane, or `None` for a
+    // degenerate triangle.
+    pub fn barycentric(&self, point: &Point3D) -> Option<(f64, f64, f64)>
+    {
+        let n = self.cross();
+        let denominator = dot3(&n, &n);
+        if denominator == 0.0
+        {
+            return None;
+        }
+        let ap = sub3(point, &self.a);
+        let v = dot3(&cross3(&ap, &sub3(&self.c, &self.a)), &n) / denominator;
+        let w = dot3(&cross3(&sub3(&self.b, &self.a), &ap), &n) / denominator;
+        Some((1.0 - v - w, v, w))
+    }
+
+    // The nearest point on the (solid) triangle, found by locating `point` among the
+    // vertex, edge and face Voronoi regions (Ericson, Real-Time Collision Detection 5.1.5).
+    pub fn closest_point(&self, point: &Point3D) -> Point3D
+    {
+        let (a, b, c) = (&self.a, &self.b, &self.c);
+        let ab = sub3(b, a);
+        let ac = sub3(c, a);
+        let ap = sub3(point, a);
+        let d1 = dot3(&ab, &ap);
+        let d2 = dot3(&ac, &ap);
+        if d1 <= 0.0 && d2 <= 0.0
+        {
+            return *a;
+        }
+
+        let bp = sub3(point, b);
+        let d3 = dot3(&ab, &bp);
+        let d4 = dot3(&ac, &bp);
+        if d3 >= 0.0 && d4 <= d3
+        {
+            return *b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0
+        {
+            return a.lerp(b, d1 / (d1 - d3));
+        }
+
+        let cp = sub3(point, c);
+        let d5 = dot3(&ab, &cp);
+        let d6 = dot3(&ac, &cp);
+        if d6 >= 0.0 && d5 <= d6
+        {
+            return *c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0
+        {
+            return a.lerp(c, d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0
+        {
+            return b.lerp(c, (d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = 1.0 / (va + vb + vc);
+        let v = vb * denominator;
+        let w = vc * denominator;
+        Point3D::new(
+            a.x + ab[0] * v + ac[0] * w,
+            a.y + ab[1] * v + ac[1] * w,
+            a.z + ab[2] * v + ac[2] * w,
+        )
     }
 }
 
@@ -179,18 +749,31 @@ impl Sphere
 
     pub fn volume(&self) -> f64
     {
-        (4.0 / 3.0) * std::f64::consts::PI * self.radius.powi(3)
+        (4.0 / 3.0) * core::f64::consts::PI * self.radius * self.radius * self.radius
     }
 
     pub fn surface_area(&self) -> f64
     {
-        4.0 * std::f64::consts::PI * self.radius.powi(2)
+        4.0 * core::f64::consts::PI * self.radius * self.radius
     }
 
     pub fn contains(&self, point: &Point3D) -> bool
     {
         self.center.distance(point) <= self.radius
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64, dz: f64)
+    {
+        self.center.translate(dx, dy, dz);
+    }
+
+    // Like `Circle::transform`, exact for similarity transforms and otherwise keeping the
+    // transformed volume.
+    pub fn transform(&self, m: &Mat4) -> Sphere
+    {
+        let det = Mat3::from_mat4(m).determinant() as f64;
+        Sphere::new(self.center.transform(m), self.radius * cbrt_f64(det.abs()))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -209,12 +792,12 @@ impl Cube
 
     pub fn volume(&self) -> f64
     {
-        self.side_length.powi(3)
+        self.side_length * self.side_length * self.side_length
     }
 
     pub fn surface_area(&self) -> f64
     {
-        6.0 * self.side_length.powi(2)
+        6.0 * self.side_length * self.side_length
     }
 
     pub fn contains(&self, point: &Point3D) -> bool
@@ -224,4 +807,459 @@ impl Cube
             point.y >= self.center.y - half_side && point.y <= self.center.y + half_side &&
             point.z >= self.center.z - half_side && point.z <= self.center.z + half_side
     }
+
+    pub fn translate(&mut self, dx: f64, dy: f64, dz: f64)
+    {
+        self.center.translate(dx, dy, dz);
+    }
+
+    // Cubes stay axis-aligned, and a transformed cube is in general not a cube, so this is
+    // the bounding box of the transformed corners.
+    pub fn transform(&self, m: &Mat4) -> Box3D
+    {
+        Shape3D::bounding_box(self).transform(m)
+    }
+}
+
+// An axis-aligned box, used as the 3D bounding box. `min` and `max` are opposite corners.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Box3D
+{
+    pub min: Point3D,
+    pub max: Point3D,
+}
+
+impl Box3D
+{
+    pub fn new(min: Point3D, max: Point3D) -> Self
+    {
+        Self { min, max }
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64, dz: f64)
+    {
+        self.min.translate(dx, dy, dz);
+        self.max.translate(dx, dy, dz);
+    }
+
+    // The bounding box of the transformed corners.
+    pub fn transform(&self, m: &Mat4) -> Box3D
+    {
+        let mut min = Point3D::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Point3D::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for i in 0..8
+        {
+            let pick = |bit: usize, lo: f64, hi: f64| if i & bit == 0 { lo } else { hi };
+            let corner = Point3D::new(pick(1, self.min.x, self.max.x), pick(2, self.min.y, self.max.y), pick(4, self.min.z, self.max.z)).transform(m);
+            min = Point3D::new(min.x.min(corner.x), min.y.min(corner.y), min.z.min(corner.z));
+            max = Point3D::new(max.x.max(corner.x), max.y.max(corner.y), max.z.max(corner.z));
+        }
+        Box3D::new(min, max)
+    }
+}
+
+// Shared interface for solid 2D shapes. `closest_point` is the nearest point on the
+// boundary, and `signed_distance` is the distance to the boundary, negative inside.
+// Rectangles are the axis-aligned bounding boxes, with `top_left` as the minimum corner.
+pub trait Shape2D
+{
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+    fn contains(&self, point: &Point) -> bool;
+    fn bounding_box(&self) -> Rectangle;
+    fn bounding_circle(&self) -> Circle;
+    fn centroid(&self) -> Point;
+    fn closest_point(&self, point: &Point) -> Point;
+    fn signed_distance(&self, point: &Point) -> f64;
+}
+
+// The 3D counterpart of `Shape2D`.
+pub trait Shape3D
+{
+    fn volume(&self) -> f64;
+    fn surface_area(&self) -> f64;
+    fn contains(&self, point: &Point3D) -> bool;
+    fn bounding_box(&self) -> Box3D;
+    fn bounding_sphere(&self) -> Sphere;
+    fn centroid(&self) -> Point3D;
+    fn closest_point(&self, point: &Point3D) -> Point3D;
+    fn signed_distance(&self, point: &Point3D) -> f64;
+}
+
+fn closest_point_on_segment(point: &Point, a: &Point, b: &Point) -> Point
+{
+    let (abx, aby) = (b.x - a.x, b.y - a.y);
+    let length_squared = abx * abx + aby * aby;
+    if length_squared == 0.0
+    {
+        return *a;
+    }
+    let t = ((point.x - a.x) * abx + (point.y - a.y) * aby) / length_squared;
+    a.lerp(b, t.clamp(0.0, 1.0))
+}
+
+// Nearest boundary point of the axis-aligned box spanning `min..max`, per axis. Outside
+// points are clamped; inside points are pushed to the nearest face.
+fn closest_point_on_box<const N: usize>(point: [f64; N], min: [f64; N], max: [f64; N]) -> [f64; N]
+{
+    let mut result = point;
+    let mut inside = true;
+    for i in 0..N
+    {
+        if point[i] < min[i] || point[i] > max[i]
+        {
+            inside = false;
+        }
+        result[i] = point[i].clamp(min[i], max[i]);
+    }
+    if inside
+    {
+        let mut best = (f64::INFINITY, 0, 0.0);
+        for i in 0..N
+        {
+            let to_min = point[i] - min[i];
+            let to_max = max[i] - point[i];
+            if to_min < best.0
+            {
+                best = (to_min, i, min[i]);
+            }
+            if to_max < best.0
+            {
+                best = (to_max, i, max[i]);
+            }
+        }
+        result[best.1] = best.2;
+    }
+    result
+}
+
+// Exact signed distance to the box spanning `min..max`.
+fn signed_distance_to_box<const N: usize>(point: [f64; N], min: [f64; N], max: [f64; N]) -> f64
+{
+    let mut outside = 0.0;
+    let mut inside = f64::NEG_INFINITY;
+    for i in 0..N
+    {
+        let half = (max[i] - min[i]) * 0.5;
+        let q = (point[i] - (min[i] + half)).abs() - half;
+        outside += q.max(0.0) * q.max(0.0);
+        inside = inside.max(q);
+    }
+    sqrt_f64(outside) + inside.min(0.0)
+}
+
+impl Shape2D for Circle
+{
+    fn area(&self) -> f64
+    {
+        Circle::area(self)
+    }
+
+    fn perimeter(&self) -> f64
+    {
+        self.circumference()
+    }
+
+    fn contains(&self, point: &Point) -> bool
+    {
+        Circle::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Rectangle
+    {
+        let diameter = 2.0 * self.radius;
+        Rectangle::new(Point::new(self.center.x - self.radius, self.center.y - self.radius), diameter, diameter)
+    }
+
+    fn bounding_circle(&self) -> Circle
+    {
+        self.clone()
+    }
+
+    fn centroid(&self) -> Point
+    {
+        self.center
+    }
+
+    fn closest_point(&self, point: &Point) -> Point
+    {
+        let distance = self.center.distance(point);
+        if distance == 0.0
+        {
+            return Point::new(self.center.x + self.radius, self.center.y);
+        }
+        self.center.lerp(point, self.radius / distance)
+    }
+
+    fn signed_distance(&self, point: &Point) -> f64
+    {
+        self.center.distance(point) - self.radius
+    }
+}
+
+impl Shape2D for Rectangle
+{
+    fn area(&self) -> f64
+    {
+        Rectangle::area(self)
+    }
+
+    fn perimeter(&self) -> f64
+    {
+        Rectangle::perimeter(self)
+    }
+
+    fn contains(&self, point: &Point) -> bool
+    {
+        Rectangle::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Rectangle
+    {
+        self.clone()
+    }
+
+    fn bounding_circle(&self) -> Circle
+    {
+        let center = Shape2D::centroid(self);
+        Circle::new(center, center.distance(&self.top_left))
+    }
+
+    fn centroid(&self) -> Point
+    {
+        Point::new(self.top_left.x + self.width * 0.5, self.top_left.y + self.height * 0.5)
+    }
+
+    fn closest_point(&self, point: &Point) -> Point
+    {
+        let min = [self.top_left.x, self.top_left.y];
+        let max = [self.top_left.x + self.width, self.top_left.y + self.height];
+        let [x, y] = closest_point_on_box([point.x, point.y], min, max);
+        Point::new(x, y)
+    }
+
+    fn signed_distance(&self, point: &Point) -> f64
+    {
+        let min = [self.top_left.x, self.top_left.y];
+        let max = [self.top_left.x + self.width, self.top_left.y + self.height];
+        signed_distance_to_box([point.x, point.y], min, max)
+    }
+}
+
+impl Shape2D for Triangle
+{
+    fn area(&self) -> f64
+    {
+        Triangle::area(self)
+    }
+
+    fn perimeter(&self) -> f64
+    {
+        Triangle::perimeter(self)
+    }
+
+    fn contains(&self, point: &Point) -> bool
+    {
+        Triangle::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Rectangle
+    {
+        let min_x = self.a.x.min(self.b.x).min(self.c.x);
+        let min_y = self.a.y.min(self.b.y).min(self.c.y);
+        let max_x = self.a.x.max(self.b.x).max(self.c.x);
+        let max_y = self.a.y.max(self.b.y).max(self.c.y);
+        Rectangle::new(Point::new(min_x, min_y), max_x - min_x, max_y - min_y)
+    }
+
+    // The minimal enclosing circle: the circle on the longest edge when the opposite
+    // vertex lies inside it (right or obtuse triangles), otherwise the circumcircle.
+    fn bounding_circle(&self) -> Circle
+    {
+        let edges = [(&self.a, &self.b, &self.c), (&self.b, &self.c, &self.a), (&self.c, &self.a, &self.b)];
+        for (p, q, opposite) in edges
+        {
+            let circle = Circle::new(p.lerp(q, 0.5), p.distance(q) * 0.5);
+            if circle.center.distance(opposite) <= circle.radius
+            {
+                return circle;
+            }
+        }
+        self.circumcircle().unwrap_or_else(|| Circle::new(self.a, 0.0))
+    }
+
+    fn centroid(&self) -> Point
+    {
+        Triangle::centroid(self)
+    }
+
+    fn closest_point(&self, point: &Point) -> Point
+    {
+        let candidates = [
+            closest_point_on_segment(point, &self.a, &self.b),
+            closest_point_on_segment(point, &self.b, &self.c),
+            closest_point_on_segment(point, &self.c, &self.a),
+        ];
+        let mut best = candidates[0];
+        for candidate in &candidates[1..]
+        {
+            if candidate.distance(point) < best.distance(point)
+            {
+                best = *candidate;
+            }
+        }
+        best
+    }
+
+    fn signed_distance(&self, point: &Point) -> f64
+    {
+        let distance = Shape2D::closest_point(self, point).distance(point);
+        if self.contains(point) { -distance } else { distance }
+    }
+}
+
+impl Shape3D for Sphere
+{
+    fn volume(&self) -> f64
+    {
+        Sphere::volume(self)
+    }
+
+    fn surface_area(&self) -> f64
+    {
+        Sphere::surface_area(self)
+    }
+
+    fn contains(&self, point: &Point3D) -> bool
+    {
+        Sphere::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Box3D
+    {
+        let r = self.radius;
+        let c = &self.center;
+        Box3D::new(Point3D::new(c.x - r, c.y - r, c.z - r), Point3D::new(c.x + r, c.y + r, c.z + r))
+    }
+
+    fn bounding_sphere(&self) -> Sphere
+    {
+        self.clone()
+    }
+
+    fn centroid(&self) -> Point3D
+    {
+        self.center
+    }
+
+    fn closest_point(&self, point: &Point3D) -> Point3D
+    {
+        let distance = self.center.distance(point);
+        if distance == 0.0
+        {
+            return Point3D::new(self.center.x + self.radius, self.center.y, self.center.z);
+        }
+        self.center.lerp(point, self.radius / distance)
+    }
+
+    fn signed_distance(&self, point: &Point3D) -> f64
+    {
+        self.center.distance(point) - self.radius
+    }
+}
+
+impl Shape3D for Cube
+{
+    fn volume(&self) -> f64
+    {
+        Cube::volume(self)
+    }
+
+    fn surface_area(&self) -> f64
+    {
+        Cube::surface_area(self)
+    }
+
+    fn contains(&self, point: &Point3D) -> bool
+    {
+        Cube::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Box3D
+    {
+        let h = self.side_length * 0.5;
+        let c = &self.center;
+        Box3D::new(Point3D::new(c.x - h, c.y - h, c.z - h), Point3D::new(c.x + h, c.y + h, c.z + h))
+    }
+
+    fn bounding_sphere(&self) -> Sphere
+    {
+        Sphere::new(self.center, self.side_length * 0.5 * sqrt_f64(3.0))
+    }
+
+    fn centroid(&self) -> Point3D
+    {
+        self.center
+    }
+
+    fn closest_point(&self, point: &Point3D) -> Point3D
+    {
+        Shape3D::bounding_box(self).closest_point(point)
+    }
+
+    fn signed_distance(&self, point: &Point3D) -> f64
+    {
+        Shape3D::bounding_box(self).signed_distance(point)
+    }
+}
+
+impl Shape3D for Box3D
+{
+    fn volume(&self) -> f64
+    {
+        (self.max.x - self.min.x) * (self.max.y - self.min.y) * (self.max.z - self.min.z)
+    }
+
+    fn surface_area(&self) -> f64
+    {
+        let (dx, dy, dz) = (self.max.x - self.min.x, self.max.y - self.min.y, self.max.z - self.min.z);
+        2.0 * (dx * dy + dy * dz + dz * dx)
+    }
+
+    fn contains(&self, point: &Point3D) -> bool
+    {
+        point.x >= self.min.x && point.x <= self.max.x &&
+            point.y >= self.min.y && point.y <= self.max.y &&
+            point.z >= self.min.z && point.z <= self.max.z
+    }
+
+    fn bounding_box(&self) -> Box3D
+    {
+        self.clone()
+    }
+
+    fn bounding_sphere(&self) -> Sphere
+    {
+        Sphere::new(self.min.lerp(&self.max, 0.5), self.min.distance(&self.max) * 0.5)
+    }
+
+    fn centroid(&self) -> Point3D
+    {
+        self.min.lerp(&self.max, 0.5)
+    }
+
+    fn closest_point(&self, point: &Point3D) -> Point3D
+    {
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+        let [x, y, z] = closest_point_on_box([point.x, point.y, point.z], min, max);
+        Point3D::new(x, y, z)
+    }
+
+    fn signed_distance(&self, point: &Point3D) -> f64
+    {
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+        signed_distance_to_box([point.x, point.y, point.z], min, max)
+    }
 }
@@ -0,0 +1,814 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::f64::consts::TAU;
+
+use super::polygon::{ on_segment, ring_signed_area, ring_winding_number };
+use super::predicates::orient2d;
+use super::{ closest_point_on_segment, Point, Polygon };
+use crate::math::atan2_f64;
+
+// Polygon boolean operations using the Martinez-Rueda-Feito sweep (Martinez et al., "A
+// simple algorithm for Boolean operations on polygons", 2013), including its handling of
+// overlapping edges. Vertices within rounding distance of an edge are treated as lying on
+// it, so feeding one result back into another operation stays consistent.
+//
+// Each operand is a set of polygons read with the even-odd rule, so polygons within one
+// operand are expected not to overlap each other. The results are non-overlapping
+// polygons with counter-clockwise exteriors and clockwise holes, with repeated and
+// collinear vertices removed.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp
+{
+    Union,
+    Intersection,
+    Difference,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EdgeType
+{
+    Normal,
+    NonContributing,
+    SameTransition,
+    DifferentTransition,
+}
+
+// One endpoint of an edge. Events live in an arena and refer to each other by index
+// because dividing a segment rewires the `other` links of events already in the queue.
+struct SweepEvent
+{
+    point: Point,
+    left: bool,
+    other: usize,
+    is_subject: bool,
+    contour_id: usize,
+    edge_type: EdgeType,
+    // Whether the edge is an inside-to-outside transition of its own polygon for a ray
+    // coming from below, and whether the edge lies outside the other polygon.
+    in_out: bool,
+    other_in_out: bool,
+    // 0 when the edge is not in the result, 1 when the result lies above it, -1 below.
+    result_transition: i8,
+}
+
+struct Sweep
+{
+    events: Vec<SweepEvent>,
+    // A binary min-heap of event indices ordered by `compare_events`.
+    queue: Vec<usize>,
+}
+
+impl Sweep
+{
+    fn add_event(&mut self, point: Point, left: bool, other: usize, is_subject: bool, contour_id: usize) -> usize
+    {
+        self.events.push(SweepEvent
+        {
+            point,
+            left,
+            other,
+            is_subject,
+            contour_id,
+            edge_type: EdgeType::Normal,
+            in_out: false,
+            other_in_out: false,
+            result_transition: 0,
+        });
+        self.events.len() - 1
+    }
+
+    fn other_point(&self, event: usize) -> Point
+    {
+        self.events[self.events[event].other].point
+    }
+
+    fn is_vertical(&self, event: usize) -> bool
+    {
+        self.events[event].point.x == self.other_point(event).x
+    }
+
+    fn is_below(&self, event: usize, point: &Point) -> bool
+    {
+        let e = &self.events[event];
+        let other = self.other_point(event);
+        if e.left
+        {
+            orient2d(&e.point, &other, point) > 0.0
+        }
+        else
+        {
+            orient2d(&other, &e.point, point) > 0.0
+        }
+    }
+
+    // Queue order: left to right, bottom to top, right endpoints before left ones, and
+    // among edges leaving the same point the lower one first.
+    fn compare_events(&self, a: usize, b: usize) -> Ordering
+    {
+        let (e1, e2) = (&self.events[a], &self.events[b]);
+        if e1.point.x != e2.point.x
+        {
+            return if e1.point.x > e2.point.x { Ordering::Greater } else { Ordering::Less };
+        }
+        if e1.point.y != e2.point.y
+        {
+            return if e1.point.y > e2.point.y { Ordering::Greater } else { Ordering::Less };
+        }
+        if e1.left != e2.left
+        {
+            return if e1.left { Ordering::Greater } else { Ordering::Less };
+        }
+        if orient2d(&e1.point, &self.other_point(a), &self.other_point(b)) != 0.0
+        {
+            return if self.is_below(a, &self.other_point(b)) { Ordering::Less } else { Ordering::Greater };
+        }
+        if !e1.is_subject && e2.is_subject { Ordering::Greater } else { Ordering::Less }
+    }
+
+    // Vertical order of two left events in the sweep line.
+    fn compare_segments(&self, a: usize, b: usize) -> Ordering
+    {
+        if a == b
+        {
+            return Ordering::Equal;
+        }
+        let (e1, e2) = (&self.events[a], &self.events[b]);
+        let (o1, o2) = (self.other_point(a), self.other_point(b));
+        if orient2d(&e1.point, &o1, &e2.point) != 0.0 || orient2d(&e1.point, &o1, &o2) != 0.0
+        {
+            if e1.point == e2.point
+            {
+                return if self.is_below(a, &o2) { Ordering::Less } else { Ordering::Greater };
+            }
+            if e1.point.x == e2.point.x
+            {
+                return if e1.point.y < e2.point.y { Ordering::Less } else { Ordering::Greater };
+            }
+            // Compare against whichever segment entered the sweep line first. A later
+            // segment starting on the earlier one, up to the tolerance intersections use,
+            // is ordered by where it heads so the two agree.
+            let (earlier, later, flip) = if self.compare_events(a, b) == Ordering::Greater { (b, a, true) } else { (a, b, false) };
+            let (start, end) = (self.events[later].point, self.other_point(later));
+            let (p1, p2) = (self.events[earlier].point, self.other_point(earlier));
+            let probe = if near_segment(&start, &p1, &p2) { end } else { start };
+            if !near_segment(&probe, &p1, &p2)
+            {
+                let order = if self.is_below(earlier, &probe) { Ordering::Less } else { Ordering::Greater };
+                return if flip { order.reverse() } else { order };
+            }
+        }
+        if e1.is_subject != e2.is_subject
+        {
+            return if e1.is_subject { Ordering::Less } else { Ordering::Greater };
+        }
+        if e1.point == e2.point
+        {
+            if o1 == o2
+            {
+                return Ordering::Equal;
+            }
+            return if e1.contour_id > e2.contour_id { Ordering::Greater } else { Ordering::Less };
+        }
+        if self.compare_events(a, b) == Ordering::Greater { Ordering::Greater } else { Ordering::Less }
+    }
+
+    fn push(&mut self, event: usize)
+    {
+        self.queue.push(event);
+        let mut i = self.queue.len() - 1;
+        while i > 0
+        {
+            let parent = (i - 1) / 2;
+            if self.compare_events(self.queue[i], self.queue[parent]) != Ordering::Less
+            {
+                break;
+            }
+            self.queue.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn pop(&mut self) -> Option<usize>
+    {
+        if self.queue.is_empty()
+        {
+            return None;
+        }
+        let top = self.queue.swap_remove(0);
+        let n = self.queue.len();
+        let mut i = 0;
+        loop
+        {
+            let mut smallest = i;
+            for child in [2 * i + 1, 2 * i + 2]
+            {
+                if child < n && self.compare_events(self.queue[child], self.queue[smallest]) == Ordering::Less
+                {
+                    smallest = child;
+                }
+            }
+            if smallest == i
+            {
+                break;
+            }
+            self.queue.swap(i, smallest);
+            i = smallest;
+        }
+        Some(top)
+    }
+
+    fn add_ring(&mut self, ring: &[Point], is_subject: bool, contour_id: usize)
+    {
+        for i in 0..ring.len()
+        {
+            let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+            if a == b
+            {
+                continue;
+            }
+            let e1 = self.add_event(a, false, 0, is_subject, contour_id);
+            let e2 = self.add_event(b, false, e1, is_subject, contour_id);
+            self.events[e1].other = e2;
+            if self.compare_events(e1, e2) == Ordering::Greater
+            {
+                self.events[e2].left = true;
+            }
+            else
+            {
+                self.events[e1].left = true;
+            }
+            self.push(e1);
+            self.push(e2);
+        }
+    }
+
+    // Splits the edge of left event `event` at `point`, queueing the two new endpoints.
+    fn divide_segment(&mut self, event: usize, point: Point)
+    {
+        let other = self.events[event].other;
+        let (is_subject, contour_id) = (self.events[event].is_subject, self.events[event].contour_id);
+        let right = self.add_event(point, false, event, is_subject, contour_id);
+        let left = self.add_event(point, true, other, is_subject, contour_id);
+        // Rounding in `point` can put the new left event after its partner; swap the
+        // roles rather than process an edge backwards.
+        if self.compare_events(left, other) == Ordering::Greater
+        {
+            self.events[other].left = true;
+            self.events[left].left = false;
+        }
+        self.events[other].other = left;
+        self.events[event].other = right;
+        self.push(left);
+        self.push(right);
+    }
+
+    fn compute_fields(&mut self, event: usize, prev: Option<usize>, op: BooleanOp)
+    {
+        match prev
+        {
+            None =>
+            {
+                self.events[event].in_out = false;
+                self.events[event].other_in_out = true;
+            }
+            Some(prev) =>
+            {
+                let p = &self.events[prev];
+                let (in_out, other_in_out) = if self.events[event].is_subject == p.is_subject
+                {
+                    (!p.in_out, p.other_in_out)
+                }
+                else
+                {
+                    (!p.other_in_out, if self.is_vertical(prev) { !p.in_out } else { p.in_out })
+                };
+                let e = &mut self.events[event];
+                e.in_out = in_out;
+                e.other_in_out = other_in_out;
+            }
+        }
+        self.events[event].result_transition = if self.in_result(event, op)
+        {
+            let e = &self.events[event];
+            let this_in = !e.in_out;
+            let that_in = !e.other_in_out;
+            let inside = match (e.edge_type, op)
+            {
+                // An overlapping pair keeps one edge; the other operand's side flips with it
+                // or against it, and `other_in_out` only describes the region below.
+                (EdgeType::SameTransition, _) => this_in,
+                (EdgeType::DifferentTransition, _) => this_in == e.is_subject,
+                (_, BooleanOp::Intersection) => this_in && that_in,
+                (_, BooleanOp::Union) => this_in || that_in,
+                (_, BooleanOp::Xor) => this_in ^ that_in,
+                (_, BooleanOp::Difference) if e.is_subject => this_in && !that_in,
+                (_, BooleanOp::Difference) => that_in && !this_in,
+            };
+            if inside { 1 } else { -1 }
+        }
+        else
+        {
+            0
+        };
+    }
+
+    fn in_result(&self, event: usize, op: BooleanOp) -> bool
+    {
+        let e = &self.events[event];
+        match e.edge_type
+        {
+            EdgeType::Normal => match op
+            {
+                BooleanOp::Intersection => !e.other_in_out,
+                BooleanOp::Union => e.other_in_out,
+                BooleanOp::Difference => e.is_subject == e.other_in_out,
+                BooleanOp::Xor => true,
+            },
+            EdgeType::SameTransition => op == BooleanOp::Intersection || op == BooleanOp::Union,
+            EdgeType::DifferentTransition => op == BooleanOp::Difference,
+            EdgeType::NonContributing => false,
+        }
+    }
+
+    // Checks two neighbouring edges for intersection and splits them where needed. Returns
+    // 0 for no intersection, 1 for a single crossing point, 2 when the edges overlap from
+    // a shared left endpoint and 3 for other overlaps.
+    fn possible_intersection(&mut self, e1: usize, e2: usize) -> u8
+    {
+        let (a1, a2) = (self.events[e1].point, self.other_point(e1));
+        let (b1, b2) = (self.events[e2].point, self.other_point(e2));
+        let (count, points) = segment_intersection(&a1, &a2, &b1, &b2);
+        if count == 0
+        {
+            return 0;
+        }
+        if count == 1 && (a1 == b1 || a2 == b2)
+        {
+            return 0;
+        }
+        // Overlapping edges of the same polygon are invalid input; leave them alone.
+        if count == 2 && self.events[e1].is_subject == self.events[e2].is_subject
+        {
+            return 0;
+        }
+        if count == 1
+        {
+            if a1 != points[0] && a2 != points[0]
+            {
+                self.divide_segment(e1, points[0]);
+            }
+            if b1 != points[0] && b2 != points[0]
+            {
+                self.divide_segment(e2, points[0]);
+            }
+            return 1;
+        }
+
+        let mut events = Vec::with_capacity(4);
+        let left_coincide = a1 == b1;
+        let right_coincide = a2 == b2;
+        if !left_coincide
+        {
+            if self.compare_events(e1, e2) == Ordering::Greater
+            {
+                events.extend([e2, e1]);
+            }
+            else
+            {
+                events.extend([e1, e2]);
+            }
+        }
+        let (r1, r2) = (self.events[e1].other, self.events[e2].other);
+        if !right_coincide
+        {
+            if self.compare_events(r1, r2) == Ordering::Greater
+            {
+                events.extend([r2, r1]);
+            }
+            else
+            {
+                events.extend([r1, r2]);
+            }
+        }
+
+        if left_coincide
+        {
+            // Shared left endpoint: one of the two edges stands for both.
+            self.events[e2].edge_type = EdgeType::NonContributing;
+            self.events[e1].edge_type = if self.events[e2].in_out == self.events[e1].in_out
+            {
+                EdgeType::SameTransition
+            }
+            else
+            {
+                EdgeType::DifferentTransition
+            };
+            if !right_coincide
+            {
+                let longer = self.events[events[1]].other;
+                let split = self.events[events[0]].point;
+                self.divide_segment(longer, split);
+            }
+            return 2;
+        }
+        if right_coincide
+        {
+            let split = self.events[events[1]].point;
+            self.divide_segment(events[0], split);
+            return 3;
+        }
+        if events[0] != self.events[events[3]].other
+        {
+            // Partial overlap.
+            let (first, second) = (self.events[events[1]].point, self.events[events[2]].point);
+            self.divide_segment(events[0], first);
+            self.divide_segment(events[1], second);
+            return 3;
+        }
+        // One edge contains the other.
+        let (first, second) = (self.events[events[1]].point, self.events[events[2]].point);
+        self.divide_segment(events[0], first);
+        // After the first split the outer edge's right end belongs to the new left piece.
+        let outer_left = self.events[events[3]].other;
+        self.divide_segment(outer_left, second);
+        3
+    }
+
+    fn subdivide(&mut self, op: BooleanOp, subject_max_x: f64, clip_max_x: f64) -> Vec<usize>
+    {
+        let mut status: Vec<usize> = Vec::new();
+        let mut sorted = Vec::new();
+        let right_bound = subject_max_x.min(clip_max_x);
+        while let Some(event) = self.pop()
+        {
+            sorted.push(event);
+            // Nothing right of these bounds can contribute.
+            if (op == BooleanOp::Intersection && self.events[event].point.x > right_bound)
+                || (op == BooleanOp::Difference && self.events[event].point.x > subject_max_x)
+            {
+                break;
+            }
+            if self.events[event].left
+            {
+                let position = status.partition_point(|&s| self.compare_segments(s, event) == Ordering::Less);
+                status.insert(position, event);
+                let prev = if position > 0 { Some(status[position - 1]) } else { None };
+                let next = status.get(position + 1).copied();
+                self.compute_fields(event, prev, op);
+                if let Some(next) = next
+                {
+                    if self.possible_intersection(event, next) == 2
+                    {
+                        self.compute_fields(event, prev, op);
+                        self.compute_fields(next, Some(event), op);
+                    }
+                }
+                if let Some(prev) = prev
+                {
+                    if self.possible_intersection(prev, event) == 2
+                    {
+                        let prev_prev = if position > 1 { Some(status[position - 2]) } else { None };
+                        self.compute_fields(prev, prev_prev, op);
+                        self.compute_fields(event, Some(prev), op);
+                    }
+                }
+            }
+            else
+            {
+                let left = self.events[event].other;
+                if let Some(position) = status.iter().position(|&s| s == left)
+                {
+                    let prev = if position > 0 { Some(status[position - 1]) } else { None };
+                    let next = status.get(position + 1).copied();
+                    status.remove(position);
+                    if let (Some(prev), Some(next)) = (prev, next)
+                    {
+                        self.possible_intersection(prev, next);
+                    }
+                }
+            }
+        }
+        sorted
+    }
+
+    // Chains the result edges into rings. Each edge is directed so the result lies on its
+    // left, and at every vertex the walk takes the first outgoing edge clockwise from the
+    // way it came in. That traces each face boundary separately, so rings touching at a
+    // vertex come out as separate simple rings: counter-clockwise exteriors and clockwise
+    // holes. This replaces the paper's contour linking, which can produce pinched rings.
+    fn connect_edges(&self, sorted: &[usize]) -> Vec<Vec<Point>>
+    {
+        let mut vertices: BTreeMap<(u64, u64), usize> = BTreeMap::new();
+        let mut points: Vec<Point> = Vec::new();
+        let mut vertex = |p: Point| {
+            // Adding 0.0 folds -0.0 into 0.0 so both hash to the same vertex.
+            let key = ((p.x + 0.0).to_bits(), (p.y + 0.0).to_bits());
+            *vertices.entry(key).or_insert_with(|| {
+                points.push(p);
+                points.len() - 1
+            })
+        };
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for &event in sorted
+        {
+            let e = &self.events[event];
+            if !e.left || e.result_transition == 0
+            {
+                continue;
+            }
+            let (from, to) = (vertex(e.point), vertex(self.other_point(event)));
+            edges.push(if e.result_transition > 0 { (from, to) } else { (to, from) });
+        }
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); points.len()];
+        for (i, &(from, _)) in edges.iter().enumerate()
+        {
+            outgoing[from].push(i);
+        }
+
+        let mut used = vec![false; edges.len()];
+        let mut rings = Vec::new();
+        for start in 0..edges.len()
+        {
+            if used[start]
+            {
+                continue;
+            }
+            let mut ring = Vec::new();
+            let mut current = start;
+            loop
+            {
+                used[current] = true;
+                let (from, to) = edges[current];
+                ring.push(points[from]);
+                if to == edges[start].0
+                {
+                    break;
+                }
+                let back = (points[from].x - points[to].x, points[from].y - points[to].y);
+                let next = outgoing[to].iter().copied().filter(|&e| !used[e]).min_by(|&a, &b| {
+                    let turn = |e: usize| clockwise_angle(back, (points[edges[e].1].x - points[to].x, points[edges[e].1].y - points[to].y));
+                    turn(a).total_cmp(&turn(b))
+                });
+                match next
+                {
+                    Some(next) => current = next,
+                    None => break,
+                }
+            }
+            split_pinched(ring, &mut rings);
+        }
+        rings
+    }
+}
+
+// Splits a closed walk at repeated vertices into simple loops. Tracing keeps faces that
+// only touch at a vertex apart, but a hole touching its own exterior still comes out as
+// one walk through that vertex twice.
+fn split_pinched(walk: Vec<Point>, rings: &mut Vec<Vec<Point>>)
+{
+    let mut stack: Vec<Point> = Vec::with_capacity(walk.len());
+    for point in walk
+    {
+        if let Some(start) = stack.iter().position(|p| *p == point)
+        {
+            rings.push(stack.split_off(start));
+        }
+        stack.push(point);
+    }
+    rings.push(stack);
+}
+
+// Clockwise rotation in (0, 2pi] taking direction `from` onto direction `to`. Kept in f64
+// rather than going through `Vec2`.
+fn clockwise_angle(from: (f64, f64), to: (f64, f64)) -> f64
+{
+    let angle = atan2_f64(from.1, from.0) - atan2_f64(to.1, to.0);
+    if angle <= 0.0 { angle + TAU } else { angle }
+}
+
+// Whether `point` is inside `ring` and not on its boundary.
+fn strictly_inside(ring: &[Point], point: &Point) -> bool
+{
+    let n = ring.len();
+    !(0..n).any(|i| on_segment(point, &ring[i], &ring[(i + 1) % n])) && ring_winding_number(ring, point) != 0
+}
+
+// Groups rings into polygons, giving each hole to the smallest exterior that contains it.
+fn assemble(rings: Vec<Vec<Point>>) -> Vec<Polygon>
+{
+    let (exteriors, holes): (Vec<_>, Vec<_>) = rings
+        .into_iter()
+        .filter_map(|ring| clean_ring(&ring))
+        .partition(|ring| ring_signed_area(ring) > 0.0);
+    let mut polygons: Vec<Polygon> = exteriors.into_iter().map(Polygon::new).collect();
+    for hole in holes
+    {
+        // A hole may touch its exterior at vertices, but not along all of its edges.
+        let probes = hole.iter().copied().chain((0..hole.len()).map(|i| hole[i].lerp(&hole[(i + 1) % hole.len()], 0.5)));
+        let mut owner: Option<(usize, f64)> = None;
+        for probe in probes
+        {
+            for (i, polygon) in polygons.iter().enumerate()
+            {
+                let area = ring_signed_area(&polygon.exterior);
+                if strictly_inside(&polygon.exterior, &probe) && owner.is_none_or(|(_, best)| area < best)
+                {
+                    owner = Some((i, area));
+                }
+            }
+            if owner.is_some()
+            {
+                break;
+            }
+        }
+        if let Some((i, _)) = owner
+        {
+            polygons[i].holes.push(hole);
+        }
+    }
+    polygons
+}
+
+// Whether `point` lies on segment `ab` up to a few ulps of the coordinates involved.
+fn near_segment(point: &Point, a: &Point, b: &Point) -> bool
+{
+    let scale = [point, a, b].iter().fold(1.0f64, |m, p| m.max(p.x.abs()).max(p.y.abs()));
+    point.distance(&closest_point_on_segment(point, a, b)) <= 64.0 * f64::EPSILON * scale
+}
+
+// Intersection of closed segments `a1a2` and `b1b2`: no point, one point, or the two ends
+// of a collinear overlap. An endpoint within rounding distance of the other segment counts
+// as lying on it and is returned exactly, so edges that were split at computed points in
+// an earlier operation still overlap instead of crossing at arbitrary places.
+fn segment_intersection(a1: &Point, a2: &Point, b1: &Point, b2: &Point) -> (usize, [Point; 2])
+{
+    let (mut touching, mut count) = ([*a1; 4], 0);
+    for (p, q1, q2) in [(a1, b1, b2), (a2, b1, b2), (b1, a1, a2), (b2, a1, a2)]
+    {
+        if near_segment(p, q1, q2) && !touching[..count].contains(p)
+        {
+            touching[count] = *p;
+            count += 1;
+        }
+    }
+    let (vax, vay) = (a2.x - a1.x, a2.y - a1.y);
+    match count
+    {
+        0 => {}
+        1 => return (1, [touching[0]; 2]),
+        _ =>
+        {
+            // Order the shared points along `a` and keep the two ends of the overlap.
+            let param = |p: &Point| (p.x - a1.x) * vax + (p.y - a1.y) * vay;
+            let touching = &mut touching[..count];
+            touching.sort_unstable_by(|x, y| param(x).total_cmp(&param(y)));
+            return (2, [touching[0], touching[count - 1]]);
+        }
+    }
+
+    // With no endpoint on the other segment, they cross only if each separates the
+    // other's endpoints strictly.
+    let (d1, d2) = (orient2d(b1, b2, a1), orient2d(b1, b2, a2));
+    let (d3, d4) = (orient2d(a1, a2, b1), orient2d(a1, a2, b2));
+    if !((d1 < 0.0 && d2 > 0.0) || (d1 > 0.0 && d2 < 0.0)) || !((d3 < 0.0 && d4 > 0.0) || (d3 > 0.0 && d4 < 0.0))
+    {
+        return (0, [*a1; 2]);
+    }
+    let s = (d1 / (d1 - d2)).clamp(0.0, 1.0);
+    (1, [Point::new(a1.x + s * vax, a1.y + s * vay); 2])
+}
+
+// Drops repeated and collinear vertices; `None` if fewer than three remain.
+pub(super) fn clean_ring(points: &[Point]) -> Option<Vec<Point>>
+{
+    let mut ring: Vec<Point> = Vec::with_capacity(points.len());
+    for point in points
+    {
+        if ring.last() != Some(point)
+        {
+            ring.push(*point);
+        }
+    }
+    while ring.len() > 1 && ring.first() == ring.last()
+    {
+        ring.pop();
+    }
+    let mut changed = true;
+    while changed && ring.len() >= 3
+    {
+        changed = false;
+        let mut i = 0;
+        while i < ring.len() && ring.len() >= 3
+        {
+            let n = ring.len();
+            let (prev, next) = (ring[(i + n - 1) % n], ring[(i + 1) % n]);
+            if orient2d(&prev, &ring[i], &next) == 0.0
+            {
+                ring.remove(i);
+                changed = true;
+            }
+            else
+            {
+                i += 1;
+            }
+        }
+    }
+    if ring.len() >= 3 { Some(ring) } else { None }
+}
+
+fn max_x(polygons: &[Polygon]) -> f64
+{
+    polygons.iter().flat_map(|p| p.exterior.iter()).map(|p| p.x).fold(f64::NEG_INFINITY, f64::max)
+}
+
+fn bounds(polygons: &[Polygon]) -> Option<[f64; 4]>
+{
+    let mut points = polygons.iter().flat_map(|p| p.exterior.iter()).peekable();
+    points.peek()?;
+    Some(points.fold([f64::INFINITY, f64::INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY], |b, p| {
+        [b[0].min(p.x), b[1].min(p.y), b[2].max(p.x), b[3].max(p.y)]
+    }))
+}
+
+// Combines two sets of polygons with `op`.
+pub fn boolean_op(subject: &[Polygon], clip: &[Polygon], op: BooleanOp) -> Vec<Polygon>
+{
+    let (subject_bounds, clip_bounds) = match (bounds(subject), bounds(clip))
+    {
+        (Some(s), Some(c)) => (s, c),
+        (None, _) => return if op == BooleanOp::Union || op == BooleanOp::Xor { normalized(clip) } else { Vec::new() },
+        (_, None) => return if op == BooleanOp::Intersection { Vec::new() } else { normalized(subject) },
+    };
+    let disjoint = subject_bounds[0] > clip_bounds[2]
+        || clip_bounds[0] > subject_bounds[2]
+        || subject_bounds[1] > clip_bounds[3]
+        || clip_bounds[1] > subject_bounds[3];
+    if disjoint
+    {
+        return match op
+        {
+            BooleanOp::Intersection => Vec::new(),
+            BooleanOp::Difference => normalized(subject),
+            BooleanOp::Union | BooleanOp::Xor => normalized(subject).into_iter().chain(normalized(clip)).collect(),
+        };
+    }
+
+    let mut sweep = Sweep { events: Vec::new(), queue: Vec::new() };
+    let mut contour_id = 0;
+    for (polygons, is_subject) in [(subject, true), (clip, false)]
+    {
+        for polygon in polygons
+        {
+            // Cleaning drops zero-width spikes and flat rings, whose overlapping edges
+            // the sweep cannot handle within one operand.
+            for ring in core::iter::once(&polygon.exterior).chain(polygon.holes.iter()).filter_map(|r| clean_ring(r))
+            {
+                sweep.add_ring(&ring, is_subject, contour_id);
+                contour_id += 1;
+            }
+        }
+    }
+    let sorted = sweep.subdivide(op, max_x(subject), max_x(clip));
+    assemble(sweep.connect_edges(&sorted))
+}
+
+// The operands as the sweep would return them, for the cases that skip it.
+fn normalized(polygons: &[Polygon]) -> Vec<Polygon>
+{
+    polygons
+        .iter()
+        .filter_map(|p| {
+            let exterior = clean_ring(&p.exterior)?;
+            let mut p = Polygon::with_holes(exterior, p.holes.iter().filter_map(|h| clean_ring(h)).collect());
+            p.normalize_orientation();
+            Some(p)
+        })
+        .collect()
+}
+
+impl Polygon
+{
+    pub fn union(&self, other: &Polygon) -> Vec<Polygon>
+    {
+        boolean_op(core::slice::from_ref(self), core::slice::from_ref(other), BooleanOp::Union)
+    }
+
+    pub fn intersection(&self, other: &Polygon) -> Vec<Polygon>
+    {
+        boolean_op(core::slice::from_ref(self), core::slice::from_ref(other), BooleanOp::Intersection)
+    }
+
+    pub fn difference(&self, other: &Polygon) -> Vec<Polygon>
+    {
+        boolean_op(core::slice::from_ref(self), core::slice::from_ref(other), BooleanOp::Difference)
+    }
+
+    pub fn xor(&self, other: &Polygon) -> Vec<Polygon>
+    {
+        boolean_op(core::slice::from_ref(self), core::slice::from_ref(other), BooleanOp::Xor)
+    }
+}
@@ -0,0 +1,376 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::predicates::{ incircle, orient2d };
+use super::{ Point, Polygon, Rectangle, Triangle };
+
+// Incremental Delaunay triangulation (Bowyer-Watson). Hull edges are closed off by "ghost"
+// faces sharing a vertex at infinity, so every point can be inserted the same way wherever
+// it lands and no bounding super-triangle leaks into the result.
+
+// The vertex at infinity.
+const GHOST: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct Face
+{
+    // Counter-clockwise; a ghost face has `GHOST` in place of one vertex.
+    vertices: [usize; 3],
+    // `neighbors[i]` lies across the edge opposite `vertices[i]`.
+    neighbors: [usize; 3],
+}
+
+impl Face
+{
+    fn is_ghost(&self) -> bool
+    {
+        self.vertices.contains(&GHOST)
+    }
+
+    // The edge opposite `vertices[i]`, in counter-clockwise order.
+    fn edge(&self, i: usize) -> (usize, usize)
+    {
+        (self.vertices[(i + 1) % 3], self.vertices[(i + 2) % 3])
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Delaunay
+{
+    vertices: Vec<Point>,
+    faces: Vec<Face>,
+    // One face around each vertex, for walking and for orbiting the vertex.
+    vertex_face: Vec<usize>,
+}
+
+impl Delaunay
+{
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    // Inserts `points` in order. Repeated points are merged, so vertex indices match input
+    // positions only when the points are distinct.
+    pub fn from_points(points: &[Point]) -> Self
+    {
+        let mut delaunay = Self::new();
+        for point in points
+        {
+            delaunay.insert(*point);
+        }
+        delaunay
+    }
+
+    pub fn vertices(&self) -> &[Point]
+    {
+        &self.vertices
+    }
+
+    // Real (finite) triangles as counter-clockwise vertex indices. Empty until three
+    // non-collinear points have been inserted.
+    pub fn triangles(&self) -> Vec<[usize; 3]>
+    {
+        self.faces.iter().filter(|f| !f.is_ghost()).map(|f| f.vertices).collect()
+    }
+
+    pub fn to_triangles(&self) -> Vec<Triangle>
+    {
+        self.faces
+            .iter()
+            .filter(|f| !f.is_ghost())
+            .map(|f| Triangle::new(self.vertices[f.vertices[0]], self.vertices[f.vertices[1]], self.vertices[f.vertices[2]]))
+            .collect()
+    }
+
+    // Adds `point` and returns its vertex index, or the index of the vertex already there.
+    pub fn insert(&mut self, point: Point) -> usize
+    {
+        if self.faces.is_empty()
+        {
+            if let Some(existing) = self.vertices.iter().position(|v| *v == point)
+            {
+                return existing;
+            }
+            self.vertices.push(point);
+            self.vertex_face.push(GHOST);
+            let n = self.vertices.len();
+            // Until three points span a triangle they are only collected.
+            if n >= 3 && orient2d(&self.vertices[0], &self.vertices[1], &point) != 0.0
+            {
+                self.start(n - 1);
+            }
+            return n - 1;
+        }
+
+        let face = self.locate_face(&point, self.vertex_face[self.vertices.len() - 1]);
+        if let Some(&existing) = self.faces[face].vertices.iter().find(|&&v| v != GHOST && self.vertices[v] == point)
+        {
+            return existing;
+        }
+        self.vertices.push(point);
+        self.vertex_face.push(GHOST);
+        let index = self.vertices.len() - 1;
+        self.insert_vertex(index, face);
+        index
+    }
+
+    // Builds the first triangle from the first two points and `third`, then inserts the
+    // collinear points collected so far.
+    fn start(&mut self, third: usize)
+    {
+        let (a, b) = if orient2d(&self.vertices[0], &self.vertices[1], &self.vertices[third]) > 0.0 { (0, 1) } else { (1, 0) };
+        let c = third;
+        self.faces = vec![
+            Face { vertices: [a, b, c], neighbors: [2, 3, 1] },
+            Face { vertices: [b, a, GHOST], neighbors: [3, 2, 0] },
+            Face { vertices: [c, b, GHOST], neighbors: [1, 3, 0] },
+            Face { vertices: [a, c, GHOST], neighbors: [2, 1, 0] },
+        ];
+        for v in [a, b, c]
+        {
+            self.vertex_face[v] = 0;
+        }
+        for index in 2..self.vertices.len()
+        {
+            if index != third
+            {
+                let face = self.locate_face(&self.vertices[index], 0);
+                self.insert_vertex(index, face);
+            }
+        }
+    }
+
+    // Whether `point` lies inside the circumcircle of `face`. For a ghost face that is the
+    // open half-plane beyond its hull edge, plus the inside of the edge itself.
+    fn in_conflict(&self, face: usize, point: &Point) -> bool
+    {
+        let f = &self.faces[face];
+        if let Some(g) = f.vertices.iter().position(|&v| v == GHOST)
+        {
+            let (a, b) = f.edge(g);
+            let (a, b) = (&self.vertices[a], &self.vertices[b]);
+            let side = orient2d(a, b, point);
+            return side > 0.0 || (side == 0.0 && (point.x - a.x) * (point.x - b.x) + (point.y - a.y) * (point.y - b.y) < 0.0);
+        }
+        let [a, b, c] = f.vertices;
+        incircle(&self.vertices[a], &self.vertices[b], &self.vertices[c], point) > 0.0
+    }
+
+    // Visibility walk from `start` to the face containing `point`, or to the ghost face
+    // beyond the hull edge it lies outside of.
+    fn locate_face(&self, point: &Point, start: usize) -> usize
+    {
+        let mut face = if start < self.faces.len() { start } else { 0 };
+        if let Some(g) = self.faces[face].vertices.iter().position(|&v| v == GHOST)
+        {
+            face = self.faces[face].neighbors[g];
+        }
+        // Rotating which edge is tried first keeps the walk from circling.
+        let mut turn = 0;
+        'walk: loop
+        {
+            let f = &self.faces[face];
+            if f.is_ghost()
+            {
+                return face;
+            }
+            turn += 1;
+            for k in 0..3
+            {
+                let i = (k + turn) % 3;
+                let (a, b) = f.edge(i);
+                if orient2d(&self.vertices[a], &self.vertices[b], point) < 0.0
+                {
+                    face = f.neighbors[i];
+                    continue 'walk;
+                }
+            }
+            return face;
+        }
+    }
+
+    // Bowyer-Watson step: removes every face whose circumcircle contains the vertex and
+    // fans the hole left behind from it.
+    fn insert_vertex(&mut self, vertex: usize, face: usize)
+    {
+        let point = self.vertices[vertex];
+        let mut cavity = vec![face];
+        let mut i = 0;
+        while i < cavity.len()
+        {
+            for neighbor in self.faces[cavity[i]].neighbors
+            {
+                if !cavity.contains(&neighbor) && self.in_conflict(neighbor, &point)
+                {
+                    cavity.push(neighbor);
+                }
+            }
+            i += 1;
+        }
+
+        // Cavity edges facing faces that stay, each with the face beyond it.
+        let mut boundary = Vec::new();
+        for &c in &cavity
+        {
+            let f = self.faces[c];
+            for i in 0..3
+            {
+                if !cavity.contains(&f.neighbors[i])
+                {
+                    boundary.push((f.edge(i), f.neighbors[i]));
+                }
+            }
+        }
+
+        // A new vertex always adds two faces, so the cavity's slots are reused.
+        let mut created = Vec::with_capacity(boundary.len());
+        for (k, &((a, b), outside)) in boundary.iter().enumerate()
+        {
+            let slot = if k < cavity.len()
+            {
+                cavity[k]
+            }
+            else
+            {
+                self.faces.push(Face { vertices: [GHOST; 3], neighbors: [GHOST; 3] });
+                self.faces.len() - 1
+            };
+            self.faces[slot] = Face { vertices: [a, b, vertex], neighbors: [GHOST, GHOST, outside] };
+            let back = &mut self.faces[outside];
+            if let Some(j) = (0..3).find(|&j| back.edge(j) == (b, a))
+            {
+                back.neighbors[j] = slot;
+            }
+            created.push(slot);
+        }
+        // The fan's faces meet along the spokes from the new vertex.
+        for &slot in &created
+        {
+            let [a, b, _] = self.faces[slot].vertices;
+            let after = created.iter().copied().find(|&s| self.faces[s].vertices[0] == b);
+            let before = created.iter().copied().find(|&s| self.faces[s].vertices[1] == a);
+            let f = &mut self.faces[slot];
+            f.neighbors[0] = after.unwrap_or(GHOST);
+            f.neighbors[1] = before.unwrap_or(GHOST);
+            for v in [a, b, vertex]
+            {
+                if v != GHOST
+                {
+                    self.vertex_face[v] = slot;
+                }
+            }
+        }
+    }
+
+    // Vertices sharing an edge with `vertex`, counter-clockwise around it.
+    pub fn neighbors(&self, vertex: usize) -> Vec<usize>
+    {
+        let mut result = Vec::new();
+        if self.faces.is_empty() || vertex >= self.vertices.len()
+        {
+            return result;
+        }
+        let start = self.vertex_face[vertex];
+        let mut face = start;
+        loop
+        {
+            let f = &self.faces[face];
+            let i = (0..3).find(|&i| f.vertices[i] == vertex).unwrap_or(0);
+            let next = f.vertices[(i + 1) % 3];
+            if next != GHOST
+            {
+                result.push(next);
+            }
+            // On to the next face counter-clockwise, across the edge from `vertex` to the
+            // third corner.
+            face = f.neighbors[(i + 1) % 3];
+            if face == start
+            {
+                return result;
+            }
+        }
+    }
+
+    // The triangle containing `point` (boundary included), or `None` outside the hull.
+    pub fn locate(&self, point: &Point) -> Option<[usize; 3]>
+    {
+        if self.faces.is_empty()
+        {
+            return None;
+        }
+        let face = &self.faces[self.locate_face(point, 0)];
+        if face.is_ghost() { None } else { Some(face.vertices) }
+    }
+
+    // The vertex nearest to `point`. Walks to the enclosing face and then greedily towards
+    // closer neighbours, which in a Delaunay triangulation always ends at the nearest one.
+    pub fn nearest(&self, point: &Point) -> Option<usize>
+    {
+        let distance = |v: usize| {
+            let p = &self.vertices[v];
+            (p.x - point.x) * (p.x - point.x) + (p.y - point.y) * (p.y - point.y)
+        };
+        if self.faces.is_empty()
+        {
+            return (0..self.vertices.len()).min_by(|&a, &b| distance(a).total_cmp(&distance(b)));
+        }
+        let face = &self.faces[self.locate_face(point, 0)];
+        let mut current = face.vertices.iter().copied().filter(|&v| v != GHOST).min_by(|&a, &b| distance(a).total_cmp(&distance(b)))?;
+        loop
+        {
+            let best = self.neighbors(current).into_iter().min_by(|&a, &b| distance(a).total_cmp(&distance(b)));
+            match best
+            {
+                Some(best) if distance(best) < distance(current) => current = best,
+                _ => return Some(current),
+            }
+        }
+    }
+
+    // The Voronoi cell of every vertex, clipped to `bounds`, in vertex order; `None` for
+    // cells missing the rectangle. Each cell is the rectangle cut by the bisectors with the
+    // vertex's Delaunay neighbours.
+    pub fn voronoi(&self, bounds: &Rectangle) -> Vec<Option<Polygon>>
+    {
+        let (x0, y0) = (bounds.top_left.x, bounds.top_left.y);
+        let (x1, y1) = (x0 + bounds.width, y0 + bounds.height);
+        (0..self.vertices.len())
+            .map(|v| {
+                let site = self.vertices[v];
+                // Collinear points have no triangles; every other point is a neighbour.
+                let neighbors = if self.faces.is_empty() { (0..self.vertices.len()).filter(|&u| u != v).collect() } else { self.neighbors(v) };
+                let mut cell = vec![Point::new(x0, y0), Point::new(x1, y0), Point::new(x1, y1), Point::new(x0, y1)];
+                for u in neighbors
+                {
+                    let other = self.vertices[u];
+                    let normal = (other.x - site.x, other.y - site.y);
+                    let mid = site.lerp(&other, 0.5);
+                    cell = clip_half_plane(&cell, |p| (p.x - mid.x) * normal.0 + (p.y - mid.y) * normal.1);
+                }
+                if cell.len() >= 3 { Some(Polygon::new(cell)) } else { None }
+            })
+            .collect()
+    }
+}
+
+// Sutherland-Hodgman against one line: keeps the part of the convex `ring` where `side` is
+// not positive.
+fn clip_half_plane(ring: &[Point], side: impl Fn(&Point) -> f64) -> Vec<Point>
+{
+    let mut result = Vec::with_capacity(ring.len() + 1);
+    for i in 0..ring.len()
+    {
+        let (a, b) = (&ring[i], &ring[(i + 1) % ring.len()]);
+        let (sa, sb) = (side(a), side(b));
+        if sa <= 0.0
+        {
+            result.push(*a);
+        }
+        if (sa < 0.0 && sb > 0.0) || (sa > 0.0 && sb < 0.0)
+        {
+            result.push(a.lerp(b, sa / (sa - sb)));
+        }
+    }
+    result
+}
@@ -0,0 +1,384 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::polygon::{ on_segment, ring_signed_area };
+use super::predicates::{ orient2d, orient3d };
+use super::{ cross3, dot3, sub3, Point, Point3D, Polygon };
+use crate::math::sqrt_f64;
+use crate::vector::{ Vec2, Vec3 };
+
+// Convex hulls: Andrew's monotone chain in 2D and quickhull in 3D (Barber et al., "The
+// Quickhull Algorithm for Convex Hulls", 1996). Points lying on a hull edge or face are not
+// hull vertices.
+
+// Six times the signed volume of `a, b, c, d`; positive when `d` lies on the side the
+// counter-clockwise triangle `a, b, c` faces.
+#[inline(always)]
+fn orient_3d(a: &Point3D, b: &Point3D, c: &Point3D, d: &Point3D) -> f64
+{
+    -orient3d(a, b, c, d)
+}
+
+// Indices of the hull vertices of `points`, counter-clockwise from the lowest-x point.
+// Fewer than three points come back when all of them are collinear.
+pub fn convex_hull_indices(points: &[Point]) -> Vec<usize>
+{
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_unstable_by(|&a, &b| points[a].x.total_cmp(&points[b].x).then(points[a].y.total_cmp(&points[b].y)));
+    order.dedup_by(|a, b| points[*a] == points[*b]);
+    if order.len() < 3
+    {
+        return order;
+    }
+    let mut hull: Vec<usize> = Vec::with_capacity(order.len() + 1);
+    // Lower chain left to right, then upper chain right to left without popping back into
+    // the lower one.
+    let mut floor = 2;
+    for (k, &i) in order.iter().chain(order.iter().rev().skip(1)).enumerate()
+    {
+        if k == order.len()
+        {
+            floor = hull.len() + 1;
+        }
+        while hull.len() >= floor && orient2d(&points[hull[hull.len() - 2]], &points[hull[hull.len() - 1]], &points[i]) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+    hull.pop();
+    hull
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull
+{
+    // Counter-clockwise, without repeated or collinear vertices.
+    pub vertices: Vec<Point>,
+}
+
+impl ConvexHull
+{
+    pub fn new(points: &[Point]) -> Self
+    {
+        Self { vertices: convex_hull_indices(points).into_iter().map(|i| points[i]).collect() }
+    }
+
+    pub fn from_vec2(points: &[Vec2]) -> Self
+    {
+        let points: Vec<Point> = points.iter().map(Point::from_vec2).collect();
+        Self::new(&points)
+    }
+
+    // Boundary included. A hull of collinear points contains the segment between its ends.
+    pub fn contains(&self, point: &Point) -> bool
+    {
+        let v = &self.vertices;
+        match v.len()
+        {
+            0 => false,
+            1 => v[0] == *point,
+            2 => on_segment(point, &v[0], &v[1]),
+            n => (0..n).all(|i| orient2d(&v[i], &v[(i + 1) % n], point) >= 0.0),
+        }
+    }
+
+    // The hull vertex furthest along `direction`.
+    pub fn support(&self, direction: &Vec2) -> Option<Point>
+    {
+        let (dx, dy) = (direction.x as f64, direction.y as f64);
+        self.vertices.iter().copied().max_by(|a, b| (a.x * dx + a.y * dy).total_cmp(&(b.x * dx + b.y * dy)))
+    }
+
+    pub fn area(&self) -> f64
+    {
+        ring_signed_area(&self.vertices)
+    }
+
+    pub fn perimeter(&self) -> f64
+    {
+        // A two-point hull is a segment traversed both ways.
+        let n = self.vertices.len();
+        (0..n).map(|i| self.vertices[i].distance(&self.vertices[(i + 1) % n])).sum()
+    }
+
+    pub fn to_polygon(&self) -> Polygon
+    {
+        Polygon::new(self.vertices.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HullFace
+{
+    // Indices into `ConvexHull3D::points`, counter-clockwise seen from outside.
+    pub vertices: [usize; 3],
+    // Unit outward normal.
+    pub normal: Vec3,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull3D
+{
+    pub points: Vec<Point3D>,
+    pub faces: Vec<HullFace>,
+}
+
+// A face under construction, with the points still outside it.
+struct Facet
+{
+    vertices: [usize; 3],
+    // `neighbors[i]` lies across the edge opposite `vertices[i]`.
+    neighbors: [usize; 3],
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl Facet
+{
+    fn edge(&self, i: usize) -> (usize, usize)
+    {
+        (self.vertices[(i + 1) % 3], self.vertices[(i + 2) % 3])
+    }
+}
+
+// Sets each facet's neighbours by matching opposite edges among `ids`.
+fn link(facets: &mut [Facet], ids: &[usize])
+{
+    for &f in ids
+    {
+        for i in 0..3
+        {
+            let (a, b) = facets[f].edge(i);
+            if let Some(&g) = ids.iter().find(|&&g| g != f && (0..3).any(|j| facets[g].edge(j) == (b, a)))
+            {
+                facets[f].neighbors[i] = g;
+            }
+        }
+    }
+}
+
+impl ConvexHull3D
+{
+    // `None` when the points do not span a volume.
+    pub fn new(points: &[Point3D]) -> Option<Self>
+    {
+        let p = points;
+        let distance_squared = |a: usize, b: usize| {
+            let d = sub3(&p[a], &p[b]);
+            dot3(&d, &d)
+        };
+        let argmax = |key: &dyn Fn(usize) -> f64| (0..p.len()).max_by(|&a, &b| key(a).total_cmp(&key(b)));
+
+        // Initial tetrahedron: the widest pair of axis extremes, then the points furthest
+        // from their line and from the plane through all three.
+        let mut extremes = Vec::with_capacity(6);
+        for axis in 0..3
+        {
+            let coordinate = |i: usize| [p[i].x, p[i].y, p[i].z][axis];
+            extremes.push(argmax(&|i| -coordinate(i))?);
+            extremes.push(argmax(&|i| coordinate(i))?);
+        }
+        let (mut i0, mut i1, mut widest) = (0, 0, 0.0);
+        for &a in &extremes
+        {
+            for &b in &extremes
+            {
+                if distance_squared(a, b) > widest
+                {
+                    (i0, i1, widest) = (a, b, distance_squared(a, b));
+                }
+            }
+        }
+        if widest == 0.0
+        {
+            return None;
+        }
+        let line = sub3(&p[i1], &p[i0]);
+        let i2 = argmax(&|i| {
+            let c = cross3(&line, &sub3(&p[i], &p[i0]));
+            dot3(&c, &c)
+        })?;
+        let i3 = argmax(&|i| orient_3d(&p[i0], &p[i1], &p[i2], &p[i]).abs())?;
+        if orient_3d(&p[i0], &p[i1], &p[i2], &p[i3]) == 0.0
+        {
+            return None;
+        }
+
+        let mut facets = Vec::new();
+        let corners = [i0, i1, i2, i3];
+        for skip in 0..4
+        {
+            let [a, b, c] = match skip
+            {
+                0 => [i1, i2, i3],
+                1 => [i0, i2, i3],
+                2 => [i0, i1, i3],
+                _ => [i0, i1, i2],
+            };
+            // Orient so the opposite corner lies behind the face.
+            let vertices = if orient_3d(&p[a], &p[b], &p[c], &p[corners[skip]]) > 0.0 { [a, c, b] } else { [a, b, c] };
+            facets.push(Facet { vertices, neighbors: [0; 3], outside: Vec::new(), alive: true });
+        }
+        link(&mut facets, &[0, 1, 2, 3]);
+        let above = |facets: &[Facet], f: usize, i: usize| {
+            let [a, b, c] = facets[f].vertices;
+            orient_3d(&p[a], &p[b], &p[c], &p[i])
+        };
+        for i in 0..p.len()
+        {
+            if let Some(f) = (0..4).find(|&f| above(&facets, f, i) > 0.0)
+            {
+                facets[f].outside.push(i);
+            }
+        }
+
+        let mut pending: Vec<usize> = (0..4).collect();
+        while let Some(f) = pending.pop()
+        {
+            if !facets[f].alive || facets[f].outside.is_empty()
+            {
+                continue;
+            }
+            // The point furthest from the face is certainly a hull vertex.
+            let apex = *facets[f].outside.iter().max_by(|&&a, &&b| above(&facets, f, a).total_cmp(&above(&facets, f, b)))?;
+
+            // Faces the apex can see, and the horizon edges bounding them.
+            let mut visible = vec![f];
+            let mut k = 0;
+            while k < visible.len()
+            {
+                for g in facets[visible[k]].neighbors
+                {
+                    if !visible.contains(&g) && above(&facets, g, apex) > 0.0
+                    {
+                        visible.push(g);
+                    }
+                }
+                k += 1;
+            }
+            let mut horizon = Vec::new();
+            for &v in &visible
+            {
+                for i in 0..3
+                {
+                    let g = facets[v].neighbors[i];
+                    if !visible.contains(&g)
+                    {
+                        horizon.push((facets[v].edge(i), g));
+                    }
+                }
+            }
+
+            let mut orphans = Vec::new();
+            for &v in &visible
+            {
+                facets[v].alive = false;
+                orphans.append(&mut facets[v].outside);
+            }
+            let mut created = Vec::with_capacity(horizon.len());
+            for &((a, b), beyond) in &horizon
+            {
+                let id = facets.len();
+                facets.push(Facet { vertices: [a, b, apex], neighbors: [0, 0, beyond], outside: Vec::new(), alive: true });
+                if let Some(j) = (0..3).find(|&j| facets[beyond].edge(j) == (b, a))
+                {
+                    facets[beyond].neighbors[j] = id;
+                }
+                created.push(id);
+            }
+            link(&mut facets, &created);
+            for i in orphans
+            {
+                if i == apex
+                {
+                    continue;
+                }
+                if let Some(&g) = created.iter().find(|&&g| above(&facets, g, i) > 0.0)
+                {
+                    facets[g].outside.push(i);
+                }
+            }
+            pending.extend(created);
+        }
+
+        let faces = facets
+            .iter()
+            .filter(|f| f.alive)
+            .map(|f| {
+                let [a, b, c] = f.vertices;
+                let n = cross3(&sub3(&p[b], &p[a]), &sub3(&p[c], &p[a]));
+                let length = sqrt_f64(dot3(&n, &n));
+                HullFace { vertices: f.vertices, normal: Vec3::new((n[0] / length) as f32, (n[1] / length) as f32, (n[2] / length) as f32) }
+            })
+            .collect();
+        Some(Self { points: points.to_vec(), faces })
+    }
+
+    pub fn from_vec3(points: &[Vec3]) -> Option<Self>
+    {
+        let points: Vec<Point3D> = points.iter().map(Point3D::from_vec3).collect();
+        Self::new(&points)
+    }
+
+    // Indices of the points that are hull vertices, ascending.
+    pub fn vertex_indices(&self) -> Vec<usize>
+    {
+        let mut indices: Vec<usize> = self.faces.iter().flat_map(|f| f.vertices).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    // Boundary included.
+    pub fn contains(&self, point: &Point3D) -> bool
+    {
+        self.faces.iter().all(|f| {
+            let [a, b, c] = f.vertices;
+            orient_3d(&self.points[a], &self.points[b], &self.points[c], point) <= 0.0
+        })
+    }
+
+    // The hull vertex furthest along `direction`.
+    pub fn support(&self, direction: &Vec3) -> Point3D
+    {
+        let d = [direction.x as f64, direction.y as f64, direction.z as f64];
+        let reach = |p: &Point3D| p.x * d[0] + p.y * d[1] + p.z * d[2];
+        self.faces
+            .iter()
+            .flat_map(|f| f.vertices)
+            .map(|i| self.points[i])
+            .max_by(|a, b| reach(a).total_cmp(&reach(b)))
+            .unwrap_or(self.points[0])
+    }
+
+    pub fn volume(&self) -> f64
+    {
+        // Tetrahedra from one hull vertex to every face.
+        let Some(origin) = self.faces.first().map(|f| self.points[f.vertices[0]])
+        else
+        {
+            return 0.0;
+        };
+        self.faces
+            .iter()
+            .map(|f| {
+                let [a, b, c] = f.vertices;
+                orient_3d(&self.points[a], &self.points[b], &self.points[c], &origin)
+            })
+            .sum::<f64>()
+            / -6.0
+    }
+
+    pub fn surface_area(&self) -> f64
+    {
+        self.faces
+            .iter()
+            .map(|f| {
+                let [a, b, c] = f.vertices;
+                let n = cross3(&sub3(&self.points[b], &self.points[a]), &sub3(&self.points[c], &self.points[a]));
+                0.5 * sqrt_f64(dot3(&n, &n))
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,245 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::polygon::on_segment;
+use super::predicates::orient2d;
+use super::{ closest_point_on_segment, Circle, Line, Point, Rectangle };
+use crate::math::sqrt_f64;
+
+// Intersection, distance, tangency and clipping queries between segments, lines, circles
+// and rectangles. A `Line` is the closed segment from `start` to `end` unless a method
+// says it treats it as the infinite line through them. Touching counts as intersecting,
+// and sidedness is decided with the exact predicates.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection
+{
+    Disjoint,
+    Point(Point),
+    // The ends of a collinear overlap, in the direction of the first segment.
+    Overlap(Point, Point),
+}
+
+impl Line
+{
+    pub fn intersection(&self, other: &Line) -> SegmentIntersection
+    {
+        let (a1, a2, b1, b2) = (&self.start, &self.end, &other.start, &other.end);
+        if a1 == a2 || b1 == b2
+        {
+            let (p, q1, q2) = if a1 == a2 { (a1, b1, b2) } else { (b1, a1, a2) };
+            return if on_segment(p, q1, q2) { SegmentIntersection::Point(*p) } else { SegmentIntersection::Disjoint };
+        }
+
+        let (d1, d2) = (orient2d(b1, b2, a1), orient2d(b1, b2, a2));
+        if d1 == 0.0 && d2 == 0.0
+        {
+            // Collinear: clip `other` to `self` by parameter along `self`, keeping input
+            // endpoints rather than recomputing them.
+            let (vx, vy) = (a2.x - a1.x, a2.y - a1.y);
+            let param = |p: &Point| ((p.x - a1.x) * vx + (p.y - a1.y) * vy) / (vx * vx + vy * vy);
+            let (near, far) = if param(b1) <= param(b2) { (b1, b2) } else { (b2, b1) };
+            let (t_near, t_far) = (param(near), param(far));
+            if t_near > 1.0 || t_far < 0.0
+            {
+                return SegmentIntersection::Disjoint;
+            }
+            let first = if t_near > 0.0 { *near } else { *a1 };
+            let last = if t_far < 1.0 { *far } else { *a2 };
+            return if first == last { SegmentIntersection::Point(first) } else { SegmentIntersection::Overlap(first, last) };
+        }
+
+        let (d3, d4) = (orient2d(a1, a2, b1), orient2d(a1, a2, b2));
+        let straddles = |p: f64, q: f64| (p <= 0.0 && q >= 0.0) || (p >= 0.0 && q <= 0.0);
+        if !straddles(d1, d2) || !straddles(d3, d4)
+        {
+            return SegmentIntersection::Disjoint;
+        }
+        // An endpoint on the other segment is returned as is.
+        let point = match (d1 == 0.0, d2 == 0.0, d3 == 0.0, d4 == 0.0)
+        {
+            (true, _, _, _) => *a1,
+            (_, true, _, _) => *a2,
+            (_, _, true, _) => *b1,
+            (_, _, _, true) => *b2,
+            _ => a1.lerp(a2, d1 / (d1 - d2)),
+        };
+        SegmentIntersection::Point(point)
+    }
+
+    // Treats both as infinite lines; `None` when they are parallel or either is a point.
+    pub fn line_intersection(&self, other: &Line) -> Option<Point>
+    {
+        let (vax, vay) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        let (vbx, vby) = (other.end.x - other.start.x, other.end.y - other.start.y);
+        let denominator = vax * vby - vay * vbx;
+        if denominator == 0.0
+        {
+            return None;
+        }
+        let (ex, ey) = (other.start.x - self.start.x, other.start.y - self.start.y);
+        Some(self.start.lerp(&self.end, (ex * vby - ey * vbx) / denominator))
+    }
+
+    pub fn closest_point(&self, point: &Point) -> Point
+    {
+        closest_point_on_segment(point, &self.start, &self.end)
+    }
+
+    pub fn distance_to_point(&self, point: &Point) -> f64
+    {
+        self.closest_point(point).distance(point)
+    }
+
+    // Zero, one or two points, ordered from `start` to `end`. A segment tangent to the
+    // circle touches it once.
+    pub fn circle_intersections(&self, circle: &Circle) -> Vec<Point>
+    {
+        let (vx, vy) = (self.end.x - self.start.x, self.end.y - self.start.y);
+        let (fx, fy) = (self.start.x - circle.center.x, self.start.y - circle.center.y);
+        // `|start + t v - center|^2 = r^2` as `a t^2 + 2 b t + c = 0`.
+        let a = vx * vx + vy * vy;
+        let b = fx * vx + fy * vy;
+        let c = fx * fx + fy * fy - circle.radius * circle.radius;
+        if a == 0.0
+        {
+            return if c == 0.0 { vec![self.start] } else { Vec::new() };
+        }
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0
+        {
+            return Vec::new();
+        }
+        let root = sqrt_f64(discriminant);
+        let roots = if root == 0.0 { vec![-b / a] } else { vec![(-b - root) / a, (-b + root) / a] };
+        roots.into_iter().filter(|t| (0.0..=1.0).contains(t)).map(|t| self.start.lerp(&self.end, t)).collect()
+    }
+}
+
+impl Circle
+{
+    // Zero, one (tangent circles) or two points, the first to the left of the line from
+    // this center to the other. Coincident circles share every point and return none.
+    pub fn intersections(&self, other: &Circle) -> Vec<Point>
+    {
+        let (dx, dy) = (other.center.x - self.center.x, other.center.y - self.center.y);
+        let d = sqrt_f64(dx * dx + dy * dy);
+        if d == 0.0 || d > self.radius + other.radius || d < (self.radius - other.radius).abs()
+        {
+            return Vec::new();
+        }
+        // Distance from this center to the chord, and half the chord.
+        let along = (d * d + self.radius * self.radius - other.radius * other.radius) / (2.0 * d);
+        let half = sqrt_f64((self.radius * self.radius - along * along).max(0.0));
+        let (ux, uy) = (dx / d, dy / d);
+        let foot = Point::new(self.center.x + along * ux, self.center.y + along * uy);
+        if half == 0.0
+        {
+            return vec![foot];
+        }
+        vec![Point::new(foot.x - half * uy, foot.y + half * ux), Point::new(foot.x + half * uy, foot.y - half * ux)]
+    }
+
+    // Tangent segments from `point` to where they touch the circle, the left one first seen
+    // from `point`. A point on the circle gets its tangent as the segment to the point a
+    // radius along it counter-clockwise; a point inside gets none.
+    pub fn tangents_from_point(&self, point: &Point) -> Vec<Line>
+    {
+        let (ux, uy) = (point.x - self.center.x, point.y - self.center.y);
+        let d2 = ux * ux + uy * uy;
+        let r2 = self.radius * self.radius;
+        if d2 < r2 || d2 == 0.0
+        {
+            return Vec::new();
+        }
+        if d2 == r2
+        {
+            return vec![Line::new(*point, Point::new(point.x - uy, point.y + ux))];
+        }
+        let base = Point::new(self.center.x + ux * r2 / d2, self.center.y + uy * r2 / d2);
+        let offset = self.radius * sqrt_f64(d2 - r2) / d2;
+        vec![
+            Line::new(*point, Point::new(base.x + uy * offset, base.y - ux * offset)),
+            Line::new(*point, Point::new(base.x - uy * offset, base.y + ux * offset)),
+        ]
+    }
+
+    // Common tangents as segments from the touching point on this circle to the one on
+    // `other`: the outer tangents first, then the inner ones that cross between the
+    // circles. Tangent circles share a tangent at their contact point, and nested or
+    // concentric circles have fewer or none.
+    pub fn common_tangents(&self, other: &Circle) -> Vec<Line>
+    {
+        let (dx, dy) = (other.center.x - self.center.x, other.center.y - self.center.y);
+        let z = dx * dx + dy * dy;
+        let mut tangents: Vec<Line> = Vec::new();
+        if z == 0.0
+        {
+            return tangents;
+        }
+        // Each line `n . p + c = 0` with unit normal `n` sits at signed distance `s1 r1`
+        // from this center and `s2 r2` from the other.
+        for (s1, s2) in [(1.0, 1.0), (-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0)]
+        {
+            let (r1, r2) = (s1 * self.radius, s2 * other.radius);
+            let r = r2 - r1;
+            let h2 = z - r * r;
+            if h2 < 0.0
+            {
+                continue;
+            }
+            let h = sqrt_f64(h2);
+            let (nx, ny) = ((dx * r + dy * h) / z, (dy * r - dx * h) / z);
+            let tangent = Line::new(
+                Point::new(self.center.x - nx * r1, self.center.y - ny * r1),
+                Point::new(other.center.x - nx * r2, other.center.y - ny * r2),
+            );
+            if !tangents.iter().any(|t| t.start == tangent.start && t.end == tangent.end)
+            {
+                tangents.push(tangent);
+            }
+        }
+        tangents
+    }
+}
+
+impl Rectangle
+{
+    // The part of `line` inside the rectangle, boundary included (Liang-Barsky).
+    pub fn clip_line(&self, line: &Line) -> Option<Line>
+    {
+        let (x0, y0) = (line.start.x, line.start.y);
+        let (dx, dy) = (line.end.x - x0, line.end.y - y0);
+        let (min_x, min_y) = (self.top_left.x, self.top_left.y);
+        let (max_x, max_y) = (min_x + self.width, min_y + self.height);
+        let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+        for (p, q) in [(-dx, x0 - min_x), (dx, max_x - x0), (-dy, y0 - min_y), (dy, max_y - y0)]
+        {
+            if p == 0.0
+            {
+                // Parallel to this boundary, and entirely outside it or not at all.
+                if q < 0.0
+                {
+                    return None;
+                }
+                continue;
+            }
+            let t = q / p;
+            if p < 0.0
+            {
+                enter = enter.max(t);
+            }
+            else
+            {
+                exit = exit.min(t);
+            }
+        }
+        if enter > exit
+        {
+            return None;
+        }
+        let start = if enter > 0.0 { line.start.lerp(&line.end, enter) } else { line.start };
+        let end = if exit < 1.0 { line.start.lerp(&line.end, exit) } else { line.end };
+        Some(Line::new(start, end))
+    }
+}
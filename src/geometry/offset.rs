@@ -0,0 +1,163 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::boolean::{ boolean_op, clean_ring, BooleanOp };
+use super::predicates::orient2d;
+use super::{ Point, Polygon };
+use crate::math::{ acos_f64, atan2_f64, ceil_f64, cos_f64, sin_f64, sqrt_f64 };
+
+// Polygon offsetting built on the boolean operations. Growing by `d` unions the polygon
+// with a band of width `d` outside every edge plus a join piece at every convex corner;
+// shrinking subtracts the matching band inside every edge, with joins at reflex corners.
+// With round joins this is the exact Minkowski sum or difference with a disk, up to the
+// arc tolerance.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinType
+{
+    // Sharp corners, squared off where the miter would reach past `limit` times the
+    // offset distance.
+    Miter
+    {
+        limit: f64,
+    },
+    // Arcs approximated by chords deviating at most `tolerance` from the true circle.
+    Round
+    {
+        tolerance: f64,
+    },
+    // Corners cut flat at the offset distance from the vertex.
+    Square,
+}
+
+// Normal and direction of one edge.
+type Frame = ((f64, f64), (f64, f64));
+
+// Unit normal to the right of the edge `a -> b`, and the unit edge direction.
+fn edge_frame(a: &Point, b: &Point) -> Frame
+{
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let length = sqrt_f64(dx * dx + dy * dy);
+    let direction = (dx / length, dy / length);
+    ((direction.1, -direction.0), direction)
+}
+
+// Every corner point goes through here so that edge bands and join pieces meeting at a
+// corner share bit-identical vertices.
+fn offset_point(point: &Point, normal: (f64, f64), distance: f64) -> Point
+{
+    Point::new(point.x + normal.0 * distance, point.y + normal.1 * distance)
+}
+
+// Piece filling the wedge at `vertex` between the band ends `vertex + n1 * r` and
+// `vertex + n2 * r`, where `n2` is `n1` turned by `angle`.
+fn join_piece(vertex: &Point, (n1, direction1): Frame, (n2, direction2): Frame, r: f64, angle: f64, join: JoinType) -> Vec<Point>
+{
+    let (p1, p2) = (offset_point(vertex, n1, r), offset_point(vertex, n2, r));
+    let cos = n1.0 * n2.0 + n1.1 * n2.1;
+    match join
+    {
+        JoinType::Round { tolerance } =>
+        {
+            let step = 2.0 * acos_f64((1.0 - tolerance / r).clamp(-1.0, 1.0));
+            let segments = if step > 0.0 { (ceil_f64(angle.abs() / step) as usize).max(1) } else { 1 };
+            let mut piece = vec![*vertex, p1];
+            for k in 1..segments
+            {
+                let theta = angle * k as f64 / segments as f64;
+                let (s, c) = (sin_f64(theta), cos_f64(theta));
+                piece.push(offset_point(vertex, (n1.0 * c - n1.1 * s, n1.0 * s + n1.1 * c), r));
+            }
+            piece.push(p2);
+            piece
+        }
+        JoinType::Miter { limit } if sqrt_f64(2.0 / (1.0 + cos)) <= limit =>
+        {
+            let scale = r / (1.0 + cos);
+            vec![*vertex, p1, Point::new(vertex.x + (n1.0 + n2.0) * scale, vertex.y + (n1.1 + n2.1) * scale), p2]
+        }
+        JoinType::Miter { .. } | JoinType::Square =>
+        {
+            // Cut perpendicular to the bisector at distance `r`, extending each band along
+            // its edge until it meets the cut.
+            let length = sqrt_f64((n1.0 + n2.0) * (n1.0 + n2.0) + (n1.1 + n2.1) * (n1.1 + n2.1));
+            let bisector = ((n1.0 + n2.0) / length, (n1.1 + n2.1) / length);
+            let t = r * (1.0 - (n1.0 * bisector.0 + n1.1 * bisector.1)) / (direction1.0 * bisector.0 + direction1.1 * bisector.1);
+            let q1 = Point::new(p1.x + direction1.0 * t, p1.y + direction1.1 * t);
+            let q2 = Point::new(p2.x - direction2.0 * t, p2.y - direction2.1 * t);
+            vec![*vertex, p1, q1, q2, p2]
+        }
+    }
+}
+
+// Unions many polygons pairwise, keeping operands balanced.
+fn union_all(mut pieces: Vec<Vec<Polygon>>) -> Vec<Polygon>
+{
+    while pieces.len() > 1
+    {
+        let mut merged = Vec::with_capacity(pieces.len().div_ceil(2));
+        let mut iter = pieces.into_iter();
+        while let Some(first) = iter.next()
+        {
+            merged.push(match iter.next()
+            {
+                Some(second) => boolean_op(&first, &second, BooleanOp::Union),
+                None => first,
+            });
+        }
+        pieces = merged;
+    }
+    pieces.pop().unwrap_or_default()
+}
+
+impl Polygon
+{
+    // Grows the polygon by `distance`, or shrinks it when `distance` is negative. Holes
+    // shrink as the polygon grows and vice versa; parts thinner than twice the inset
+    // vanish, so the result may hold several polygons or none.
+    pub fn offset(&self, distance: f64, join: JoinType) -> Vec<Polygon>
+    {
+        let mut polygon = self.clone();
+        polygon.normalize_orientation();
+        if distance == 0.0
+        {
+            return vec![polygon];
+        }
+        let outward = distance > 0.0;
+        let r = distance.abs();
+
+        // Every ring now has the interior on its left, so the band goes right of each
+        // edge when growing and left of it when shrinking.
+        let mut pieces = Vec::new();
+        for ring in core::iter::once(&polygon.exterior).chain(polygon.holes.iter()).filter_map(|r| clean_ring(r))
+        {
+            let n = ring.len();
+            let frames: Vec<_> = (0..n)
+                .map(|i| {
+                    let (normal, direction) = edge_frame(&ring[i], &ring[(i + 1) % n]);
+                    (if outward { normal } else { (-normal.0, -normal.1) }, direction)
+                })
+                .collect();
+            for i in 0..n
+            {
+                let (a, b) = (&ring[i], &ring[(i + 1) % n]);
+                let normal = frames[i].0;
+                pieces.push(vec![Polygon::new(vec![*a, *b, offset_point(b, normal, r), offset_point(a, normal, r)])]);
+
+                // The corner at `b` needs filling when the normals spread apart there: at
+                // left turns when growing and right turns when shrinking.
+                let turn = orient2d(a, b, &ring[(i + 2) % n]);
+                let (n1, n2) = (frames[i].0, frames[(i + 1) % n].0);
+                let angle = atan2_f64(n1.0 * n2.1 - n1.1 * n2.0, n1.0 * n2.0 + n1.1 * n2.1);
+                if (outward && turn > 0.0) || (!outward && turn < 0.0)
+                {
+                    pieces.push(vec![Polygon::new(join_piece(b, frames[i], frames[(i + 1) % n], r, angle, join))]);
+                }
+            }
+        }
+
+        let band = union_all(pieces);
+        let op = if outward { BooleanOp::Union } else { BooleanOp::Difference };
+        boolean_op(&[polygon], &band, op)
+    }
+}
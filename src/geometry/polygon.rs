@@ -0,0 +1,610 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::predicates::orient2d;
+use super::{ closest_point_on_segment, Circle, Point, Rectangle, Shape2D, Winding };
+use crate::math::atan2_f64;
+use crate::matrix::Mat3;
+
+// Rings are stored open: the last vertex connects back to the first and is not repeated.
+// Holes may use either winding; only the exterior's winding is reported by `orientation`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon
+{
+    pub exterior: Vec<Point>,
+    pub holes: Vec<Vec<Point>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline
+{
+    pub points: Vec<Point>,
+}
+
+// Whether `point`, known to be collinear with `a` and `b`, lies within their bounding box.
+#[inline(always)]
+fn within_bounds(point: &Point, a: &Point, b: &Point) -> bool
+{
+    point.x >= a.x.min(b.x) && point.x <= a.x.max(b.x) && point.y >= a.y.min(b.y) && point.y <= a.y.max(b.y)
+}
+
+pub(super) fn on_segment(point: &Point, a: &Point, b: &Point) -> bool
+{
+    orient2d(a, b, point) == 0.0 && within_bounds(point, a, b)
+}
+
+// Closed-segment intersection test, including touching endpoints and collinear overlap.
+fn segments_intersect(p1: &Point, p2: &Point, q1: &Point, q2: &Point) -> bool
+{
+    let d1 = orient2d(q1, q2, p1);
+    let d2 = orient2d(q1, q2, p2);
+    let d3 = orient2d(p1, p2, q1);
+    let d4 = orient2d(p1, p2, q2);
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0)) && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+    (d1 == 0.0 && within_bounds(p1, q1, q2))
+        || (d2 == 0.0 && within_bounds(p2, q1, q2))
+        || (d3 == 0.0 && within_bounds(q1, p1, p2))
+        || (d4 == 0.0 && within_bounds(q2, p1, p2))
+}
+
+// Consecutive segments share an endpoint, so they only count as intersecting when they are
+// collinear and fold back over each other.
+fn adjacent_segments_overlap(a: &Point, shared: &Point, b: &Point) -> bool
+{
+    orient2d(a, shared, b) == 0.0 && (a.x - shared.x) * (b.x - shared.x) + (a.y - shared.y) * (b.y - shared.y) > 0.0
+}
+
+fn ring_edges(ring: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_
+{
+    (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()]))
+}
+
+// Shoelace formula; positive for counter-clockwise rings.
+pub fn ring_signed_area(ring: &[Point]) -> f64
+{
+    0.5 * ring_edges(ring).map(|(a, b)| a.x * b.y - b.x * a.y).sum::<f64>()
+}
+
+// Area-weighted centroid of a ring together with its signed area.
+fn ring_centroid(ring: &[Point]) -> (Point, f64)
+{
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+    let mut twice_area = 0.0;
+    for (a, b) in ring_edges(ring)
+    {
+        let cross = a.x * b.y - b.x * a.y;
+        cx += (a.x + b.x) * cross;
+        cy += (a.y + b.y) * cross;
+        twice_area += cross;
+    }
+    if twice_area == 0.0
+    {
+        return (Point::new(0.0, 0.0), 0.0);
+    }
+    (Point::new(cx / (3.0 * twice_area), cy / (3.0 * twice_area)), 0.5 * twice_area)
+}
+
+// Winding number of `ring` around `point` (Sunday's crossing rules). Counter-clockwise
+// rings count positive.
+pub fn ring_winding_number(ring: &[Point], point: &Point) -> i32
+{
+    let mut winding = 0;
+    for (a, b) in ring_edges(ring)
+    {
+        if a.y <= point.y
+        {
+            if b.y > point.y && orient2d(&a, &b, point) > 0.0
+            {
+                winding += 1;
+            }
+        }
+        else if b.y <= point.y && orient2d(&a, &b, point) < 0.0
+        {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+impl Polygon
+{
+    pub fn new(exterior: Vec<Point>) -> Self
+    {
+        Self { exterior, holes: Vec::new() }
+    }
+
+    pub fn with_holes(exterior: Vec<Point>, holes: Vec<Vec<Point>>) -> Self
+    {
+        Self { exterior, holes }
+    }
+
+    fn rings(&self) -> impl Iterator<Item = &Vec<Point>>
+    {
+        core::iter::once(&self.exterior).chain(self.holes.iter())
+    }
+
+    // Every edge, exterior first and then each hole in order. Edge indices used by
+    // `self_intersections` refer to this order.
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_
+    {
+        self.rings().flat_map(|ring| ring_edges(ring))
+    }
+
+    // The exterior's signed area minus the holes' areas, regardless of hole winding.
+    pub fn area(&self) -> f64
+    {
+        ring_signed_area(&self.exterior).abs() - self.holes.iter().map(|hole| ring_signed_area(hole).abs()).sum::<f64>()
+    }
+
+    pub fn perimeter(&self) -> f64
+    {
+        self.edges().map(|(a, b)| a.distance(&b)).sum()
+    }
+
+    pub fn orientation(&self) -> Winding
+    {
+        let area = ring_signed_area(&self.exterior);
+        if area > 0.0
+        {
+            Winding::CounterClockwise
+        }
+        else if area < 0.0
+        {
+            Winding::Clockwise
+        }
+        else
+        {
+            Winding::Collinear
+        }
+    }
+
+    // Reverses rings as needed so the exterior is counter-clockwise and holes clockwise.
+    pub fn normalize_orientation(&mut self)
+    {
+        if ring_signed_area(&self.exterior) < 0.0
+        {
+            self.exterior.reverse();
+        }
+        for hole in &mut self.holes
+        {
+            if ring_signed_area(hole) > 0.0
+            {
+                hole.reverse();
+            }
+        }
+    }
+
+    // The area centroid, or `None` when the polygon has no area.
+    pub fn centroid(&self) -> Option<Point>
+    {
+        let (exterior, exterior_area) = ring_centroid(&self.exterior);
+        let mut area = exterior_area.abs();
+        let mut x = exterior.x * area;
+        let mut y = exterior.y * area;
+        for hole in &self.holes
+        {
+            let (center, hole_area) = ring_centroid(hole);
+            area -= hole_area.abs();
+            x -= center.x * hole_area.abs();
+            y -= center.y * hole_area.abs();
+        }
+        if area == 0.0
+        {
+            return None;
+        }
+        Some(Point::new(x / area, y / area))
+    }
+
+    // True for a polygon without holes whose exterior turns the same way at every vertex
+    // and winds around exactly once. Collinear vertices are allowed.
+    pub fn is_convex(&self) -> bool
+    {
+        let ring = &self.exterior;
+        let n = ring.len();
+        if !self.holes.is_empty() || n < 3
+        {
+            return false;
+        }
+        let mut sign = 0.0;
+        let mut turning = 0.0;
+        for i in 0..n
+        {
+            let (a, b, c) = (&ring[i], &ring[(i + 1) % n], &ring[(i + 2) % n]);
+            let cross = orient2d(a, b, c);
+            if cross != 0.0
+            {
+                if sign != 0.0 && cross.signum() != sign
+                {
+                    return false;
+                }
+                sign = cross.signum();
+            }
+            let (ux, uy) = (b.x - a.x, b.y - a.y);
+            let (vx, vy) = (c.x - b.x, c.y - b.y);
+            turning += atan2_f64(ux * vy - uy * vx, ux * vx + uy * vy);
+        }
+        sign != 0.0 && (turning.abs() - core::f64::consts::TAU).abs() < 1e-6
+    }
+
+    // Winding number of the exterior around `point`, less the holes' contributions taken
+    // with the opposite sense, so points inside a hole have winding 0.
+    pub fn winding_number(&self, point: &Point) -> i32
+    {
+        let exterior = ring_winding_number(&self.exterior, point);
+        let outer_sign = if ring_signed_area(&self.exterior) < 0.0 { -1 } else { 1 };
+        let holes: i32 = self.holes.iter().map(|hole| ring_winding_number(hole, point).abs()).sum();
+        exterior - outer_sign * holes
+    }
+
+    // Points on any boundary, including hole boundaries, count as inside.
+    pub fn contains(&self, point: &Point) -> bool
+    {
+        if self.edges().any(|(a, b)| on_segment(point, &a, &b))
+        {
+            return true;
+        }
+        self.winding_number(point) != 0
+    }
+
+    // Pairs `(i, j)` with `i < j` of edges (indexed as in `edges`) that intersect other than
+    // at the vertex shared by neighbours in the same ring. Empty for a simple polygon.
+    pub fn self_intersections(&self) -> Vec<(usize, usize)>
+    {
+        let mut ring_of = Vec::new();
+        let mut edges = Vec::new();
+        for (r, ring) in self.rings().enumerate()
+        {
+            for (k, edge) in ring_edges(ring).enumerate()
+            {
+                ring_of.push((r, k, ring.len()));
+                edges.push(edge);
+            }
+        }
+        let mut result = Vec::new();
+        for i in 0..edges.len()
+        {
+            for j in (i + 1)..edges.len()
+            {
+                let (ri, ki, n) = ring_of[i];
+                let (rj, kj, _) = ring_of[j];
+                let (p1, p2) = &edges[i];
+                let (q1, q2) = &edges[j];
+                let intersects = if ri == rj && n > 2 && kj == ki + 1
+                {
+                    adjacent_segments_overlap(p1, p2, q2)
+                }
+                else if ri == rj && n > 2 && ki == 0 && kj == n - 1
+                {
+                    adjacent_segments_overlap(q1, p1, p2)
+                }
+                else
+                {
+                    segments_intersect(p1, p2, q1, q2)
+                };
+                if intersects
+                {
+                    result.push((i, j));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn is_simple(&self) -> bool
+    {
+        self.self_intersections().is_empty()
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        for ring in core::iter::once(&mut self.exterior).chain(self.holes.iter_mut())
+        {
+            ring.iter_mut().for_each(|point| point.translate(dx, dy));
+        }
+    }
+
+    pub fn transform(&self, m: &Mat3) -> Polygon
+    {
+        let map = |ring: &Vec<Point>| ring.iter().map(|point| point.transform(m)).collect::<Vec<_>>();
+        Polygon::with_holes(map(&self.exterior), self.holes.iter().map(map).collect())
+    }
+}
+
+impl Shape2D for Polygon
+{
+    fn area(&self) -> f64
+    {
+        Polygon::area(self)
+    }
+
+    fn perimeter(&self) -> f64
+    {
+        Polygon::perimeter(self)
+    }
+
+    fn contains(&self, point: &Point) -> bool
+    {
+        Polygon::contains(self, point)
+    }
+
+    fn bounding_box(&self) -> Rectangle
+    {
+        let mut min = Point::new(f64::INFINITY, f64::INFINITY);
+        let mut max = Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for point in &self.exterior
+        {
+            min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y));
+        }
+        Rectangle::new(min, max.x - min.x, max.y - min.y)
+    }
+
+    // Centered on the bounding box rather than minimal.
+    fn bounding_circle(&self) -> Circle
+    {
+        let center = Shape2D::centroid(&self.bounding_box());
+        let radius = self.exterior.iter().map(|point| center.distance(point)).fold(0.0, f64::max);
+        Circle::new(center, radius)
+    }
+
+    // Falls back to the first vertex for polygons without area.
+    fn centroid(&self) -> Point
+    {
+        Polygon::centroid(self).unwrap_or_else(|| self.exterior.first().copied().unwrap_or(Point::new(0.0, 0.0)))
+    }
+
+    fn closest_point(&self, point: &Point) -> Point
+    {
+        let mut best = *point;
+        let mut best_distance = f64::INFINITY;
+        for (a, b) in self.edges()
+        {
+            let candidate = closest_point_on_segment(point, &a, &b);
+            let distance = candidate.distance(point);
+            if distance < best_distance
+            {
+                best = candidate;
+                best_distance = distance;
+            }
+        }
+        best
+    }
+
+    fn signed_distance(&self, point: &Point) -> f64
+    {
+        let distance = Shape2D::closest_point(self, point).distance(point);
+        if self.contains(point) { -distance } else { distance }
+    }
+}
+
+// Orders heap entries by smallest area first, breaking ties by index.
+struct AreaEntry
+{
+    area: f64,
+    index: usize,
+    version: usize,
+}
+
+impl PartialEq for AreaEntry
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for AreaEntry {}
+
+impl PartialOrd for AreaEntry
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for AreaEntry
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        other.area.total_cmp(&self.area).then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl Polyline
+{
+    pub fn new(points: Vec<Point>) -> Self
+    {
+        Self { points }
+    }
+
+    pub fn segments(&self) -> impl Iterator<Item = (Point, Point)> + '_
+    {
+        self.points.windows(2).map(|pair| (pair[0], pair[1]))
+    }
+
+    pub fn length(&self) -> f64
+    {
+        self.segments().map(|(a, b)| a.distance(&b)).sum()
+    }
+
+    // The point `distance` along the polyline, clamped to its ends.
+    pub fn point_at(&self, distance: f64) -> Option<Point>
+    {
+        let first = *self.points.first()?;
+        let mut remaining = distance;
+        if remaining <= 0.0
+        {
+            return Some(first);
+        }
+        for (a, b) in self.segments()
+        {
+            let length = a.distance(&b);
+            if remaining <= length && length > 0.0
+            {
+                return Some(a.lerp(&b, remaining / length));
+            }
+            remaining -= length;
+        }
+        self.points.last().copied()
+    }
+
+    // Points spaced `spacing` apart along the arc length, starting at the first point.
+    // The last point is always kept, so the final gap may be shorter.
+    pub fn resample(&self, spacing: f64) -> Polyline
+    {
+        let length = self.length();
+        if self.points.len() < 2 || spacing <= 0.0 || length == 0.0
+        {
+            return self.clone();
+        }
+        let mut result = vec![self.points[0]];
+        let mut target = spacing;
+        let mut travelled = 0.0;
+        for (a, b) in self.segments()
+        {
+            let segment = a.distance(&b);
+            while target <= travelled + segment && target < length
+            {
+                result.push(a.lerp(&b, (target - travelled) / segment));
+                target += spacing;
+            }
+            travelled += segment;
+        }
+        let last = self.points[self.points.len() - 1];
+        if result.last().is_none_or(|point| point.distance(&last) > 0.0)
+        {
+            result.push(last);
+        }
+        Polyline::new(result)
+    }
+
+    // Douglas-Peucker: keeps the ends and recursively the point farthest from the current
+    // chord while that distance exceeds `epsilon`.
+    pub fn simplify_douglas_peucker(&self, epsilon: f64) -> Polyline
+    {
+        let n = self.points.len();
+        if n < 3
+        {
+            return self.clone();
+        }
+        let mut keep = vec![false; n];
+        keep[0] = true;
+        keep[n - 1] = true;
+        let mut stack = vec![(0, n - 1)];
+        while let Some((start, end)) = stack.pop()
+        {
+            let (a, b) = (&self.points[start], &self.points[end]);
+            let mut farthest = (0.0, start);
+            for i in (start + 1)..end
+            {
+                let distance = closest_point_on_segment(&self.points[i], a, b).distance(&self.points[i]);
+                if distance > farthest.0
+                {
+                    farthest = (distance, i);
+                }
+            }
+            if farthest.0 > epsilon
+            {
+                keep[farthest.1] = true;
+                stack.push((start, farthest.1));
+                stack.push((farthest.1, end));
+            }
+        }
+        Polyline::new(self.points.iter().zip(keep).filter(|(_, k)| *k).map(|(point, _)| *point).collect())
+    }
+
+    // Visvalingam-Whyatt: repeatedly removes the interior point whose triangle with its
+    // neighbours has the smallest area, while that area is below `min_area`.
+    pub fn simplify_visvalingam(&self, min_area: f64) -> Polyline
+    {
+        let n = self.points.len();
+        if n < 3
+        {
+            return self.clone();
+        }
+        let mut previous: Vec<usize> = (0..n).map(|i| i.wrapping_sub(1)).collect();
+        let mut next: Vec<usize> = (1..=n).collect();
+        let mut version = vec![0; n];
+        let mut removed = vec![false; n];
+        let area = |p: usize, i: usize, q: usize| 0.5 * orient2d(&self.points[p], &self.points[i], &self.points[q]).abs();
+
+        let mut heap = BinaryHeap::new();
+        for i in 1..(n - 1)
+        {
+            heap.push(AreaEntry { area: area(i - 1, i, i + 1), index: i, version: 0 });
+        }
+        while let Some(entry) = heap.pop()
+        {
+            if removed[entry.index] || entry.version != version[entry.index]
+            {
+                continue;
+            }
+            if entry.area >= min_area
+            {
+                break;
+            }
+            let (p, q) = (previous[entry.index], next[entry.index]);
+            removed[entry.index] = true;
+            next[p] = q;
+            previous[q] = p;
+            // Neighbours keep at least the removed point's area, so effective areas only
+            // grow and the threshold cut is well defined.
+            for neighbour in [p, q]
+            {
+                if neighbour != 0 && neighbour != n - 1
+                {
+                    version[neighbour] += 1;
+                    let updated = area(previous[neighbour], neighbour, next[neighbour]).max(entry.area);
+                    heap.push(AreaEntry { area: updated, index: neighbour, version: version[neighbour] });
+                }
+            }
+        }
+        Polyline::new(self.points.iter().zip(removed).filter(|(_, r)| !*r).map(|(point, _)| *point).collect())
+    }
+
+    // Pairs `(i, j)` with `i < j` of segments that intersect, other than consecutive
+    // segments meeting at their shared point.
+    pub fn self_intersections(&self) -> Vec<(usize, usize)>
+    {
+        let segments: Vec<_> = self.segments().collect();
+        let mut result = Vec::new();
+        for i in 0..segments.len()
+        {
+            for j in (i + 1)..segments.len()
+            {
+                let (p1, p2) = &segments[i];
+                let (q1, q2) = &segments[j];
+                let intersects = if j == i + 1
+                {
+                    adjacent_segments_overlap(p1, p2, q2)
+                }
+                else
+                {
+                    segments_intersect(p1, p2, q1, q2)
+                };
+                if intersects
+                {
+                    result.push((i, j));
+                }
+            }
+        }
+        result
+    }
+
+    pub fn translate(&mut self, dx: f64, dy: f64)
+    {
+        self.points.iter_mut().for_each(|point| point.translate(dx, dy));
+    }
+
+    pub fn transform(&self, m: &Mat3) -> Polyline
+    {
+        Polyline::new(self.points.iter().map(|point| point.transform(m)).collect())
+    }
+}
@@ -0,0 +1,282 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{ Point, Point3D };
+
+// Robust geometric predicates after Shewchuk, "Adaptive Precision Floating-Point Arithmetic
+// and Fast Robust Geometric Predicates", 1997. Each predicate first evaluates its
+// determinant in plain f64 and returns it when it clears Shewchuk's forward error bound;
+// only the rare near-degenerate inputs fall through to exact expansion arithmetic. The
+// sign of the result is always exact and the magnitude approximates the determinant.
+//
+// Sign conventions follow Shewchuk's:
+// - `orient2d(a, b, c)` is positive when `a, b, c` turn counter-clockwise.
+// - `orient3d(a, b, c, d)` is positive when `d` lies below the plane of `a, b, c`, "below"
+//   being the side from which `a, b, c` appear clockwise.
+// - `incircle(a, b, c, d)` is positive when `d` lies inside the circle through the
+//   counter-clockwise `a, b, c`.
+// - `insphere(a, b, c, d, e)` is positive when `e` lies inside the sphere through `a, b, c,
+//   d`, given `orient3d(a, b, c, d) > 0`.
+//
+// Inputs must stay clear of overflow and underflow for the exact stage to be exact. The
+// expansion arithmetic is shared with the segment sweep, which works with exact crossing
+// points.
+
+// Half an ulp of 1.
+const ROUNDOFF: f64 = f64::EPSILON / 2.0;
+const SPLITTER: f64 = 134_217_729.0; // 2^27 + 1
+
+const ORIENT2D_BOUND: f64 = (3.0 + 16.0 * ROUNDOFF) * ROUNDOFF;
+const ORIENT3D_BOUND: f64 = (7.0 + 56.0 * ROUNDOFF) * ROUNDOFF;
+const INCIRCLE_BOUND: f64 = (10.0 + 96.0 * ROUNDOFF) * ROUNDOFF;
+const INSPHERE_BOUND: f64 = (16.0 + 224.0 * ROUNDOFF) * ROUNDOFF;
+
+// An exact sum of nonoverlapping f64 components in increasing magnitude.
+pub(super) type Expansion = Vec<f64>;
+
+#[inline(always)]
+fn two_sum(a: f64, b: f64) -> (f64, f64)
+{
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+// Requires `|a| >= |b|`.
+#[inline(always)]
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64)
+{
+    let x = a + b;
+    (x, b - (x - a))
+}
+
+#[inline(always)]
+fn split(a: f64) -> (f64, f64)
+{
+    let c = SPLITTER * a;
+    let high = c - (c - a);
+    (high, a - high)
+}
+
+#[inline(always)]
+fn two_product(a: f64, b: f64) -> (f64, f64)
+{
+    let x = a * b;
+    let (a_high, a_low) = split(a);
+    let (b_high, b_low) = split(b);
+    let error = x - a_high * b_high - a_low * b_high - a_high * b_low;
+    (x, a_low * b_low - error)
+}
+
+pub(super) fn difference(a: f64, b: f64) -> Expansion
+{
+    let x = a - b;
+    let b_virtual = a - x;
+    let a_virtual = x + b_virtual;
+    let low = (a - a_virtual) + (b_virtual - b);
+    if low == 0.0 { vec![x] } else { vec![low, x] }
+}
+
+// `e + b`, dropping zero components.
+fn grow(e: &[f64], b: f64) -> Expansion
+{
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &component in e
+    {
+        let (sum, error) = two_sum(q, component);
+        if error != 0.0
+        {
+            h.push(error);
+        }
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty()
+    {
+        h.push(q);
+    }
+    h
+}
+
+pub(super) fn add(e: &[f64], f: &[f64]) -> Expansion
+{
+    f.iter().fold(e.to_vec(), |sum, &component| grow(&sum, component))
+}
+
+pub(super) fn sub(e: &[f64], f: &[f64]) -> Expansion
+{
+    f.iter().fold(e.to_vec(), |sum, &component| grow(&sum, -component))
+}
+
+// `e * b`, dropping zero components.
+pub(super) fn scale(e: &[f64], b: f64) -> Expansion
+{
+    let mut h = Vec::with_capacity(2 * e.len());
+    let (mut q, low) = two_product(e[0], b);
+    if low != 0.0
+    {
+        h.push(low);
+    }
+    for &component in &e[1..]
+    {
+        let (product, product_low) = two_product(component, b);
+        let (sum, error) = two_sum(q, product_low);
+        if error != 0.0
+        {
+            h.push(error);
+        }
+        let (next, error) = fast_two_sum(product, sum);
+        if error != 0.0
+        {
+            h.push(error);
+        }
+        q = next;
+    }
+    if q != 0.0 || h.is_empty()
+    {
+        h.push(q);
+    }
+    h
+}
+
+pub(super) fn mul(e: &[f64], f: &[f64]) -> Expansion
+{
+    f.iter().fold(vec![0.0], |sum, &component| add(&sum, &scale(e, component)))
+}
+
+// The components sum to the exact value, and the largest carries its sign.
+pub(super) fn estimate(e: &[f64]) -> f64
+{
+    e.iter().sum()
+}
+
+pub fn orient2d(a: &Point, b: &Point, c: &Point) -> f64
+{
+    let left = (a.x - c.x) * (b.y - c.y);
+    let right = (a.y - c.y) * (b.x - c.x);
+    let det = left - right;
+    // Opposite signs cannot cancel, so the rounded difference has the right sign.
+    if (left > 0.0 && right <= 0.0) || (left < 0.0 && right >= 0.0) || left == 0.0
+    {
+        return det;
+    }
+    let bound = ORIENT2D_BOUND * (left.abs() + right.abs());
+    if det.abs() >= bound
+    {
+        return det;
+    }
+    estimate(&orient2d_exact(a, b, c))
+}
+
+pub(super) fn orient2d_exact(a: &Point, b: &Point, c: &Point) -> Expansion
+{
+    let (acx, acy) = (difference(a.x, c.x), difference(a.y, c.y));
+    let (bcx, bcy) = (difference(b.x, c.x), difference(b.y, c.y));
+    sub(&mul(&acx, &bcy), &mul(&acy, &bcx))
+}
+
+pub fn orient3d(a: &Point3D, b: &Point3D, c: &Point3D, d: &Point3D) -> f64
+{
+    let (adx, ady, adz) = (a.x - d.x, a.y - d.y, a.z - d.z);
+    let (bdx, bdy, bdz) = (b.x - d.x, b.y - d.y, b.z - d.z);
+    let (cdx, cdy, cdz) = (c.x - d.x, c.y - d.y, c.z - d.z);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let det = adz * (bdxcdy - cdxbdy) + bdz * (cdxady - adxcdy) + cdz * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * adz.abs() + (cdxady.abs() + adxcdy.abs()) * bdz.abs() + (adxbdy.abs() + bdxady.abs()) * cdz.abs();
+    if det.abs() > ORIENT3D_BOUND * permanent
+    {
+        return det;
+    }
+
+    let [adx, ady, adz] = [difference(a.x, d.x), difference(a.y, d.y), difference(a.z, d.z)];
+    let [bdx, bdy, bdz] = [difference(b.x, d.x), difference(b.y, d.y), difference(b.z, d.z)];
+    let [cdx, cdy, cdz] = [difference(c.x, d.x), difference(c.y, d.y), difference(c.z, d.z)];
+    let bc = sub(&mul(&bdx, &cdy), &mul(&cdx, &bdy));
+    let ca = sub(&mul(&cdx, &ady), &mul(&adx, &cdy));
+    let ab = sub(&mul(&adx, &bdy), &mul(&bdx, &ady));
+    estimate(&add(&add(&mul(&adz, &bc), &mul(&bdz, &ca)), &mul(&cdz, &ab)))
+}
+
+pub fn incircle(a: &Point, b: &Point, c: &Point, d: &Point) -> f64
+{
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let (alift, blift, clift) = (adx * adx + ady * ady, bdx * bdx + bdy * bdy, cdx * cdx + cdy * cdy);
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift + (cdxady.abs() + adxcdy.abs()) * blift + (adxbdy.abs() + bdxady.abs()) * clift;
+    if det.abs() > INCIRCLE_BOUND * permanent
+    {
+        return det;
+    }
+
+    let (adx, ady) = (difference(a.x, d.x), difference(a.y, d.y));
+    let (bdx, bdy) = (difference(b.x, d.x), difference(b.y, d.y));
+    let (cdx, cdy) = (difference(c.x, d.x), difference(c.y, d.y));
+    let lift = |x: &[f64], y: &[f64]| add(&mul(x, x), &mul(y, y));
+    let bc = sub(&mul(&bdx, &cdy), &mul(&cdx, &bdy));
+    let ca = sub(&mul(&cdx, &ady), &mul(&adx, &cdy));
+    let ab = sub(&mul(&adx, &bdy), &mul(&bdx, &ady));
+    estimate(&add(&add(&mul(&lift(&adx, &ady), &bc), &mul(&lift(&bdx, &bdy), &ca)), &mul(&lift(&cdx, &cdy), &ab)))
+}
+
+pub fn insphere(a: &Point3D, b: &Point3D, c: &Point3D, d: &Point3D, e: &Point3D) -> f64
+{
+    let (aex, aey, aez) = (a.x - e.x, a.y - e.y, a.z - e.z);
+    let (bex, bey, bez) = (b.x - e.x, b.y - e.y, b.z - e.z);
+    let (cex, cey, cez) = (c.x - e.x, c.y - e.y, c.z - e.z);
+    let (dex, dey, dez) = (d.x - e.x, d.y - e.y, d.z - e.z);
+    let (aexbey, bexaey) = (aex * bey, bex * aey);
+    let (bexcey, cexbey) = (bex * cey, cex * bey);
+    let (cexdey, dexcey) = (cex * dey, dex * cey);
+    let (dexaey, aexdey) = (dex * aey, aex * dey);
+    let (aexcey, cexaey) = (aex * cey, cex * aey);
+    let (bexdey, dexbey) = (bex * dey, dex * bey);
+    let (ab, bc, cd, da) = (aexbey - bexaey, bexcey - cexbey, cexdey - dexcey, dexaey - aexdey);
+    let (ac, bd) = (aexcey - cexaey, bexdey - dexbey);
+    let abc = aez * bc - bez * ac + cez * ab;
+    let bcd = bez * cd - cez * bd + dez * bc;
+    let cda = cez * da + dez * ac + aez * cd;
+    let dab = dez * ab + aez * bd + bez * da;
+    let alift = aex * aex + aey * aey + aez * aez;
+    let blift = bex * bex + bey * bey + bez * bez;
+    let clift = cex * cex + cey * cey + cez * cez;
+    let dlift = dex * dex + dey * dey + dez * dez;
+    let det = (dlift * abc - clift * dab) + (blift * cda - alift * bcd);
+
+    let (aez, bez, cez, dez) = (aez.abs(), bez.abs(), cez.abs(), dez.abs());
+    let (aexbey, bexaey, bexcey, cexbey) = (aexbey.abs(), bexaey.abs(), bexcey.abs(), cexbey.abs());
+    let (cexdey, dexcey, dexaey, aexdey) = (cexdey.abs(), dexcey.abs(), dexaey.abs(), aexdey.abs());
+    let (aexcey, cexaey, bexdey, dexbey) = (aexcey.abs(), cexaey.abs(), bexdey.abs(), dexbey.abs());
+    let permanent = ((cexdey + dexcey) * bez + (dexbey + bexdey) * cez + (bexcey + cexbey) * dez) * alift
+        + ((dexaey + aexdey) * cez + (aexcey + cexaey) * dez + (cexdey + dexcey) * aez) * blift
+        + ((aexbey + bexaey) * dez + (bexdey + dexbey) * aez + (dexaey + aexdey) * bez) * clift
+        + ((bexcey + cexbey) * aez + (cexaey + aexcey) * bez + (aexbey + bexaey) * cez) * dlift;
+    if det.abs() > INSPHERE_BOUND * permanent
+    {
+        return det;
+    }
+
+    let [aex, aey, aez] = [difference(a.x, e.x), difference(a.y, e.y), difference(a.z, e.z)];
+    let [bex, bey, bez] = [difference(b.x, e.x), difference(b.y, e.y), difference(b.z, e.z)];
+    let [cex, cey, cez] = [difference(c.x, e.x), difference(c.y, e.y), difference(c.z, e.z)];
+    let [dex, dey, dez] = [difference(d.x, e.x), difference(d.y, e.y), difference(d.z, e.z)];
+    let cross = |ux: &[f64], uy: &[f64], vx: &[f64], vy: &[f64]| sub(&mul(ux, vy), &mul(vx, uy));
+    let (ab, bc) = (cross(&aex, &aey, &bex, &bey), cross(&bex, &bey, &cex, &cey));
+    let (cd, da) = (cross(&cex, &cey, &dex, &dey), cross(&dex, &dey, &aex, &aey));
+    let (ac, bd) = (cross(&aex, &aey, &cex, &cey), cross(&bex, &bey, &dex, &dey));
+    let abc = add(&sub(&mul(&aez, &bc), &mul(&bez, &ac)), &mul(&cez, &ab));
+    let bcd = add(&sub(&mul(&bez, &cd), &mul(&cez, &bd)), &mul(&dez, &bc));
+    let cda = add(&add(&mul(&cez, &da), &mul(&dez, &ac)), &mul(&aez, &cd));
+    let dab = add(&add(&mul(&dez, &ab), &mul(&aez, &bd)), &mul(&bez, &da));
+    let lift = |x: &[f64], y: &[f64], z: &[f64]| add(&add(&mul(x, x), &mul(y, y)), &mul(z, z));
+    let first = sub(&mul(&lift(&dex, &dey, &dez), &abc), &mul(&lift(&cex, &cey, &cez), &dab));
+    let second = sub(&mul(&lift(&bex, &bey, &bez), &cda), &mul(&lift(&aex, &aey, &aez), &bcd));
+    estimate(&add(&first, &second))
+}
@@ -0,0 +1,381 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::predicates::{ difference, estimate, mul, orient2d, orient2d_exact, scale, sub, Expansion };
+use super::{ Line, Point };
+
+// All intersections among a set of segments with the Bentley-Ottmann sweep, in
+// O((n + k) log n) for n segments and k intersecting pairs.
+//
+// The sweep line is vertical and moves left to right, visiting points by x and then y,
+// so a vertical segment is swept from its lower end up. Crossing points are kept exactly
+// as rationals over expansions, so every comparison between events and every test of a
+// segment against an event is exact and degenerate configurations (shared endpoints,
+// several segments through one point, collinear overlaps, vertical segments) need no
+// tolerances. The status is a treap that is only ever split by the side of the current
+// event each segment lies on, which stays consistent however the segments cross.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionPoint
+{
+    pub point: Point,
+    // Indices of every segment through `point`, ascending.
+    pub segments: Vec<usize>,
+}
+
+impl IntersectionPoint
+{
+    pub fn pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_
+    {
+        let s = &self.segments;
+        (0..s.len()).flat_map(move |i| (i + 1..s.len()).map(move |j| (s[i], s[j])))
+    }
+}
+
+// Bound on the relative error of a rounded crossing coordinate, with a wide margin.
+const APPROXIMATION: f64 = 16.0 * f64::EPSILON;
+
+// An input endpoint, or a crossing `(x / w, y / w)` with `w > 0`.
+#[derive(Debug, Clone)]
+enum Event
+{
+    Input(Point),
+    Crossing
+    {
+        x: Expansion,
+        y: Expansion,
+        w: Expansion,
+        approx: Point,
+    },
+}
+
+impl Event
+{
+    // The crossing of the segments `ab` and `cd`, known to cross properly.
+    fn crossing(a: &Point, b: &Point, c: &Point, d: &Point) -> Event
+    {
+        // `(d1 b - d2 a) / (d1 - d2)` with `d1, d2` the sides of `a, b` on `cd`.
+        let (mut d1, mut d2) = (orient2d_exact(c, d, a), orient2d_exact(c, d, b));
+        if estimate(&d1) < estimate(&d2)
+        {
+            (d1, d2) = (d1.iter().map(|v| -v).collect(), d2.iter().map(|v| -v).collect());
+        }
+        let w = sub(&d1, &d2);
+        let x = sub(&scale(&d1, b.x), &scale(&d2, a.x));
+        let y = sub(&scale(&d1, b.y), &scale(&d2, a.y));
+        let approx = Point::new(estimate(&x) / estimate(&w), estimate(&y) / estimate(&w));
+        Event::Crossing { x, y, w, approx }
+    }
+
+    fn approx(&self) -> Point
+    {
+        match self
+        {
+            Event::Input(p) => *p,
+            Event::Crossing { approx, .. } => *approx,
+        }
+    }
+
+    fn exact(&self) -> (Expansion, Expansion, Expansion)
+    {
+        match self
+        {
+            Event::Input(p) => (vec![p.x], vec![p.y], vec![1.0]),
+            Event::Crossing { x, y, w, .. } => (x.clone(), y.clone(), w.clone()),
+        }
+    }
+
+    // Positive when the event lies left of the directed segment `a -> b`.
+    fn side(&self, a: &Point, b: &Point) -> f64
+    {
+        let Event::Crossing { x, y, w, approx } = self
+        else
+        {
+            return orient2d(a, b, &self.approx());
+        };
+        let estimate_side = orient2d(a, b, approx);
+        let bound = APPROXIMATION * ((b.x - a.x).abs() + (b.y - a.y).abs()) * (approx.x.abs() + approx.y.abs());
+        if estimate_side.abs() > bound
+        {
+            return estimate_side;
+        }
+        let (dx, dy) = (difference(b.x, a.x), difference(b.y, a.y));
+        let (ex, ey) = (sub(x, &scale(w, a.x)), sub(y, &scale(w, a.y)));
+        estimate(&sub(&mul(&dx, &ey), &mul(&dy, &ex)))
+    }
+}
+
+// Signs without telling zero from negative zero.
+#[inline(always)]
+fn sign(value: f64) -> Ordering
+{
+    value.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+}
+
+#[inline(always)]
+fn opposite(p: f64, q: f64) -> bool
+{
+    (p < 0.0 && q > 0.0) || (p > 0.0 && q < 0.0)
+}
+
+// Compares coordinates `a / aw` and `b / bw`, whose roundings are `a_approx` and
+// `b_approx`.
+fn compare_coordinate(a_approx: f64, b_approx: f64, exact: impl FnOnce() -> f64) -> Ordering
+{
+    if (a_approx - b_approx).abs() > APPROXIMATION * (a_approx.abs() + b_approx.abs())
+    {
+        return sign(a_approx - b_approx);
+    }
+    sign(exact())
+}
+
+impl Ord for Event
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        if let (Event::Input(p), Event::Input(q)) = (self, other)
+        {
+            return sign(p.x - q.x).then(sign(p.y - q.y));
+        }
+        let (p, q) = (self.approx(), other.approx());
+        let (a, b) = (self.exact(), other.exact());
+        compare_coordinate(p.x, q.x, || estimate(&sub(&mul(&a.0, &b.2), &mul(&b.0, &a.2))))
+            .then_with(|| compare_coordinate(p.y, q.y, || estimate(&sub(&mul(&a.1, &b.2), &mul(&b.1, &a.2)))))
+    }
+}
+
+impl PartialOrd for Event
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Event
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Event {}
+
+const NIL: usize = usize::MAX;
+
+struct Node
+{
+    segment: usize,
+    priority: u64,
+    left: usize,
+    right: usize,
+}
+
+// The segments crossing the sweep line, bottom to top.
+struct Status
+{
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+    seed: u64,
+}
+
+impl Status
+{
+    fn node(&mut self, segment: usize) -> usize
+    {
+        // Xorshift priorities keep the treap balanced in expectation.
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let node = Node { segment, priority: self.seed, left: NIL, right: NIL };
+        match self.free.pop()
+        {
+            Some(id) =>
+            {
+                self.nodes[id] = node;
+                id
+            }
+            None =>
+            {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    // Splits off the longest prefix whose segments satisfy `in_prefix`, which must hold
+    // for a prefix of the in-order sequence.
+    fn split(&mut self, root: usize, in_prefix: &dyn Fn(usize) -> bool) -> (usize, usize)
+    {
+        if root == NIL
+        {
+            return (NIL, NIL);
+        }
+        if in_prefix(self.nodes[root].segment)
+        {
+            let (left, right) = self.split(self.nodes[root].right, in_prefix);
+            self.nodes[root].right = left;
+            (root, right)
+        }
+        else
+        {
+            let (left, right) = self.split(self.nodes[root].left, in_prefix);
+            self.nodes[root].left = right;
+            (left, root)
+        }
+    }
+
+    fn merge(&mut self, a: usize, b: usize) -> usize
+    {
+        if a == NIL || b == NIL
+        {
+            return if a == NIL { b } else { a };
+        }
+        if self.nodes[a].priority > self.nodes[b].priority
+        {
+            self.nodes[a].right = self.merge(self.nodes[a].right, b);
+            a
+        }
+        else
+        {
+            self.nodes[b].left = self.merge(a, self.nodes[b].left);
+            b
+        }
+    }
+
+    fn extreme(&self, mut root: usize, rightmost: bool) -> Option<usize>
+    {
+        if root == NIL
+        {
+            return None;
+        }
+        loop
+        {
+            let next = if rightmost { self.nodes[root].right } else { self.nodes[root].left };
+            if next == NIL
+            {
+                return Some(self.nodes[root].segment);
+            }
+            root = next;
+        }
+    }
+
+    // Appends the segments in order and frees their nodes.
+    fn drain(&mut self, root: usize, out: &mut Vec<usize>)
+    {
+        if root == NIL
+        {
+            return;
+        }
+        let (left, right) = (self.nodes[root].left, self.nodes[root].right);
+        self.drain(left, out);
+        out.push(self.nodes[root].segment);
+        self.free.push(root);
+        self.drain(right, out);
+    }
+}
+
+// Every point where two or more of `segments` meet, by x and then y, with the segments
+// through it. `point` is the crossing rounded to f64, and the order is that of the
+// rounded points, so it stays monotonic where two exact points round the other way.
+//
+// Touching counts: segments sharing an endpoint meet there, and collinear overlaps are
+// reported at the ends of the overlap. Zero-length segments are points, reported where
+// they lie on another segment.
+pub fn segment_intersections(segments: &[Line]) -> Vec<IntersectionPoint>
+{
+    // Each segment from its first to its last point in sweep order.
+    let ends: Vec<(Point, Point)> = segments
+        .iter()
+        .map(|s| {
+            let before = (s.start.x, s.start.y) <= (s.end.x, s.end.y);
+            if before { (s.start, s.end) } else { (s.end, s.start) }
+        })
+        .collect();
+
+    // Events with the segments starting at each.
+    let mut queue: BTreeMap<Event, Vec<usize>> = BTreeMap::new();
+    for (i, (first, last)) in ends.iter().enumerate()
+    {
+        queue.entry(Event::Input(*first)).or_default().push(i);
+        queue.entry(Event::Input(*last)).or_default();
+    }
+
+    let mut status = Status { nodes: Vec::with_capacity(segments.len()), free: Vec::new(), seed: 0x9e37_79b9_7f4a_7c15 };
+    let mut root = NIL;
+    let mut result = Vec::new();
+    let mut through = Vec::new();
+    while let Some((event, starting)) = queue.pop_first()
+    {
+        // The status splits into the segments passing below the event, through it, and
+        // above it.
+        let (below, rest) = status.split(root, &|s| event.side(&ends[s].0, &ends[s].1) > 0.0);
+        let (on, above) = status.split(rest, &|s| event.side(&ends[s].0, &ends[s].1) >= 0.0);
+        through.clear();
+        status.drain(on, &mut through);
+
+        let mut involved: Vec<usize> = through.iter().chain(starting.iter()).copied().collect();
+        if involved.len() >= 2
+        {
+            involved.sort_unstable();
+            result.push(IntersectionPoint { point: event.approx(), segments: involved });
+        }
+
+        // Segments carrying on past the event, ordered bottom to top just after it by the
+        // side of each one's far end on the others.
+        let mut continuing: Vec<usize> = through
+            .iter()
+            .filter(|&&s| Event::Input(ends[s].1) != event)
+            .chain(starting.iter().filter(|&&s| ends[s].0 != ends[s].1))
+            .copied()
+            .collect();
+        continuing.sort_unstable_by(|&s, &t| {
+            sign(orient2d(&ends[s].0, &ends[s].1, &ends[t].1)).reverse().then(s.cmp(&t))
+        });
+
+        let mut middle = NIL;
+        for &s in &continuing
+        {
+            let node = status.node(s);
+            middle = status.merge(middle, node);
+        }
+        let (lower, upper) = (status.extreme(below, true), status.extreme(above, false));
+        root = status.merge(below, middle);
+        root = status.merge(root, above);
+
+        let mut schedule = |s: Option<usize>, t: Option<usize>| {
+            if let (Some(s), Some(t)) = (s, t)
+            {
+                let ((a, b), (c, d)) = (&ends[s], &ends[t]);
+                let (d1, d2) = (orient2d(c, d, a), orient2d(c, d, b));
+                let (d3, d4) = (orient2d(a, b, c), orient2d(a, b, d));
+                // Only proper crossings need scheduling; a segment touching another at
+                // an endpoint is found when the sweep reaches that endpoint.
+                if opposite(d1, d2) && opposite(d3, d4)
+                {
+                    let crossing = Event::crossing(a, b, c, d);
+                    if crossing > event
+                    {
+                        queue.entry(crossing).or_default();
+                    }
+                }
+            }
+        };
+        match (continuing.first(), continuing.last())
+        {
+            (Some(&first), Some(&last)) =>
+            {
+                schedule(lower, Some(first));
+                schedule(Some(last), upper);
+            }
+            _ => schedule(lower, upper),
+        }
+    }
+    // Rounding can swap neighbours by an ulp; the stable sort leaves everything else in
+    // sweep order.
+    result.sort_by(|p, q| sign(p.point.x - q.point.x).then(sign(p.point.y - q.point.y)));
+    result
+}
@@ -0,0 +1,322 @@
+use alloc::collections::{ BTreeMap, BTreeSet };
+use alloc::vec::Vec;
+
+use super::polygon::ring_signed_area;
+use super::predicates::{ incircle, orient2d };
+use super::{ Point, Polygon, Triangle };
+
+// Polygon triangulation by ear clipping, with holes joined to the exterior by bridge edges
+// (Eberly, "Triangulation by Ear Clipping"), and the constrained Delaunay triangulation
+// obtained from it by edge flips.
+//
+// Triangles are index triples into `Polygon::vertices`, the exterior followed by each hole
+// in order, and always wind counter-clockwise whatever the input winding. Rings are
+// expected to be simple and holes to lie inside the exterior without crossing it.
+
+// Inclusive of the boundary, for a counter-clockwise triangle.
+fn in_triangle(a: &Point, b: &Point, c: &Point, p: &Point) -> bool
+{
+    orient2d(a, b, p) >= 0.0 && orient2d(b, c, p) >= 0.0 && orient2d(c, a, p) >= 0.0
+}
+
+// Whether the direction from `ring[k]` towards `target` points into the polygon, given the
+// ring's interior is on its left.
+fn locally_inside(vertices: &[Point], ring: &[usize], k: usize, target: &Point) -> bool
+{
+    let n = ring.len();
+    let (prev, here, next) = (&vertices[ring[(k + n - 1) % n]], &vertices[ring[k]], &vertices[ring[(k + 1) % n]]);
+    if orient2d(prev, here, next) >= 0.0
+    {
+        orient2d(here, next, target) > 0.0 && orient2d(here, prev, target) < 0.0
+    }
+    else
+    {
+        orient2d(here, next, target) > 0.0 || orient2d(here, prev, target) < 0.0
+    }
+}
+
+// Splices `hole` into `outer` through a bridge from the hole's rightmost vertex to an outer
+// vertex it can see, leaving one ring that runs out along the bridge, around the hole and
+// back. Both bridge ends appear twice in the result.
+fn bridge_hole(vertices: &[Point], outer: &mut Vec<usize>, hole: &[usize])
+{
+    let start = (0..hole.len()).max_by(|&i, &j| vertices[hole[i]].x.total_cmp(&vertices[hole[j]].x)).unwrap_or(0);
+    let m = vertices[hole[start]];
+    let n = outer.len();
+
+    // Nearest edge hit by the ray from `m` towards +x, and the point it is hit at.
+    let mut hit: Option<(f64, usize)> = None;
+    for k in 0..n
+    {
+        let (a, b) = (&vertices[outer[k]], &vertices[outer[(k + 1) % n]]);
+        if (a.y > m.y) == (b.y > m.y) && a.y != m.y
+        {
+            continue;
+        }
+        let x = if a.y == b.y { a.x.min(b.x).max(m.x) } else { a.x + (m.y - a.y) * (b.x - a.x) / (b.y - a.y) };
+        if x >= m.x && hit.is_none_or(|(best, _)| x < best)
+        {
+            hit = Some((x, k));
+        }
+    }
+    let Some((x, k)) = hit
+    else
+    {
+        return;
+    };
+    let hit_point = Point::new(x, m.y);
+    let (a, b) = (vertices[outer[k]], vertices[outer[(k + 1) % n]]);
+    let mut bridge = if a == hit_point
+    {
+        k
+    }
+    else if b == hit_point || b.x > a.x
+    {
+        (k + 1) % n
+    }
+    else
+    {
+        k
+    };
+
+    // Reflex vertices inside the triangle `m, hit, candidate` could block the view; the
+    // one making the smallest angle with the ray is visible instead.
+    if vertices[outer[bridge]] != hit_point
+    {
+        let p = vertices[outer[bridge]];
+        let (t1, t2, t3) = if p.y < m.y { (m, p, hit_point) } else { (m, hit_point, p) };
+        let mut best: Option<(f64, f64)> = None;
+        for i in 0..n
+        {
+            let q = &vertices[outer[i]];
+            let reflex = orient2d(&vertices[outer[(i + n - 1) % n]], q, &vertices[outer[(i + 1) % n]]) < 0.0;
+            if !reflex || *q == p || !in_triangle(&t1, &t2, &t3, q)
+            {
+                continue;
+            }
+            let (dx, dy) = (q.x - m.x, q.y - m.y);
+            let slope = dy.abs() / dx;
+            let distance = dx * dx + dy * dy;
+            if dx > 0.0 && best.is_none_or(|(s, d)| slope < s || (slope == s && distance < d))
+            {
+                best = Some((slope, distance));
+                bridge = i;
+            }
+        }
+    }
+    // A vertex used by an earlier bridge appears twice; use the copy facing `m`.
+    if let Some(facing) = (0..n).find(|&i| outer[i] == outer[bridge] && locally_inside(vertices, outer, i, &m))
+    {
+        bridge = facing;
+    }
+
+    let mut spliced = Vec::with_capacity(n + hole.len() + 2);
+    spliced.extend_from_slice(&outer[..=bridge]);
+    spliced.extend((0..=hole.len()).map(|i| hole[(start + i) % hole.len()]));
+    spliced.extend_from_slice(&outer[bridge..]);
+    *outer = spliced;
+}
+
+// Ear clipping over a counter-clockwise ring of vertex indices.
+fn clip_ears(vertices: &[Point], ring: &[usize], triangles: &mut Vec<[usize; 3]>)
+{
+    let n = ring.len();
+    let mut prev: Vec<usize> = (0..n).map(|i| (i + n - 1) % n).collect();
+    let mut next: Vec<usize> = (0..n).map(|i| (i + 1) % n).collect();
+    let point = |i: usize| &vertices[ring[i]];
+    let mut remaining = n;
+    let mut current = 0;
+    let mut stalled = 0;
+    while remaining > 3
+    {
+        let (p, c, q) = (prev[current], current, next[current]);
+        let convex = orient2d(point(p), point(c), point(q)) > 0.0;
+        let is_ear = convex && {
+            let mut other = next[q];
+            let mut clear = true;
+            while other != p
+            {
+                let v = point(other);
+                let reflex = orient2d(point(prev[other]), v, point(next[other])) <= 0.0;
+                if reflex && v != point(p) && v != point(c) && v != point(q) && in_triangle(point(p), point(c), point(q), v)
+                {
+                    clear = false;
+                    break;
+                }
+                other = next[other];
+            }
+            clear
+        };
+        // After a full lap without an ear the ring is degenerate: drop a vertex that
+        // adds no area, or failing that clip any convex corner so the loop ends.
+        let forced = stalled >= remaining && (orient2d(point(p), point(c), point(q)) == 0.0 || (stalled >= 2 * remaining && convex));
+        if is_ear || forced
+        {
+            if convex
+            {
+                triangles.push([ring[p], ring[c], ring[q]]);
+            }
+            next[p] = q;
+            prev[q] = p;
+            remaining -= 1;
+            current = q;
+            stalled = 0;
+        }
+        else
+        {
+            current = q;
+            stalled += 1;
+            if stalled >= 3 * remaining
+            {
+                return;
+            }
+        }
+    }
+    let (p, c, q) = (prev[current], current, next[current]);
+    if orient2d(point(p), point(c), point(q)) > 0.0
+    {
+        triangles.push([ring[p], ring[c], ring[q]]);
+    }
+}
+
+// Lawson's flip algorithm: flips every unconstrained edge whose opposite vertex lies inside
+// the neighbouring circumcircle until none is left, which yields the constrained Delaunay
+// triangulation of the same domain.
+fn flip_to_delaunay(vertices: &[Point], triangles: &mut [[usize; 3]], constraints: &BTreeSet<(usize, usize)>)
+{
+    let mut owner: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (t, tri) in triangles.iter().enumerate()
+    {
+        for i in 0..3
+        {
+            owner.insert((tri[i], tri[(i + 1) % 3]), t);
+        }
+    }
+    let opposite = |tri: &[usize; 3], a: usize, b: usize| (0..3).find(|&i| tri[i] == a && tri[(i + 1) % 3] == b).map(|i| tri[(i + 2) % 3]);
+
+    let mut stack: Vec<(usize, usize)> = owner.keys().copied().filter(|&(a, b)| a < b).collect();
+    while let Some((a, b)) = stack.pop()
+    {
+        if constraints.contains(&(a.min(b), a.max(b)))
+        {
+            continue;
+        }
+        let (Some(&t1), Some(&t2)) = (owner.get(&(a, b)), owner.get(&(b, a)))
+        else
+        {
+            continue;
+        };
+        let (Some(c), Some(d)) = (opposite(&triangles[t1], a, b), opposite(&triangles[t2], b, a))
+        else
+        {
+            continue;
+        };
+        let (pa, pb, pc, pd) = (&vertices[a], &vertices[b], &vertices[c], &vertices[d]);
+        if incircle(pa, pb, pc, pd) <= 0.0 || orient2d(pa, pd, pc) <= 0.0 || orient2d(pd, pb, pc) <= 0.0
+        {
+            continue;
+        }
+        for tri in [triangles[t1], triangles[t2]]
+        {
+            for i in 0..3
+            {
+                owner.remove(&(tri[i], tri[(i + 1) % 3]));
+            }
+        }
+        triangles[t1] = [a, d, c];
+        triangles[t2] = [d, b, c];
+        for (t, tri) in [(t1, triangles[t1]), (t2, triangles[t2])]
+        {
+            for i in 0..3
+            {
+                owner.insert((tri[i], tri[(i + 1) % 3]), t);
+            }
+        }
+        stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+    }
+}
+
+impl Polygon
+{
+    // The exterior followed by each hole: the vertex order triangle indices refer to.
+    pub fn vertices(&self) -> Vec<Point>
+    {
+        self.exterior.iter().chain(self.holes.iter().flatten()).copied().collect()
+    }
+
+    // Index rings with the exterior counter-clockwise and holes clockwise.
+    fn index_rings(&self) -> (Vec<usize>, Vec<Vec<usize>>)
+    {
+        let mut offset = 0;
+        let mut ring = |points: &[Point], counter_clockwise: bool| {
+            let mut indices: Vec<usize> = (offset..offset + points.len()).collect();
+            offset += points.len();
+            if (ring_signed_area(points) > 0.0) != counter_clockwise
+            {
+                indices.reverse();
+            }
+            indices
+        };
+        let exterior = ring(&self.exterior, true);
+        let holes = self.holes.iter().map(|hole| ring(hole, false)).collect();
+        (exterior, holes)
+    }
+
+    // Ear-clipping triangulation; `n + 2h - 2` triangles for `n` vertices and `h` holes
+    // unless degenerate corners are dropped.
+    pub fn triangulate(&self) -> Vec<[usize; 3]>
+    {
+        let vertices = self.vertices();
+        let (mut ring, mut holes) = self.index_rings();
+        if ring.len() < 3
+        {
+            return Vec::new();
+        }
+        // Rightmost holes first, so each bridge only has to see past holes already joined.
+        let max_x = |hole: &Vec<usize>| hole.iter().map(|&i| vertices[i].x).fold(f64::NEG_INFINITY, f64::max);
+        holes.retain(|hole| hole.len() >= 3);
+        holes.sort_unstable_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+        for hole in &holes
+        {
+            bridge_hole(&vertices, &mut ring, hole);
+        }
+        let mut triangles = Vec::with_capacity(ring.len());
+        clip_ears(&vertices, &ring, &mut triangles);
+        triangles
+    }
+
+    // Constrained Delaunay triangulation: the polygon's edges are kept and every other edge
+    // satisfies the empty-circumcircle property.
+    pub fn triangulate_delaunay(&self) -> Vec<[usize; 3]>
+    {
+        let vertices = self.vertices();
+        let mut triangles = self.triangulate();
+        let (exterior, holes) = self.index_rings();
+        let mut constraints = BTreeSet::new();
+        for ring in core::iter::once(&exterior).chain(holes.iter())
+        {
+            for i in 0..ring.len()
+            {
+                let (a, b) = (ring[i], ring[(i + 1) % ring.len()]);
+                constraints.insert((a.min(b), a.max(b)));
+            }
+        }
+        flip_to_delaunay(&vertices, &mut triangles, &constraints);
+        triangles
+    }
+
+    pub fn triangles(&self) -> Vec<Triangle>
+    {
+        triangles_from_indices(&self.vertices(), &self.triangulate())
+    }
+
+    pub fn delaunay_triangles(&self) -> Vec<Triangle>
+    {
+        triangles_from_indices(&self.vertices(), &self.triangulate_delaunay())
+    }
+}
+
+pub fn triangles_from_indices(vertices: &[Point], indices: &[[usize; 3]]) -> Vec<Triangle>
+{
+    indices.iter().map(|&[a, b, c]| Triangle::new(vertices[a], vertices[b], vertices[c])).collect()
+}
@@ -2,7 +2,21 @@
 
 #[cfg(not(feature = "std"))]
 extern crate libm;
+extern crate alloc;
+
+pub mod angle;
+pub mod coordinates;
+pub mod dual_quat;
+pub mod error;
+pub mod euler;
+pub mod frame;
 pub mod math;
 pub mod matrix;
+pub mod quat;
+pub mod rotor;
+pub mod space;
 pub mod vector;
 pub mod geometry;
+mod swizzle;
+
+pub use error::VectoriaError;
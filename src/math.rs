@@ -1,13 +1,8 @@
-#![cfg_attr(not(feature = "std"), no_std)]
-
-#[cfg(not(feature = "std"))]
-extern crate libm;
-
-pub const PI: f32 = std::f32::consts::PI;
+pub const PI: f32 = core::f32::consts::PI;
 pub const TAU: f32 = 2.0 * PI;
-pub const E: f32 = std::f32::consts::E;
-pub const SQRT_2: f32 = std::f32::consts::SQRT_2;
-pub const LN_2: f32 = std::f32::consts::LN_2;
+pub const E: f32 = core::f32::consts::E;
+pub const SQRT_2: f32 = core::f32::consts::SQRT_2;
+pub const LN_2: f32 = core::f32::consts::LN_2;
 pub const DEG_TO_RAD: f32 = PI / 180.0;
 pub const RAD_TO_DEG: f32 = 180.0 / PI;
 pub const EPSILON: f32 = 1e-6;
@@ -36,6 +31,38 @@ pub fn lerp(start: f32, end: f32, t: f32) -> f32
     start + t * (end - start)
 }
 
+#[inline(always)]
+pub fn abs(a: f32) -> f32
+{
+    a.abs()
+}
+
+#[inline(always)]
+pub fn signum(a: f32) -> f32
+{
+    a.signum()
+}
+
+// GLSL-style fractional part, always in [0, 1) for finite inputs.
+#[inline(always)]
+pub fn fract(a: f32) -> f32
+{
+    a - floor(a)
+}
+
+#[inline(always)]
+pub fn step(edge: f32, x: f32) -> f32
+{
+    if x < edge { 0.0 } else { 1.0 }
+}
+
+#[inline(always)]
+pub fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32
+{
+    let t = clamp((x - edge0) / (edge1 - edge0), 0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
 #[inline(always)]
 pub fn approx_equal(a: f32, b: f32, epsilon: f32) -> bool
 {
@@ -60,45 +87,115 @@ pub fn radians_to_degrees(radians: f32) -> f32
     radians * RAD_TO_DEG
 }
 
-#[cfg(feature = "std")]
-#[inline(always)]
-pub fn sin(x: f32) -> f32
+// Every sqrt and transcendental call in the crate goes through the functions below, which
+// forward to the inherent float methods with `std` and to `libm` without it.
+macro_rules! unary_backend
 {
-    x.sin()
+    ($($name:ident, $name_f64:ident, $libm:ident, $libm_f64:ident;)*) =>
+    {
+        $(
+            #[cfg(feature = "std")]
+            #[inline(always)]
+            pub fn $name(x: f32) -> f32
+            {
+                x.$name()
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline(always)]
+            pub fn $name(x: f32) -> f32
+            {
+                libm::$libm(x)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline(always)]
+            pub fn $name_f64(x: f64) -> f64
+            {
+                x.$name()
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline(always)]
+            pub fn $name_f64(x: f64) -> f64
+            {
+                libm::$libm_f64(x)
+            }
+        )*
+    };
 }
 
-#[cfg(not(feature = "std"))]
-#[inline(always)]
-pub fn sin(x: f32) -> f32
+macro_rules! binary_backend
 {
-    libm::sinf(x)
+    ($($name:ident, $name_f64:ident, $libm:ident, $libm_f64:ident;)*) =>
+    {
+        $(
+            #[cfg(feature = "std")]
+            #[inline(always)]
+            pub fn $name(x: f32, y: f32) -> f32
+            {
+                x.$name(y)
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline(always)]
+            pub fn $name(x: f32, y: f32) -> f32
+            {
+                libm::$libm(x, y)
+            }
+
+            #[cfg(feature = "std")]
+            #[inline(always)]
+            pub fn $name_f64(x: f64, y: f64) -> f64
+            {
+                x.$name(y)
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline(always)]
+            pub fn $name_f64(x: f64, y: f64) -> f64
+            {
+                libm::$libm_f64(x, y)
+            }
+        )*
+    };
 }
 
-#[cfg(feature = "std")]
-#[inline(always)]
-pub fn cos(x: f32) -> f32
+unary_backend!
 {
-    x.cos()
+    sqrt, sqrt_f64, sqrtf, sqrt;
+    cbrt, cbrt_f64, cbrtf, cbrt;
+    sin, sin_f64, sinf, sin;
+    cos, cos_f64, cosf, cos;
+    tan, tan_f64, tanf, tan;
+    asin, asin_f64, asinf, asin;
+    acos, acos_f64, acosf, acos;
+    atan, atan_f64, atanf, atan;
+    exp, exp_f64, expf, exp;
+    ln, ln_f64, logf, log;
+    floor, floor_f64, floorf, floor;
+    ceil, ceil_f64, ceilf, ceil;
+    round, round_f64, roundf, round;
+    trunc, trunc_f64, truncf, trunc;
 }
 
-#[cfg(not(feature = "std"))]
-#[inline(always)]
-pub fn cos(x: f32) -> f32
+binary_backend!
 {
-    libm::cosf(x)
+    atan2, atan2_f64, atan2f, atan2;
+    powf, powf_f64, powf, pow;
+    hypot, hypot_f64, hypotf, hypot;
 }
 
 #[cfg(feature = "std")]
 #[inline(always)]
-pub fn tan(x: f32) -> f32
+pub fn mul_add(x: f32, a: f32, b: f32) -> f32
 {
-    x.tan()
+    x.mul_add(a, b)
 }
 
 #[cfg(not(feature = "std"))]
 #[inline(always)]
-pub fn tan(x: f32) -> f32
+pub fn mul_add(x: f32, a: f32, b: f32) -> f32
 {
-    libm::tanf(x)
+    libm::fmaf(x, a, b)
 }
-
@@ -1,12 +1,14 @@
-#![cfg_attr(not(feature = "std"), no_std)]
-
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::*;
 
-use crate::math::EPSILON;
+use crate::angle::Radians;
+use crate::error::{ check_len, VectoriaError };
+use crate::math::{ cos, sin, EPSILON };
+use crate::vector::Vec3;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Mat4
 {
@@ -37,6 +39,22 @@ impl Mat4
         }
     }
 
+    // Embeds `m` as the upper-left block of an otherwise identity matrix.
+    #[inline(always)]
+    pub fn from_mat3(m: &Mat3) -> Mat4
+    {
+        let d = &m.data;
+        Mat4
+        {
+            data: [
+                [d[0][0], d[0][1], d[0][2], 0.0],
+                [d[1][0], d[1][1], d[1][2], 0.0],
+                [d[2][0], d[2][1], d[2][2], 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
     #[inline(always)]
     pub fn add(&self, other: &Mat4) -> Mat4
     {
@@ -72,24 +90,21 @@ impl Mat4
     #[inline(always)]
     pub fn mul_simd(&self, other: &Mat4) -> Mat4
     {
+        // Each row of the product is a combination of the rows of `other`, computed once.
         let mut result = Mat4::new_zero();
-        for i in 0..4
-        {
-            unsafe {
-                for j in 0..4
-                {
-                    let mut dot_product = _mm_set1_ps(0.0);
-                    for k in 0..4
-                    {
-                        let a_element = _mm_set1_ps(self.data[i][k]);
-                        let b = _mm_loadu_ps(other.data[k].as_ptr());
-                        let product = _mm_mul_ps(a_element, b);
-                        dot_product = _mm_add_ps(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    _mm_storeu_ps(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
-                }
+        unsafe {
+            let b0 = _mm_loadu_ps(other.data[0].as_ptr());
+            let b1 = _mm_loadu_ps(other.data[1].as_ptr());
+            let b2 = _mm_loadu_ps(other.data[2].as_ptr());
+            let b3 = _mm_loadu_ps(other.data[3].as_ptr());
+            for i in 0..4
+            {
+                let row = &self.data[i];
+                let r = _mm_add_ps(
+                    _mm_add_ps(_mm_mul_ps(_mm_set1_ps(row[0]), b0), _mm_mul_ps(_mm_set1_ps(row[1]), b1)),
+                    _mm_add_ps(_mm_mul_ps(_mm_set1_ps(row[2]), b2), _mm_mul_ps(_mm_set1_ps(row[3]), b3)),
+                );
+                _mm_storeu_ps(result.data[i].as_mut_ptr(), r);
             }
         }
         result
@@ -100,23 +115,19 @@ impl Mat4
     pub fn mul_neon(&self, other: &Mat4) -> Mat4
     {
         let mut result = Mat4::new_zero();
-        for i in 0..4
-        {
-            unsafe {
-                for j in 0..4
-                {
-                    let mut dot_product = vdupq_n_f32(0.0);
-                    for k in 0..4
-                    {
-                        let a_element = vdupq_n_f32(self.data[i][k]);
-                        let b = vld1q_f32(other.data[k].as_ptr());
-                        let product = vmulq_f32(a_element, b);
-                        dot_product = vaddq_f32(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    vst1q_f32(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
-                }
+        unsafe {
+            let b0 = vld1q_f32(other.data[0].as_ptr());
+            let b1 = vld1q_f32(other.data[1].as_ptr());
+            let b2 = vld1q_f32(other.data[2].as_ptr());
+            let b3 = vld1q_f32(other.data[3].as_ptr());
+            for i in 0..4
+            {
+                let row = &self.data[i];
+                let r = vaddq_f32(
+                    vaddq_f32(vmulq_n_f32(b0, row[0]), vmulq_n_f32(b1, row[1])),
+                    vaddq_f32(vmulq_n_f32(b2, row[2]), vmulq_n_f32(b3, row[3])),
+                );
+                vst1q_f32(result.data[i].as_mut_ptr(), r);
             }
         }
         result
@@ -133,15 +144,23 @@ impl Mat4
         {
             self.mul_neon(other)
         }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            self.mul(other)
+        }
     }
 
     #[inline(always)]
-    pub fn invert(&self) -> Option<Mat4>
+    pub fn invert(&self) -> Result<Mat4, VectoriaError>
     {
         let det = self.determinant();
+        if !det.is_finite()
+        {
+            return Err(VectoriaError::NotFinite);
+        }
         if det.abs() < EPSILON
         {
-            return None;
+            return Err(VectoriaError::Singular);
         }
 
         let mut inv = Mat4::new_zero();
@@ -149,12 +168,13 @@ impl Mat4
         {
             for j in 0..4
             {
-                // Minor for element (i, j)
+                // Cofactor for element (i, j), transposed into the adjugate
                 let minor = self.get_minor(i, j);
-                inv.data[j][i] = minor.determinant() / det;
+                let sign = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
+                inv.data[j][i] = sign * minor.determinant() / det;
             }
         }
-        Some(inv)
+        Ok(inv)
     }
 
     #[inline(always)]
@@ -205,10 +225,11 @@ impl Mat4
     }
 
     #[inline(always)]
-    pub fn rotate_x(angle: f32) -> Mat4
+    pub fn rotate_x(angle: impl Into<Radians>) -> Mat4
     {
-        let cos_theta = angle.cos();
-        let sin_theta = angle.sin();
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
         Mat4
         {
             data: [
@@ -221,10 +242,11 @@ impl Mat4
     }
 
     #[inline(always)]
-    pub fn rotate_y(angle: f32) -> Mat4
+    pub fn rotate_y(angle: impl Into<Radians>) -> Mat4
     {
-        let cos_theta = angle.cos();
-        let sin_theta = angle.sin();
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
         Mat4
         {
             data: [
@@ -237,10 +259,11 @@ impl Mat4
     }
 
     #[inline(always)]
-    pub fn rotate_z(angle: f32) -> Mat4
+    pub fn rotate_z(angle: impl Into<Radians>) -> Mat4
     {
-        let cos_theta = angle.cos();
-        let sin_theta = angle.sin();
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
         Mat4
         {
             data: [
@@ -265,6 +288,56 @@ impl Mat4
             ],
         }
     }
+
+    // Treats the matrix as affine: the bottom row is ignored and no perspective divide is done.
+    #[inline(always)]
+    pub fn transform_point(&self, point: &Vec3) -> Vec3
+    {
+        let d = &self.data;
+        Vec3
+        {
+            x: d[0][0] * point.x + d[0][1] * point.y + d[0][2] * point.z + d[0][3],
+            y: d[1][0] * point.x + d[1][1] * point.y + d[1][2] * point.z + d[1][3],
+            z: d[2][0] * point.x + d[2][1] * point.y + d[2][2] * point.z + d[2][3],
+        }
+    }
+
+    #[inline(always)]
+    pub fn transform_vector(&self, vector: &Vec3) -> Vec3
+    {
+        let d = &self.data;
+        Vec3
+        {
+            x: d[0][0] * vector.x + d[0][1] * vector.y + d[0][2] * vector.z,
+            y: d[1][0] * vector.x + d[1][1] * vector.y + d[1][2] * vector.z,
+            z: d[2][0] * vector.x + d[2][1] * vector.y + d[2][2] * vector.z,
+        }
+    }
+
+    pub fn transform_points(&self, points: &[Vec3], out: &mut [Vec3]) -> Result<(), VectoriaError>
+    {
+        check_len(points.len(), out.len())?;
+        transform_batch(self, points, out, 1.0);
+        Ok(())
+    }
+
+    pub fn transform_vectors(&self, vectors: &[Vec3], out: &mut [Vec3]) -> Result<(), VectoriaError>
+    {
+        check_len(vectors.len(), out.len())?;
+        transform_batch(self, vectors, out, 0.0);
+        Ok(())
+    }
+
+    pub fn mul_batch(a: &[Mat4], b: &[Mat4], out: &mut [Mat4]) -> Result<(), VectoriaError>
+    {
+        check_len(a.len(), b.len())?;
+        check_len(a.len(), out.len())?;
+        for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut())
+        {
+            *out = a.mul_auto(b);
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -273,6 +346,31 @@ pub struct Mat3
     pub data: [[f32; 3]; 3],
 }
 
+// A Mat3 row padded to a full, 16-byte aligned vector register so the SIMD
+// multiplies never load past the end of the matrix.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+#[derive(Clone, Copy)]
+#[repr(C, align(16))]
+struct PaddedRow([f32; 4]);
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+struct PaddedRows([PaddedRow; 3]);
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+impl From<&Mat3> for PaddedRows
+{
+    #[inline(always)]
+    fn from(m: &Mat3) -> PaddedRows
+    {
+        let mut rows = PaddedRows([PaddedRow([0.0; 4]); 3]);
+        for i in 0..3
+        {
+            rows.0[i].0[..3].copy_from_slice(&m.data[i]);
+        }
+        rows
+    }
+}
+
 impl Mat3
 {
     pub fn new_identity() -> Mat3
@@ -296,6 +394,46 @@ impl Mat3
         }
     }
 
+    #[inline(always)]
+    pub fn from_columns(c0: &Vec3, c1: &Vec3, c2: &Vec3) -> Mat3
+    {
+        Mat3
+        {
+            data: [
+                [c0.x, c1.x, c2.x],
+                [c0.y, c1.y, c2.y],
+                [c0.z, c1.z, c2.z],
+            ],
+        }
+    }
+
+    #[inline(always)]
+    pub fn column(&self, index: usize) -> Vec3
+    {
+        Vec3::new(self.data[0][index], self.data[1][index], self.data[2][index])
+    }
+
+    // Gram-Schmidt orthonormalization of the columns, keeping the direction of the first
+    // column. Fails with `Singular` when the columns are linearly dependent, or so nearly
+    // so that what is left of a column after projection is mostly f32 rounding.
+    pub fn orthonormalize(&self) -> Result<Mat3, VectoriaError>
+    {
+        let residual = |column: &Vec3, rest: &Vec3| {
+            if rest.length() < 1e-4 * column.length()
+            {
+                return Err(VectoriaError::Singular);
+            }
+            rest.try_normalize().ok_or(VectoriaError::Singular)
+        };
+        let c0 = self.column(0);
+        let c1 = self.column(1);
+        let c2 = self.column(2);
+        let e0 = c0.try_normalize().ok_or(VectoriaError::Singular)?;
+        let e1 = residual(&c1, &c1.sub(&e0.mul_scale(c1.dot(&e0))))?;
+        let e2 = residual(&c2, &c2.sub(&e0.mul_scale(c2.dot(&e0))).sub(&e1.mul_scale(c2.dot(&e1))))?;
+        Ok(Mat3::from_columns(&e0, &e1, &e2))
+    }
+
     #[inline(always)]
     pub fn add(&self, other: &Mat3) -> Mat3
     {
@@ -332,24 +470,22 @@ impl Mat3
     #[inline(always)]
     pub fn mul_simd(&self, other: &Mat3) -> Mat3
     {
+        let rows = PaddedRows::from(other);
         let mut result = Mat3::new_zero();
-        for i in 0..3
-        {
-            unsafe {
-                for j in 0..3
-                {
-                    let mut dot_product = _mm_set1_ps(0.0);
-                    for k in 0..3
-                    {
-                        let a_element = _mm_set1_ps(self.data[i][k]);
-                        let b = _mm_loadu_ps(other.data[k].as_ptr());
-                        let product = _mm_mul_ps(a_element, b);
-                        dot_product = _mm_add_ps(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    _mm_storeu_ps(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
-                }
+        let mut temp = PaddedRow([0.0; 4]);
+        unsafe {
+            let b0 = _mm_load_ps(rows.0[0].0.as_ptr());
+            let b1 = _mm_load_ps(rows.0[1].0.as_ptr());
+            let b2 = _mm_load_ps(rows.0[2].0.as_ptr());
+            for i in 0..3
+            {
+                let row = &self.data[i];
+                let r = _mm_add_ps(
+                    _mm_add_ps(_mm_mul_ps(_mm_set1_ps(row[0]), b0), _mm_mul_ps(_mm_set1_ps(row[1]), b1)),
+                    _mm_mul_ps(_mm_set1_ps(row[2]), b2),
+                );
+                _mm_store_ps(temp.0.as_mut_ptr(), r);
+                result.data[i].copy_from_slice(&temp.0[..3]);
             }
         }
         result
@@ -359,23 +495,22 @@ impl Mat3
     #[inline(always)]
     pub fn mul_neon(&self, other: &Mat3) -> Mat3
     {
+        let rows = PaddedRows::from(other);
         let mut result = Mat3::new_zero();
-        for i in 0..3 {
-            unsafe {
-                for j in 0..3
-                {
-                    let mut dot_product = vdupq_n_f32(0.0);
-                    for k in 0..3
-                    {
-                        let a_element = vdupq_n_f32(self.data[i][k]);
-                        let b = vld1q_f32(other.data[k].as_ptr());
-                        let product = vmulq_f32(a_element, b);
-                        dot_product = vaddq_f32(dot_product, product);
-                    }
-                    let mut temp = [0.0; 4];
-                    vst1q_f32(temp.as_mut_ptr(), dot_product);
-                    result.data[i][j] = temp[j];
-                }
+        let mut temp = PaddedRow([0.0; 4]);
+        unsafe {
+            let b0 = vld1q_f32(rows.0[0].0.as_ptr());
+            let b1 = vld1q_f32(rows.0[1].0.as_ptr());
+            let b2 = vld1q_f32(rows.0[2].0.as_ptr());
+            for i in 0..3
+            {
+                let row = &self.data[i];
+                let r = vaddq_f32(
+                    vaddq_f32(vmulq_n_f32(b0, row[0]), vmulq_n_f32(b1, row[1])),
+                    vmulq_n_f32(b2, row[2]),
+                );
+                vst1q_f32(temp.0.as_mut_ptr(), r);
+                result.data[i].copy_from_slice(&temp.0[..3]);
             }
         }
         result
@@ -392,15 +527,23 @@ impl Mat3
         {
             self.mul_neon(other)
         }
+        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+        {
+            self.mul(other)
+        }
     }
 
     #[inline(always)]
-    pub fn invert(&self) -> Option<Mat3>
+    pub fn invert(&self) -> Result<Mat3, VectoriaError>
     {
         let det = self.determinant();
+        if !det.is_finite()
+        {
+            return Err(VectoriaError::NotFinite);
+        }
         if det.abs() < EPSILON
         {
-            return None;
+            return Err(VectoriaError::Singular);
         }
 
         let mut inv = Mat3::new_zero();
@@ -424,7 +567,7 @@ impl Mat3
             }
         }
 
-        Some(inv)
+        Ok(inv)
     }
 
     #[inline(always)]
@@ -434,4 +577,129 @@ impl Mat3
             self.data[0][1] * (self.data[1][0] * self.data[2][2] - self.data[1][2] * self.data[2][0]) +
             self.data[0][2] * (self.data[1][0] * self.data[2][1] - self.data[1][1] * self.data[2][0])
     }
-}
\ No newline at end of file
+
+    #[inline(always)]
+    pub fn rotate_x(angle: impl Into<Radians>) -> Mat3
+    {
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
+        Mat3
+        {
+            data: [
+                [1.0, 0.0, 0.0],
+                [0.0, cos_theta, -sin_theta],
+                [0.0, sin_theta, cos_theta],
+            ],
+        }
+    }
+
+    #[inline(always)]
+    pub fn rotate_y(angle: impl Into<Radians>) -> Mat3
+    {
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
+        Mat3
+        {
+            data: [
+                [cos_theta, 0.0, sin_theta],
+                [0.0, 1.0, 0.0],
+                [-sin_theta, 0.0, cos_theta],
+            ],
+        }
+    }
+
+    #[inline(always)]
+    pub fn rotate_z(angle: impl Into<Radians>) -> Mat3
+    {
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
+        Mat3
+        {
+            data: [
+                [cos_theta, -sin_theta, 0.0],
+                [sin_theta, cos_theta, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    // The upper-left 3x3 block of `m`.
+    #[inline(always)]
+    pub fn from_mat4(m: &Mat4) -> Mat3
+    {
+        let d = &m.data;
+        Mat3
+        {
+            data: [
+                [d[0][0], d[0][1], d[0][2]],
+                [d[1][0], d[1][1], d[1][2]],
+                [d[2][0], d[2][1], d[2][2]],
+            ],
+        }
+    }
+}
+
+// Kernels behind the Mat4 batch transforms, built on the columns of `m`.
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn transform_batch(m: &Mat4, input: &[Vec3], out: &mut [Vec3], w: f32)
+{
+    let d = &m.data;
+    unsafe {
+        let c0 = _mm_set_ps(0.0, d[2][0], d[1][0], d[0][0]);
+        let c1 = _mm_set_ps(0.0, d[2][1], d[1][1], d[0][1]);
+        let c2 = _mm_set_ps(0.0, d[2][2], d[1][2], d[0][2]);
+        let c3 = _mm_mul_ps(_mm_set_ps(0.0, d[2][3], d[1][3], d[0][3]), _mm_set1_ps(w));
+        let mut temp = [0.0; 4];
+        for (v, out) in input.iter().zip(out.iter_mut())
+        {
+            let r = _mm_add_ps(
+                _mm_add_ps(_mm_mul_ps(c0, _mm_set1_ps(v.x)), _mm_mul_ps(c1, _mm_set1_ps(v.y))),
+                _mm_add_ps(_mm_mul_ps(c2, _mm_set1_ps(v.z)), c3),
+            );
+            _mm_storeu_ps(temp.as_mut_ptr(), r);
+            *out = Vec3::new(temp[0], temp[1], temp[2]);
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn transform_batch(m: &Mat4, input: &[Vec3], out: &mut [Vec3], w: f32)
+{
+    let d = &m.data;
+    let c0 = [d[0][0], d[1][0], d[2][0], 0.0];
+    let c1 = [d[0][1], d[1][1], d[2][1], 0.0];
+    let c2 = [d[0][2], d[1][2], d[2][2], 0.0];
+    let c3 = [d[0][3] * w, d[1][3] * w, d[2][3] * w, 0.0];
+    unsafe {
+        let c0 = vld1q_f32(c0.as_ptr());
+        let c1 = vld1q_f32(c1.as_ptr());
+        let c2 = vld1q_f32(c2.as_ptr());
+        let c3 = vld1q_f32(c3.as_ptr());
+        let mut temp = [0.0; 4];
+        for (v, out) in input.iter().zip(out.iter_mut())
+        {
+            let r = vaddq_f32(
+                vaddq_f32(vmulq_n_f32(c0, v.x), vmulq_n_f32(c1, v.y)),
+                vaddq_f32(vmulq_n_f32(c2, v.z), c3),
+            );
+            vst1q_f32(temp.as_mut_ptr(), r);
+            *out = Vec3::new(temp[0], temp[1], temp[2]);
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn transform_batch(m: &Mat4, input: &[Vec3], out: &mut [Vec3], w: f32)
+{
+    for (v, out) in input.iter().zip(out.iter_mut())
+    {
+        *out = m.transform_vector(v).add(&Vec3::new(m.data[0][3] * w, m.data[1][3] * w, m.data[2][3] * w));
+    }
+}
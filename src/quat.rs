@@ -0,0 +1,166 @@
+use crate::angle::Radians;
+use crate::math::{ cos, sin, sqrt };
+use crate::matrix::Mat3;
+use crate::vector::Vec3;
+
+// A Hamilton quaternion `w + xi + yj + zk`. Unit quaternions rotate vectors as `q v q*`,
+// matching the right-handed rotations of `Mat3::rotate_x/y/z`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Quat
+{
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat
+{
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quat
+    {
+        Quat { x, y, z, w }
+    }
+
+    #[inline(always)]
+    pub fn identity() -> Quat
+    {
+        Quat::new(0.0, 0.0, 0.0, 1.0)
+    }
+
+    // A pure quaternion with `v` as its vector part.
+    #[inline(always)]
+    pub fn from_vec3(v: &Vec3) -> Quat
+    {
+        Quat::new(v.x, v.y, v.z, 0.0)
+    }
+
+    pub fn from_axis_angle(axis: &Vec3, angle: impl Into<Radians>) -> Quat
+    {
+        let angle = angle.into().0;
+        let axis = axis.normalize();
+        let half_sin = sin(angle * 0.5);
+        Quat::new(axis.x * half_sin, axis.y * half_sin, axis.z * half_sin, cos(angle * 0.5))
+    }
+
+    // Converts a rotation matrix using Shepperd's method, picking the largest diagonal
+    // term to stay well conditioned.
+    pub fn from_mat3(m: &Mat3) -> Quat
+    {
+        let d = &m.data;
+        let trace = d[0][0] + d[1][1] + d[2][2];
+        let q = if trace > 0.0
+        {
+            let s = sqrt(trace + 1.0) * 2.0;
+            Quat::new((d[2][1] - d[1][2]) / s, (d[0][2] - d[2][0]) / s, (d[1][0] - d[0][1]) / s, 0.25 * s)
+        }
+        else if d[0][0] > d[1][1] && d[0][0] > d[2][2]
+        {
+            let s = sqrt(1.0 + d[0][0] - d[1][1] - d[2][2]) * 2.0;
+            Quat::new(0.25 * s, (d[0][1] + d[1][0]) / s, (d[0][2] + d[2][0]) / s, (d[2][1] - d[1][2]) / s)
+        }
+        else if d[1][1] > d[2][2]
+        {
+            let s = sqrt(1.0 + d[1][1] - d[0][0] - d[2][2]) * 2.0;
+            Quat::new((d[0][1] + d[1][0]) / s, 0.25 * s, (d[1][2] + d[2][1]) / s, (d[0][2] - d[2][0]) / s)
+        }
+        else
+        {
+            let s = sqrt(1.0 + d[2][2] - d[0][0] - d[1][1]) * 2.0;
+            Quat::new((d[0][2] + d[2][0]) / s, (d[1][2] + d[2][1]) / s, 0.25 * s, (d[1][0] - d[0][1]) / s)
+        };
+        q.normalize()
+    }
+
+    pub fn to_mat3(&self) -> Mat3
+    {
+        let (x, y, z, w) = (self.x, self.y, self.z, self.w);
+        Mat3
+        {
+            data: [
+                [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y)],
+                [2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)],
+                [2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y)],
+            ],
+        }
+    }
+
+    #[inline(always)]
+    pub fn vector(&self) -> Vec3
+    {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Quat) -> Quat
+    {
+        Quat::new(self.x + other.x, self.y + other.y, self.z + other.z, self.w + other.w)
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, other: &Quat) -> Quat
+    {
+        Quat::new(self.x - other.x, self.y - other.y, self.z - other.z, self.w - other.w)
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Quat
+    {
+        Quat::new(self.x * scale, self.y * scale, self.z * scale, self.w * scale)
+    }
+
+    // Hamilton product; `a.mul(&b)` applies `b` first, then `a`.
+    #[inline(always)]
+    pub fn mul(&self, other: &Quat) -> Quat
+    {
+        Quat
+        {
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+        }
+    }
+
+    #[inline(always)]
+    pub fn conjugate(&self) -> Quat
+    {
+        Quat::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Quat) -> f32
+    {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        sqrt(self.dot(self))
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Quat
+    {
+        let len = self.length();
+        if len > 0.0
+        {
+            self.mul_scale(1.0 / len)
+        }
+        else
+        {
+            *self
+        }
+    }
+
+    // Rotates `v` by this quaternion, which must be unit length.
+    #[inline(always)]
+    pub fn rotate(&self, v: &Vec3) -> Vec3
+    {
+        let u = self.vector();
+        let t = u.cross(v).mul_scale(2.0);
+        v.add(&t.mul_scale(self.w)).add(&u.cross(&t))
+    }
+}
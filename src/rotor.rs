@@ -0,0 +1,368 @@
+use crate::angle::Radians;
+use crate::math;
+use crate::math::{ acos, atan2, cos, sin, sqrt, EPSILON };
+use crate::matrix::Mat3;
+use crate::vector::{ Vec2, Vec3 };
+
+// Rotors from geometric algebra. A rotor `R = a + B` (scalar plus bivector) rotates a
+// vector by the sandwich product `R v R~`, where `R~ = a - B` is the reverse. A rotation
+// by `angle` in the plane of the unit bivector `B` (oriented from its first vector to
+// its second) is `cos(angle / 2) - sin(angle / 2) B`.
+
+// A bivector in 3D, stored by its components on the basis planes e1^e2, e1^e3 and e2^e3.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bivector3
+{
+    pub xy: f32,
+    pub xz: f32,
+    pub yz: f32,
+}
+
+impl Bivector3
+{
+    #[inline(always)]
+    pub fn new(xy: f32, xz: f32, yz: f32) -> Bivector3
+    {
+        Bivector3 { xy, xz, yz }
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Bivector3) -> Bivector3
+    {
+        Bivector3::new(self.xy + other.xy, self.xz + other.xz, self.yz + other.yz)
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Bivector3
+    {
+        Bivector3::new(self.xy * scale, self.xz * scale, self.yz * scale)
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        sqrt(self.xy * self.xy + self.xz * self.xz + self.yz * self.yz)
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Bivector3
+    {
+        let len = self.length();
+        if len > 0.0
+        {
+            self.mul_scale(1.0 / len)
+        }
+        else
+        {
+            *self
+        }
+    }
+}
+
+impl Vec3
+{
+    // The outer product `self ^ other`: the oriented plane spanned by the two vectors.
+    #[inline(always)]
+    pub fn wedge(&self, other: &Vec3) -> Bivector3
+    {
+        Bivector3
+        {
+            xy: self.x * other.y - self.y * other.x,
+            xz: self.x * other.z - self.z * other.x,
+            yz: self.y * other.z - self.z * other.y,
+        }
+    }
+
+    // The geometric product `self other = self . other + self ^ other`.
+    #[inline(always)]
+    pub fn geometric_product(&self, other: &Vec3) -> Rotor3
+    {
+        Rotor3::new(self.dot(other), self.wedge(other))
+    }
+}
+
+impl Vec2
+{
+    #[inline(always)]
+    pub fn geometric_product(&self, other: &Vec2) -> Rotor2
+    {
+        Rotor2::new(self.dot(other), self.perp_dot(other))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotor2
+{
+    pub a: f32,
+    pub xy: f32,
+}
+
+impl Rotor2
+{
+    #[inline(always)]
+    pub fn new(a: f32, xy: f32) -> Rotor2
+    {
+        Rotor2 { a, xy }
+    }
+
+    #[inline(always)]
+    pub fn identity() -> Rotor2
+    {
+        Rotor2::new(1.0, 0.0)
+    }
+
+    // Counter-clockwise rotation by `angle` radians.
+    #[inline(always)]
+    pub fn from_angle(angle: impl Into<Radians>) -> Rotor2
+    {
+        let angle = angle.into().0;
+        Rotor2::new(cos(angle * 0.5), -sin(angle * 0.5))
+    }
+
+    // The rotor turning the direction of `from` onto the direction of `to`.
+    pub fn from_vectors(from: &Vec2, to: &Vec2) -> Rotor2
+    {
+        let from = from.unit();
+        let to = to.unit();
+        let r = Rotor2::new(1.0 + to.dot(&from), to.perp_dot(&from));
+        if r.length() < EPSILON
+        {
+            // Opposite directions: a half turn.
+            return Rotor2::new(0.0, 1.0);
+        }
+        r.normalize()
+    }
+
+    #[inline(always)]
+    pub fn angle(&self) -> f32
+    {
+        -2.0 * atan2(self.xy, self.a)
+    }
+
+    #[inline(always)]
+    pub fn reverse(&self) -> Rotor2
+    {
+        Rotor2::new(self.a, -self.xy)
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Rotor2) -> f32
+    {
+        self.a * other.a + self.xy * other.xy
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        sqrt(self.dot(self))
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Rotor2
+    {
+        let len = self.length();
+        if len > 0.0
+        {
+            Rotor2::new(self.a / len, self.xy / len)
+        }
+        else
+        {
+            *self
+        }
+    }
+
+    // Geometric product; `a.mul(&b)` rotates by `b` first, then `a`.
+    #[inline(always)]
+    pub fn mul(&self, other: &Rotor2) -> Rotor2
+    {
+        Rotor2::new(
+            self.a * other.a - self.xy * other.xy,
+            self.a * other.xy + self.xy * other.a,
+        )
+    }
+
+    #[inline(always)]
+    pub fn rotate(&self, v: &Vec2) -> Vec2
+    {
+        let cos_theta = self.a * self.a - self.xy * self.xy;
+        let sin_theta = -2.0 * self.a * self.xy;
+        Vec2::new(cos_theta * v.x - sin_theta * v.y, sin_theta * v.x + cos_theta * v.y)
+    }
+
+    // Interpolates the rotation angle along the shortest arc.
+    pub fn slerp(&self, other: &Rotor2, t: f32) -> Rotor2
+    {
+        let other = if self.dot(other) < 0.0 { Rotor2::new(-other.a, -other.xy) } else { *other };
+        let delta = self.reverse().mul(&other);
+        self.mul(&Rotor2::from_angle(delta.angle() * t))
+    }
+
+    // As a 2D homogeneous matrix, which is also the rotation about the z axis in 3D.
+    pub fn to_mat3(&self) -> Mat3
+    {
+        let cos_theta = self.a * self.a - self.xy * self.xy;
+        let sin_theta = -2.0 * self.a * self.xy;
+        Mat3
+        {
+            data: [
+                [cos_theta, -sin_theta, 0.0],
+                [sin_theta, cos_theta, 0.0],
+                [0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rotor3
+{
+    pub a: f32,
+    pub b: Bivector3,
+}
+
+impl Rotor3
+{
+    #[inline(always)]
+    pub fn new(a: f32, b: Bivector3) -> Rotor3
+    {
+        Rotor3 { a, b }
+    }
+
+    #[inline(always)]
+    pub fn identity() -> Rotor3
+    {
+        Rotor3::new(1.0, Bivector3::new(0.0, 0.0, 0.0))
+    }
+
+    // Rotation by `angle` radians in `plane`, turning the plane's first vector towards its second.
+    pub fn from_plane_angle(plane: &Bivector3, angle: impl Into<Radians>) -> Rotor3
+    {
+        let angle = angle.into().0;
+        Rotor3::new(cos(angle * 0.5), plane.normalize().mul_scale(-sin(angle * 0.5)))
+    }
+
+    // The smallest rotation taking the direction of `from` onto the direction of `to`.
+    pub fn from_vectors(from: &Vec3, to: &Vec3) -> Rotor3
+    {
+        let from = from.normalize();
+        let to = to.normalize();
+        let r = Rotor3::new(1.0 + to.dot(&from), to.wedge(&from));
+        if r.length() < EPSILON
+        {
+            // Opposite directions: a half turn in any plane containing `from`.
+            let (ortho, _) = from.any_orthonormal_pair();
+            return Rotor3::new(0.0, ortho.wedge(&from));
+        }
+        r.normalize()
+    }
+
+    #[inline(always)]
+    pub fn reverse(&self) -> Rotor3
+    {
+        Rotor3::new(self.a, self.b.mul_scale(-1.0))
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Rotor3) -> f32
+    {
+        self.a * other.a + self.b.xy * other.b.xy + self.b.xz * other.b.xz + self.b.yz * other.b.yz
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        sqrt(self.dot(self))
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Rotor3) -> Rotor3
+    {
+        Rotor3::new(self.a + other.a, self.b.add(&other.b))
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Rotor3
+    {
+        Rotor3::new(self.a * scale, self.b.mul_scale(scale))
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Rotor3
+    {
+        let len = self.length();
+        if len > 0.0
+        {
+            self.mul_scale(1.0 / len)
+        }
+        else
+        {
+            *self
+        }
+    }
+
+    // Geometric product; `a.mul(&b)` rotates by `b` first, then `a`.
+    pub fn mul(&self, other: &Rotor3) -> Rotor3
+    {
+        let (a1, b) = (self.a, &self.b);
+        let (a2, c) = (other.a, &other.b);
+        Rotor3
+        {
+            a: a1 * a2 - b.xy * c.xy - b.xz * c.xz - b.yz * c.yz,
+            b: Bivector3
+            {
+                xy: a1 * c.xy + a2 * b.xy - b.xz * c.yz + b.yz * c.xz,
+                xz: a1 * c.xz + a2 * b.xz + b.xy * c.yz - b.yz * c.xy,
+                yz: a1 * c.yz + a2 * b.yz - b.xy * c.xz + b.xz * c.xy,
+            },
+        }
+    }
+
+    // The sandwich product `R v R~`.
+    pub fn rotate(&self, v: &Vec3) -> Vec3
+    {
+        let (a, b) = (self.a, &self.b);
+        // q = R v, a vector plus a trivector part.
+        let q1 = a * v.x + b.xy * v.y + b.xz * v.z;
+        let q2 = a * v.y - b.xy * v.x + b.yz * v.z;
+        let q3 = a * v.z - b.xz * v.x - b.yz * v.y;
+        let q123 = b.xy * v.z - b.xz * v.y + b.yz * v.x;
+        Vec3
+        {
+            x: a * q1 + b.xy * q2 + b.xz * q3 + b.yz * q123,
+            y: a * q2 - b.xy * q1 + b.yz * q3 - b.xz * q123,
+            z: a * q3 - b.xz * q1 - b.yz * q2 + b.xy * q123,
+        }
+    }
+
+    // Spherical interpolation along the shortest arc; both rotors must be unit length.
+    pub fn slerp(&self, other: &Rotor3, t: f32) -> Rotor3
+    {
+        let mut cos_theta = self.dot(other);
+        let other = if cos_theta < 0.0
+        {
+            cos_theta = -cos_theta;
+            other.mul_scale(-1.0)
+        }
+        else
+        {
+            *other
+        };
+        if cos_theta > 1.0 - EPSILON
+        {
+            return self.mul_scale(1.0 - t).add(&other.mul_scale(t)).normalize();
+        }
+        let theta = acos(math::clamp(cos_theta, -1.0, 1.0));
+        let sin_theta = sin(theta);
+        self.mul_scale(sin((1.0 - t) * theta) / sin_theta)
+            .add(&other.mul_scale(sin(t * theta) / sin_theta))
+    }
+
+    pub fn to_mat3(&self) -> Mat3
+    {
+        Mat3::from_columns(
+            &self.rotate(&Vec3::new(1.0, 0.0, 0.0)),
+            &self.rotate(&Vec3::new(0.0, 1.0, 0.0)),
+            &self.rotate(&Vec3::new(0.0, 0.0, 1.0)),
+        )
+    }
+}
@@ -0,0 +1,359 @@
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::error::VectoriaError;
+use crate::matrix::Mat4;
+use crate::vector;
+
+// Opt-in coordinate-space tagging. `Vec3<S>`, `Point3<S>` and `Transform<Src, Dst>` wrap the
+// raw `vector::Vec3` and `Mat4` with a zero-sized marker, so mixing spaces is a compile
+// error while the layout stays identical to the raw type. Any type can serve as a space;
+// the markers below cover the common ones.
+//
+// `PhantomData<fn() -> S>` keeps the wrappers `Send`, `Sync` and `Copy` regardless of `S`,
+// which is also why the trait impls are written out instead of derived.
+
+pub enum World {}
+pub enum View {}
+pub enum Object {}
+
+// Mixing spaces must fail to compile; the doctests below check that it does, next to a
+// version that keeps the spaces straight.
+#[cfg(doctest)]
+/// ```
+/// use vectoria::space::{ Object, Transform, Vec3, View, World };
+/// let to_world: Transform<Object, World> = Transform::from_raw(vectoria::matrix::Mat4::new_identity());
+/// let to_view: Transform<World, View> = Transform::from_raw(vectoria::matrix::Mat4::new_identity());
+/// let _ = to_world.then(&to_view);
+/// let _ = Vec3::<World>::new(1.0, 0.0, 0.0).add(&Vec3::<World>::new(0.0, 1.0, 0.0));
+/// ```
+///
+/// ```compile_fail
+/// use vectoria::space::{ Object, Transform, View, World };
+/// let to_world: Transform<Object, World> = Transform::from_raw(vectoria::matrix::Mat4::new_identity());
+/// let to_view: Transform<Object, View> = Transform::from_raw(vectoria::matrix::Mat4::new_identity());
+/// let _ = to_world.then(&to_view);
+/// ```
+///
+/// ```compile_fail
+/// use vectoria::space::{ Vec3, View, World };
+/// let _ = Vec3::<World>::new(1.0, 0.0, 0.0).add(&Vec3::<View>::new(0.0, 1.0, 0.0));
+/// ```
+pub struct MixedSpaces;
+
+#[repr(transparent)]
+pub struct Vec3<S>
+{
+    raw: vector::Vec3,
+    space: PhantomData<fn() -> S>,
+}
+
+#[repr(transparent)]
+pub struct Point3<S>
+{
+    raw: vector::Vec3,
+    space: PhantomData<fn() -> S>,
+}
+
+#[repr(transparent)]
+pub struct Transform<Src, Dst>
+{
+    raw: Mat4,
+    spaces: PhantomData<fn(Src) -> Dst>,
+}
+
+impl<S> Vec3<S>
+{
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32) -> Vec3<S>
+    {
+        Vec3::from_raw(vector::Vec3::new(x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn from_raw(raw: vector::Vec3) -> Vec3<S>
+    {
+        Vec3
+        {
+            raw,
+            space: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> &vector::Vec3
+    {
+        &self.raw
+    }
+
+    #[inline(always)]
+    pub fn into_raw(self) -> vector::Vec3
+    {
+        self.raw
+    }
+
+    // Reinterprets the vector in another space without changing its components.
+    #[inline(always)]
+    pub fn cast<T>(&self) -> Vec3<T>
+    {
+        Vec3::from_raw(self.raw)
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Vec3<S>) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.add(&other.raw))
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, other: &Vec3<S>) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.sub(&other.raw))
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.mul_scale(scale))
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Vec3<S>) -> f32
+    {
+        self.raw.dot(&other.raw)
+    }
+
+    #[inline(always)]
+    pub fn cross(&self, other: &Vec3<S>) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.cross(&other.raw))
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        self.raw.length()
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.normalize())
+    }
+}
+
+impl<S> Point3<S>
+{
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32) -> Point3<S>
+    {
+        Point3::from_raw(vector::Vec3::new(x, y, z))
+    }
+
+    #[inline(always)]
+    pub fn origin() -> Point3<S>
+    {
+        Point3::new(0.0, 0.0, 0.0)
+    }
+
+    #[inline(always)]
+    pub fn from_raw(raw: vector::Vec3) -> Point3<S>
+    {
+        Point3
+        {
+            raw,
+            space: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> &vector::Vec3
+    {
+        &self.raw
+    }
+
+    #[inline(always)]
+    pub fn into_raw(self) -> vector::Vec3
+    {
+        self.raw
+    }
+
+    #[inline(always)]
+    pub fn cast<T>(&self) -> Point3<T>
+    {
+        Point3::from_raw(self.raw)
+    }
+
+    // The displacement from `other` to `self`.
+    #[inline(always)]
+    pub fn sub(&self, other: &Point3<S>) -> Vec3<S>
+    {
+        Vec3::from_raw(self.raw.sub(&other.raw))
+    }
+
+    #[inline(always)]
+    pub fn add_vector(&self, offset: &Vec3<S>) -> Point3<S>
+    {
+        Point3::from_raw(self.raw.add(&offset.raw))
+    }
+
+    #[inline(always)]
+    pub fn sub_vector(&self, offset: &Vec3<S>) -> Point3<S>
+    {
+        Point3::from_raw(self.raw.sub(&offset.raw))
+    }
+
+    #[inline(always)]
+    pub fn distance(&self, other: &Point3<S>) -> f32
+    {
+        self.raw.distance(&other.raw)
+    }
+}
+
+impl<S> Transform<S, S>
+{
+    #[inline(always)]
+    pub fn identity() -> Transform<S, S>
+    {
+        Transform::from_raw(Mat4::new_identity())
+    }
+}
+
+impl<Src, Dst> Transform<Src, Dst>
+{
+    #[inline(always)]
+    pub fn from_raw(raw: Mat4) -> Transform<Src, Dst>
+    {
+        Transform
+        {
+            raw,
+            spaces: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    pub fn raw(&self) -> &Mat4
+    {
+        &self.raw
+    }
+
+    #[inline(always)]
+    pub fn into_raw(self) -> Mat4
+    {
+        self.raw
+    }
+
+    // Applies `self` first, then `next`.
+    #[inline(always)]
+    pub fn then<Next>(&self, next: &Transform<Dst, Next>) -> Transform<Src, Next>
+    {
+        Transform::from_raw(next.raw.mul(&self.raw))
+    }
+
+    // Matrix product `self * other`: applies `other` first, then `self`.
+    #[inline(always)]
+    pub fn mul<Prior>(&self, other: &Transform<Prior, Src>) -> Transform<Prior, Dst>
+    {
+        Transform::from_raw(self.raw.mul(&other.raw))
+    }
+
+    #[inline(always)]
+    pub fn invert(&self) -> Result<Transform<Dst, Src>, VectoriaError>
+    {
+        Ok(Transform::from_raw(self.raw.invert()?))
+    }
+
+    #[inline(always)]
+    pub fn transform_point(&self, point: &Point3<Src>) -> Point3<Dst>
+    {
+        Point3::from_raw(self.raw.transform_point(&point.raw))
+    }
+
+    #[inline(always)]
+    pub fn transform_vector(&self, vector: &Vec3<Src>) -> Vec3<Dst>
+    {
+        Vec3::from_raw(self.raw.transform_vector(&vector.raw))
+    }
+}
+
+impl<S> Clone for Vec3<S>
+{
+    #[inline(always)]
+    fn clone(&self) -> Vec3<S>
+    {
+        *self
+    }
+}
+
+impl<S> Copy for Vec3<S> {}
+
+impl<S> PartialEq for Vec3<S>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Vec3<S>) -> bool
+    {
+        self.raw == other.raw
+    }
+}
+
+impl<S> fmt::Debug for Vec3<S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_tuple("Vec3").field(&self.raw).finish()
+    }
+}
+
+impl<S> Clone for Point3<S>
+{
+    #[inline(always)]
+    fn clone(&self) -> Point3<S>
+    {
+        *self
+    }
+}
+
+impl<S> Copy for Point3<S> {}
+
+impl<S> PartialEq for Point3<S>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Point3<S>) -> bool
+    {
+        self.raw == other.raw
+    }
+}
+
+impl<S> fmt::Debug for Point3<S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_tuple("Point3").field(&self.raw).finish()
+    }
+}
+
+impl<Src, Dst> Clone for Transform<Src, Dst>
+{
+    #[inline(always)]
+    fn clone(&self) -> Transform<Src, Dst>
+    {
+        Transform::from_raw(self.raw.clone())
+    }
+}
+
+impl<Src, Dst> PartialEq for Transform<Src, Dst>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Transform<Src, Dst>) -> bool
+    {
+        self.raw == other.raw
+    }
+}
+
+impl<Src, Dst> fmt::Debug for Transform<Src, Dst>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_tuple("Transform").field(&self.raw).finish()
+    }
+}
@@ -1,11 +1,15 @@
-#![cfg_attr(not(feature = "std"), no_std)]
-
 #[cfg(target_arch = "aarch64")]
 use core::arch::aarch64::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+use crate::angle::Radians;
+use crate::error::{ check_len, VectoriaError };
+use crate::math;
+use crate::math::{ acos, cos, sin, sqrt, EPSILON, PI };
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Vec2
 {
     pub x: f32,
@@ -13,6 +17,7 @@ pub struct Vec2
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Vec3
 {
     pub x: f32,
@@ -21,6 +26,7 @@ pub struct Vec3
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Vec4
 {
     pub x: f32,
@@ -36,6 +42,24 @@ impl Vec2
         Vec2 { x, y }
     }
 
+    #[inline(always)]
+    pub fn with_x(&self, x: f32) -> Vec2
+    {
+        Vec2 { x, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn with_y(&self, y: f32) -> Vec2
+    {
+        Vec2 { y, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn extend(&self, z: f32) -> Vec3
+    {
+        Vec3::new(self.x, self.y, z)
+    }
+
     #[inline(always)]
     pub fn add(&self, other: &Vec2) -> Vec2
     {
@@ -75,7 +99,40 @@ impl Vec2
     #[inline(always)]
     pub fn length(&self) -> f32
     {
-        (self.x * self.x + self.y * self.y).sqrt()
+        sqrt(self.length_squared())
+    }
+
+    #[inline(always)]
+    pub fn length_squared(&self) -> f32
+    {
+        self.dot(self)
+    }
+
+    #[inline(always)]
+    pub fn is_normalized(&self) -> bool
+    {
+        math::approx_equal(self.length_squared(), 1.0, 1e-4)
+    }
+
+    // Returns `None` when the vector is zero, too small to normalize or not finite.
+    #[inline(always)]
+    pub fn try_normalize(&self) -> Option<Vec2>
+    {
+        let inv = 1.0 / self.length();
+        if inv.is_finite() && inv > 0.0
+        {
+            Some(self.mul_scale(inv))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn normalize_or(&self, fallback: Vec2) -> Vec2
+    {
+        self.try_normalize().unwrap_or(fallback)
     }
 
     #[inline(always)]
@@ -95,20 +152,29 @@ impl Vec2
     #[inline(always)]
     pub fn distance(&self, other: &Vec2) -> f32
     {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2)).sqrt()
+        self.sub(other).length()
     }
 
     #[inline(always)]
     pub fn angle(&self, other: &Vec2) -> f32
     {
-        (self.dot(other) / (self.length() * other.length())).acos()
+        let denom = self.length() * other.length();
+        if denom <= 0.0
+        {
+            return 0.0;
+        }
+        acos(math::clamp(self.dot(other) / denom, -1.0, 1.0))
     }
 
     #[inline(always)]
     pub fn project(&self, other: &Vec2) -> Vec2
     {
-        let scale = self.dot(other) / other.dot(other);
-        other.mul_scale(scale)
+        let len_sq = other.length_squared();
+        if len_sq <= 0.0
+        {
+            return other.mul_scale(0.0);
+        }
+        other.mul_scale(self.dot(other) / len_sq)
     }
 
     #[inline(always)]
@@ -121,211 +187,920 @@ impl Vec2
             y: self.y - 2.0 * dot * normal.y,
         }
     }
-}
 
-impl Vec3
-{
-    pub fn new(x: f32, y: f32, z: f32) -> Vec3
+    #[inline(always)]
+    fn map(&self, f: impl Fn(f32) -> f32) -> Vec2
     {
-        Vec3 { x, y, z }
+        Vec2
+        {
+            x: f(self.x),
+            y: f(self.y),
+        }
     }
 
     #[inline(always)]
-    pub fn add(&self, other: &Vec3) -> Vec3
+    fn zip_map(&self, other: &Vec2, f: impl Fn(f32, f32) -> f32) -> Vec2
     {
-        Vec3
+        Vec2
         {
-            x: self.x + other.x,
-            y: self.y + other.y,
-            z: self.z + other.z,
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
         }
     }
 
     #[inline(always)]
-    pub fn sub(&self, other: &Vec3) -> Vec3
+    pub fn min(&self, other: &Vec2) -> Vec2
     {
-        Vec3
+        self.zip_map(other, math::min)
+    }
+
+    #[inline(always)]
+    pub fn max(&self, other: &Vec2) -> Vec2
+    {
+        self.zip_map(other, math::max)
+    }
+
+    #[inline(always)]
+    pub fn clamp(&self, min: &Vec2, max: &Vec2) -> Vec2
+    {
+        self.max(min).min(max)
+    }
+
+    #[inline(always)]
+    pub fn abs(&self) -> Vec2
+    {
+        self.map(math::abs)
+    }
+
+    #[inline(always)]
+    pub fn signum(&self) -> Vec2
+    {
+        self.map(math::signum)
+    }
+
+    #[inline(always)]
+    pub fn floor(&self) -> Vec2
+    {
+        self.map(math::floor)
+    }
+
+    #[inline(always)]
+    pub fn ceil(&self) -> Vec2
+    {
+        self.map(math::ceil)
+    }
+
+    #[inline(always)]
+    pub fn round(&self) -> Vec2
+    {
+        self.map(math::round)
+    }
+
+    #[inline(always)]
+    pub fn fract(&self) -> Vec2
+    {
+        self.map(math::fract)
+    }
+
+    #[inline(always)]
+    pub fn recip(&self) -> Vec2
+    {
+        self.map(|v| 1.0 / v)
+    }
+
+    // Computes `self * a + b` per component with a single rounding.
+    #[inline(always)]
+    pub fn mul_add(&self, a: &Vec2, b: &Vec2) -> Vec2
+    {
+        Vec2
         {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
+            x: math::mul_add(self.x, a.x, b.x),
+            y: math::mul_add(self.y, a.y, b.y),
         }
     }
 
     #[inline(always)]
-    pub fn mul_scale(&self, scale: f32) -> Vec3
+    pub fn powf(&self, n: f32) -> Vec2
     {
-        Vec3
+        self.map(|v| math::powf(v, n))
+    }
+
+    #[inline(always)]
+    pub fn exp(&self) -> Vec2
+    {
+        self.map(math::exp)
+    }
+
+    #[inline(always)]
+    pub fn lerp(&self, other: &Vec2, t: f32) -> Vec2
+    {
+        self.zip_map(other, |a, b| math::lerp(a, b, t))
+    }
+
+    // Returns 0.0 for components below `edge` and 1.0 otherwise, like GLSL `step(edge, self)`.
+    #[inline(always)]
+    pub fn step(&self, edge: &Vec2) -> Vec2
+    {
+        edge.zip_map(self, math::step)
+    }
+
+    #[inline(always)]
+    pub fn smoothstep(&self, edge0: &Vec2, edge1: &Vec2) -> Vec2
+    {
+        Vec2
         {
-            x: self.x * scale,
-            y: self.y * scale,
-            z: self.z * scale,
+            x: math::smoothstep(edge0.x, edge1.x, self.x),
+            y: math::smoothstep(edge0.y, edge1.y, self.y),
         }
     }
 
     #[inline(always)]
-    pub fn dot(&self, other: &Vec3) -> f32
+    pub fn min_element(&self) -> f32
     {
-        self.x * other.x + self.y * other.y + self.z * other.z
+        math::min(self.x, self.y)
     }
 
     #[inline(always)]
-    pub fn cross(&self, other: &Vec3) -> Vec3
+    pub fn max_element(&self) -> f32
     {
-        Vec3
+        math::max(self.x, self.y)
+    }
+
+    #[inline(always)]
+    pub fn sum(&self) -> f32
+    {
+        self.x + self.y
+    }
+
+    #[inline(always)]
+    pub fn product(&self) -> f32
+    {
+        self.x * self.y
+    }
+
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool
+    {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    #[inline(always)]
+    pub fn is_nan(&self) -> bool
+    {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    // Spherically interpolates between the directions of `self` and `other` while the
+    // length is interpolated linearly. Falls back to `lerp` when either vector is zero.
+    pub fn slerp(&self, other: &Vec2, t: f32) -> Vec2
+    {
+        let len_a = self.length();
+        let len_b = other.length();
+        if len_a <= 0.0 || len_b <= 0.0
         {
-            x: self.y * other.z - self.z * other.y,
-            y: self.z * other.x - self.x * other.z,
-            z: self.x * other.y - self.y * other.x,
+            return self.lerp(other, t);
         }
+        let a = self.mul_scale(1.0 / len_a);
+        let b = other.mul_scale(1.0 / len_b);
+        a.slerp_unit(&b, t).mul_scale(math::lerp(len_a, len_b, t))
     }
 
     #[inline(always)]
-    pub fn length(&self) -> f32
+    pub fn nlerp(&self, other: &Vec2, t: f32) -> Vec2
     {
-        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+        self.lerp(other, t).unit()
     }
 
-    #[inline(always)]
-    pub fn normalize(&self) -> Vec3
+    pub fn move_towards(&self, target: &Vec2, max_distance: f32) -> Vec2
     {
-        let len: f32 = self.length();
-        if len > 0.0
+        let delta = target.sub(self);
+        let distance = delta.length();
+        if distance <= max_distance || distance <= 0.0
         {
-            self.mul_scale(1.0 / len)
+            *target
         }
         else
         {
-            *self
+            self.add(&delta.mul_scale(max_distance / distance))
         }
     }
 
-    #[inline(always)]
-    pub fn distance(&self, other: &Vec3) -> f32
+    // Turns `self` towards the direction of `target` by at most `max_angle`,
+    // keeping the length of `self`.
+    pub fn rotate_towards(&self, target: &Vec2, max_angle: impl Into<Radians>) -> Vec2
+    {
+        let max_angle = max_angle.into().0;
+        let len = self.length();
+        let len_target = target.length();
+        if len <= 0.0 || len_target <= 0.0
+        {
+            return *self;
+        }
+        let a = self.mul_scale(1.0 / len);
+        let b = target.mul_scale(1.0 / len_target);
+        let angle = acos(math::clamp(a.dot(&b), -1.0, 1.0));
+        if angle <= max_angle
+        {
+            return b.mul_scale(len);
+        }
+        a.slerp_unit(&b, max_angle / angle).mul_scale(len)
+    }
+
+    fn slerp_unit(&self, other: &Vec2, t: f32) -> Vec2
     {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2)).sqrt()
+        let cos_theta = math::clamp(self.dot(other), -1.0, 1.0);
+        if cos_theta > 1.0 - EPSILON
+        {
+            return self.lerp(other, t).unit();
+        }
+        if cos_theta < -1.0 + EPSILON
+        {
+            // Antiparallel: any great circle works, so rotate through a perpendicular direction.
+            let ortho = self.perp();
+            return self.mul_scale(cos(t * PI)).add(&ortho.mul_scale(sin(t * PI)));
+        }
+        let theta = acos(cos_theta);
+        let sin_theta = sin(theta);
+        self.mul_scale(sin((1.0 - t) * theta) / sin_theta).add(&other.mul_scale(sin(t * theta) / sin_theta))
     }
 
     #[inline(always)]
-    pub fn angle(&self, other: &Vec3) -> f32
+    pub fn rotate(&self, angle: impl Into<Radians>) -> Vec2
     {
-        (self.dot(other) / (self.length() * other.length())).acos()
+        let angle = angle.into().0;
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
+        Vec2
+        {
+            x: self.x * cos_theta - self.y * sin_theta,
+            y: self.x * sin_theta + self.y * cos_theta,
+        }
     }
 
     #[inline(always)]
-    pub fn project(&self, other: &Vec3) -> Vec3
+    pub fn perp(&self) -> Vec2
     {
-        let scale = self.dot(other) / other.dot(other);
-        other.mul_scale(scale)
+        Vec2
+        {
+            x: -self.y,
+            y: self.x,
+        }
     }
 
+    // The z component of the 3D cross product, positive when `other` is counter-clockwise of `self`.
     #[inline(always)]
-    pub fn reflect(&self, normal: &Vec3) -> Vec3
+    pub fn perp_dot(&self, other: &Vec2) -> f32
     {
-        let dot = self.dot(normal);
-        Vec3 {
-            x: self.x - 2.0 * dot * normal.x,
-            y: self.y - 2.0 * dot * normal.y,
-            z: self.z - 2.0 * dot * normal.z,
-        }
+        self.x * other.y - self.y * other.x
     }
 }
 
-impl Vec4
+impl Vec3
 {
+    pub fn new(x: f32, y: f32, z: f32) -> Vec3
+    {
+        Vec3 { x, y, z }
+    }
+
     #[inline(always)]
-    pub fn as_ptr(&self) -> *const f32
+    pub fn with_x(&self, x: f32) -> Vec3
     {
-        &self.x as *const f32
+        Vec3 { x, ..*self }
     }
 
     #[inline(always)]
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vec4
+    pub fn with_y(&self, y: f32) -> Vec3
     {
-        Vec4 { x, y, z, w }
+        Vec3 { y, ..*self }
     }
 
     #[inline(always)]
-    pub fn add(&self, other: &Vec4) -> Vec4
+    pub fn with_z(&self, z: f32) -> Vec3
     {
-        Vec4
+        Vec3 { z, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn extend(&self, w: f32) -> Vec4
+    {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    #[inline(always)]
+    pub fn truncate(&self) -> Vec2
+    {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Vec3) -> Vec3
+    {
+        Vec3
         {
             x: self.x + other.x,
             y: self.y + other.y,
             z: self.z + other.z,
-            w: self.w + other.w,
         }
     }
 
     #[inline(always)]
-    pub fn sub(&self, other: &Vec4) -> Vec4
+    pub fn sub(&self, other: &Vec3) -> Vec3
     {
-        Vec4
+        Vec3
         {
             x: self.x - other.x,
             y: self.y - other.y,
             z: self.z - other.z,
-            w: self.w - other.w,
         }
     }
 
     #[inline(always)]
-    pub fn mul_scale(&self, scale: f32) -> Vec4
+    pub fn mul_scale(&self, scale: f32) -> Vec3
     {
-        Vec4
+        Vec3
         {
             x: self.x * scale,
             y: self.y * scale,
             z: self.z * scale,
-            w: self.w * scale,
         }
     }
 
     #[inline(always)]
-    pub fn dot(&self, other: &Vec4) -> f32
+    pub fn dot(&self, other: &Vec3) -> f32
     {
-        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+        self.x * other.x + self.y * other.y + self.z * other.z
     }
 
-    #[cfg(target_arch = "x86_64")]
     #[inline(always)]
-    pub fn dot_simd(&self, other: &Vec4) -> f32
+    pub fn cross(&self, other: &Vec3) -> Vec3
     {
-        unsafe {
-            let a = _mm_loadu_ps(self.as_ptr());
-            let b = _mm_loadu_ps(other.as_ptr());
-            let product = _mm_mul_ps(a, b);
-            let sum1 = _mm_hadd_ps(product, product);
-            let sum2 = _mm_hadd_ps(sum1, sum1);
-            _mm_cvtss_f32(sum2)
+        Vec3
+        {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
         }
     }
 
-    #[cfg(target_arch = "aarch64")]
     #[inline(always)]
-    pub fn dot_simd(&self, other: &Vec4) -> f32
+    pub fn length(&self) -> f32
     {
-        unsafe {
-            let a = vld1q_f32(self.as_ptr());
-            let b = vld1q_f32(other.as_ptr());
-            let product = vmulq_f32(a, b);
-            let sum1 = vaddq_f32(product, vextq_f32::<2>(product, product));
-            let sum2 = vaddq_f32(sum1, vextq_f32::<1>(sum1, sum1));
-            vgetq_lane_f32::<0>(sum2)
-        }
+        sqrt(self.length_squared())
     }
 
     #[inline(always)]
-    pub fn length(&self) -> f32
+    pub fn length_squared(&self) -> f32
     {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2) + self.w.powi(2)).sqrt()
+        self.dot(self)
     }
 
     #[inline(always)]
-    pub fn unit(&self) -> Vec4
+    pub fn is_normalized(&self) -> bool
     {
-        let len = self.length();
-        if len > 0.0
+        math::approx_equal(self.length_squared(), 1.0, 1e-4)
+    }
+
+    // Returns `None` when the vector is zero, too small to normalize or not finite.
+    #[inline(always)]
+    pub fn try_normalize(&self) -> Option<Vec3>
+    {
+        let inv = 1.0 / self.length();
+        if inv.is_finite() && inv > 0.0
+        {
+            Some(self.mul_scale(inv))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn normalize_or(&self, fallback: Vec3) -> Vec3
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline(always)]
+    pub fn normalize(&self) -> Vec3
+    {
+        let len: f32 = self.length();
+        if len > 0.0
+        {
+            self.mul_scale(1.0 / len)
+        }
+        else
+        {
+            *self
+        }
+    }
+
+    #[inline(always)]
+    pub fn distance(&self, other: &Vec3) -> f32
+    {
+        self.sub(other).length()
+    }
+
+    #[inline(always)]
+    pub fn angle(&self, other: &Vec3) -> f32
+    {
+        let denom = self.length() * other.length();
+        if denom <= 0.0
+        {
+            return 0.0;
+        }
+        acos(math::clamp(self.dot(other) / denom, -1.0, 1.0))
+    }
+
+    #[inline(always)]
+    pub fn project(&self, other: &Vec3) -> Vec3
+    {
+        let len_sq = other.length_squared();
+        if len_sq <= 0.0
+        {
+            return other.mul_scale(0.0);
+        }
+        other.mul_scale(self.dot(other) / len_sq)
+    }
+
+    #[inline(always)]
+    pub fn reflect(&self, normal: &Vec3) -> Vec3
+    {
+        let dot = self.dot(normal);
+        Vec3 {
+            x: self.x - 2.0 * dot * normal.x,
+            y: self.y - 2.0 * dot * normal.y,
+            z: self.z - 2.0 * dot * normal.z,
+        }
+    }
+
+    #[inline(always)]
+    fn map(&self, f: impl Fn(f32) -> f32) -> Vec3
+    {
+        Vec3
+        {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    #[inline(always)]
+    fn zip_map(&self, other: &Vec3, f: impl Fn(f32, f32) -> f32) -> Vec3
+    {
+        Vec3
+        {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+        }
+    }
+
+    #[inline(always)]
+    pub fn min(&self, other: &Vec3) -> Vec3
+    {
+        self.zip_map(other, math::min)
+    }
+
+    #[inline(always)]
+    pub fn max(&self, other: &Vec3) -> Vec3
+    {
+        self.zip_map(other, math::max)
+    }
+
+    #[inline(always)]
+    pub fn clamp(&self, min: &Vec3, max: &Vec3) -> Vec3
+    {
+        self.max(min).min(max)
+    }
+
+    #[inline(always)]
+    pub fn abs(&self) -> Vec3
+    {
+        self.map(math::abs)
+    }
+
+    #[inline(always)]
+    pub fn signum(&self) -> Vec3
+    {
+        self.map(math::signum)
+    }
+
+    #[inline(always)]
+    pub fn floor(&self) -> Vec3
+    {
+        self.map(math::floor)
+    }
+
+    #[inline(always)]
+    pub fn ceil(&self) -> Vec3
+    {
+        self.map(math::ceil)
+    }
+
+    #[inline(always)]
+    pub fn round(&self) -> Vec3
+    {
+        self.map(math::round)
+    }
+
+    #[inline(always)]
+    pub fn fract(&self) -> Vec3
+    {
+        self.map(math::fract)
+    }
+
+    #[inline(always)]
+    pub fn recip(&self) -> Vec3
+    {
+        self.map(|v| 1.0 / v)
+    }
+
+    // Computes `self * a + b` per component with a single rounding.
+    #[inline(always)]
+    pub fn mul_add(&self, a: &Vec3, b: &Vec3) -> Vec3
+    {
+        Vec3
+        {
+            x: math::mul_add(self.x, a.x, b.x),
+            y: math::mul_add(self.y, a.y, b.y),
+            z: math::mul_add(self.z, a.z, b.z),
+        }
+    }
+
+    #[inline(always)]
+    pub fn powf(&self, n: f32) -> Vec3
+    {
+        self.map(|v| math::powf(v, n))
+    }
+
+    #[inline(always)]
+    pub fn exp(&self) -> Vec3
+    {
+        self.map(math::exp)
+    }
+
+    #[inline(always)]
+    pub fn lerp(&self, other: &Vec3, t: f32) -> Vec3
+    {
+        self.zip_map(other, |a, b| math::lerp(a, b, t))
+    }
+
+    // Returns 0.0 for components below `edge` and 1.0 otherwise, like GLSL `step(edge, self)`.
+    #[inline(always)]
+    pub fn step(&self, edge: &Vec3) -> Vec3
+    {
+        edge.zip_map(self, math::step)
+    }
+
+    #[inline(always)]
+    pub fn smoothstep(&self, edge0: &Vec3, edge1: &Vec3) -> Vec3
+    {
+        Vec3
+        {
+            x: math::smoothstep(edge0.x, edge1.x, self.x),
+            y: math::smoothstep(edge0.y, edge1.y, self.y),
+            z: math::smoothstep(edge0.z, edge1.z, self.z),
+        }
+    }
+
+    #[inline(always)]
+    pub fn min_element(&self) -> f32
+    {
+        math::min(math::min(self.x, self.y), self.z)
+    }
+
+    #[inline(always)]
+    pub fn max_element(&self) -> f32
+    {
+        math::max(math::max(self.x, self.y), self.z)
+    }
+
+    #[inline(always)]
+    pub fn sum(&self) -> f32
+    {
+        self.x + self.y + self.z
+    }
+
+    #[inline(always)]
+    pub fn product(&self) -> f32
+    {
+        self.x * self.y * self.z
+    }
+
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool
+    {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    #[inline(always)]
+    pub fn is_nan(&self) -> bool
+    {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    // Spherically interpolates between the directions of `self` and `other` while the
+    // length is interpolated linearly. Falls back to `lerp` when either vector is zero.
+    pub fn slerp(&self, other: &Vec3, t: f32) -> Vec3
+    {
+        let len_a = self.length();
+        let len_b = other.length();
+        if len_a <= 0.0 || len_b <= 0.0
+        {
+            return self.lerp(other, t);
+        }
+        let a = self.mul_scale(1.0 / len_a);
+        let b = other.mul_scale(1.0 / len_b);
+        a.slerp_unit(&b, t).mul_scale(math::lerp(len_a, len_b, t))
+    }
+
+    #[inline(always)]
+    pub fn nlerp(&self, other: &Vec3, t: f32) -> Vec3
+    {
+        self.lerp(other, t).normalize()
+    }
+
+    pub fn move_towards(&self, target: &Vec3, max_distance: f32) -> Vec3
+    {
+        let delta = target.sub(self);
+        let distance = delta.length();
+        if distance <= max_distance || distance <= 0.0
+        {
+            *target
+        }
+        else
+        {
+            self.add(&delta.mul_scale(max_distance / distance))
+        }
+    }
+
+    // Turns `self` towards the direction of `target` by at most `max_angle`,
+    // keeping the length of `self`.
+    pub fn rotate_towards(&self, target: &Vec3, max_angle: impl Into<Radians>) -> Vec3
+    {
+        let max_angle = max_angle.into().0;
+        let len = self.length();
+        let len_target = target.length();
+        if len <= 0.0 || len_target <= 0.0
+        {
+            return *self;
+        }
+        let a = self.mul_scale(1.0 / len);
+        let b = target.mul_scale(1.0 / len_target);
+        let angle = acos(math::clamp(a.dot(&b), -1.0, 1.0));
+        if angle <= max_angle
+        {
+            return b.mul_scale(len);
+        }
+        a.slerp_unit(&b, max_angle / angle).mul_scale(len)
+    }
+
+    fn slerp_unit(&self, other: &Vec3, t: f32) -> Vec3
+    {
+        let cos_theta = math::clamp(self.dot(other), -1.0, 1.0);
+        if cos_theta > 1.0 - EPSILON
+        {
+            return self.lerp(other, t).normalize();
+        }
+        if cos_theta < -1.0 + EPSILON
+        {
+            // Antiparallel: any great circle works, so rotate through a perpendicular direction.
+            let ortho = self.any_orthonormal_pair().0;
+            return self.mul_scale(cos(t * PI)).add(&ortho.mul_scale(sin(t * PI)));
+        }
+        let theta = acos(cos_theta);
+        let sin_theta = sin(theta);
+        self.mul_scale(sin((1.0 - t) * theta) / sin_theta).add(&other.mul_scale(sin(t * theta) / sin_theta))
+    }
+
+    // Rotates `self` by `angle` radians around `axis` using Rodrigues' formula.
+    pub fn rotate_around_axis(&self, axis: &Vec3, angle: impl Into<Radians>) -> Vec3
+    {
+        let angle = angle.into().0;
+        let k = axis.normalize();
+        let cos_theta = cos(angle);
+        let sin_theta = sin(angle);
+        self.mul_scale(cos_theta)
+            .add(&k.cross(self).mul_scale(sin_theta))
+            .add(&k.mul_scale(k.dot(self) * (1.0 - cos_theta)))
+    }
+
+    // Returns `(tangent, bitangent, normal)` for a right-handed orthonormal basis whose
+    // normal is `self` normalized. Branchless (Frisvad, revised by Duff et al.).
+    #[inline(always)]
+    pub fn orthonormal_basis(&self) -> (Vec3, Vec3, Vec3)
+    {
+        let normal = self.normalize();
+        let (tangent, bitangent) = normal.any_orthonormal_pair();
+        (tangent, bitangent, normal)
+    }
+
+    // Returns two unit vectors that together with `self` (assumed unit length) form a
+    // right-handed orthonormal basis. Uses the branchless construction of Duff et al. (2017).
+    #[inline(always)]
+    pub fn any_orthonormal_pair(&self) -> (Vec3, Vec3)
+    {
+        let sign = 1.0f32.copysign(self.z);
+        let a = -1.0 / (sign + self.z);
+        let b = self.x * self.y * a;
+        (
+            Vec3::new(1.0 + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
+
+    pub fn dot_batch(a: &[Vec3], b: &[Vec3], out: &mut [f32]) -> Result<(), VectoriaError>
+    {
+        check_len(a.len(), b.len())?;
+        check_len(a.len(), out.len())?;
+
+        let mut a_chunks = a.chunks_exact(4);
+        let mut b_chunks = b.chunks_exact(4);
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for ((a4, b4), out4) in (&mut a_chunks).zip(&mut b_chunks).zip(&mut out_chunks)
+        {
+            dot4_vec3(a4, b4, out4);
+        }
+        for ((a, b), out) in a_chunks.remainder().iter().zip(b_chunks.remainder()).zip(out_chunks.into_remainder())
+        {
+            *out = a.dot(b);
+        }
+        Ok(())
+    }
+
+    pub fn normalize_batch(vectors: &mut [Vec3])
+    {
+        let mut chunks = vectors.chunks_exact_mut(4);
+        for v4 in &mut chunks
+        {
+            normalize4_vec3(v4);
+        }
+        for v in chunks.into_remainder()
+        {
+            *v = v.normalize();
+        }
+    }
+}
+
+impl Vec4
+{
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const f32
+    {
+        &self.x as *const f32
+    }
+
+    #[inline(always)]
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Vec4
+    {
+        Vec4 { x, y, z, w }
+    }
+
+    #[inline(always)]
+    pub fn with_x(&self, x: f32) -> Vec4
+    {
+        Vec4 { x, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn with_y(&self, y: f32) -> Vec4
+    {
+        Vec4 { y, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn with_z(&self, z: f32) -> Vec4
+    {
+        Vec4 { z, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn with_w(&self, w: f32) -> Vec4
+    {
+        Vec4 { w, ..*self }
+    }
+
+    #[inline(always)]
+    pub fn truncate(&self) -> Vec3
+    {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline(always)]
+    pub fn add(&self, other: &Vec4) -> Vec4
+    {
+        Vec4
+        {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+
+    #[inline(always)]
+    pub fn sub(&self, other: &Vec4) -> Vec4
+    {
+        Vec4
+        {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+
+    #[inline(always)]
+    pub fn mul_scale(&self, scale: f32) -> Vec4
+    {
+        Vec4
+        {
+            x: self.x * scale,
+            y: self.y * scale,
+            z: self.z * scale,
+            w: self.w * scale,
+        }
+    }
+
+    #[inline(always)]
+    pub fn dot(&self, other: &Vec4) -> f32
+    {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline(always)]
+    pub fn dot_simd(&self, other: &Vec4) -> f32
+    {
+        unsafe {
+            let a = _mm_loadu_ps(self.as_ptr());
+            let b = _mm_loadu_ps(other.as_ptr());
+            let product = _mm_mul_ps(a, b);
+            let sum1 = _mm_hadd_ps(product, product);
+            let sum2 = _mm_hadd_ps(sum1, sum1);
+            _mm_cvtss_f32(sum2)
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    #[inline(always)]
+    pub fn dot_simd(&self, other: &Vec4) -> f32
+    {
+        unsafe {
+            let a = vld1q_f32(self.as_ptr());
+            let b = vld1q_f32(other.as_ptr());
+            let product = vmulq_f32(a, b);
+            let sum1 = vaddq_f32(product, vextq_f32::<2>(product, product));
+            let sum2 = vaddq_f32(sum1, vextq_f32::<1>(sum1, sum1));
+            vgetq_lane_f32::<0>(sum2)
+        }
+    }
+
+    #[inline(always)]
+    pub fn length(&self) -> f32
+    {
+        sqrt(self.length_squared())
+    }
+
+    #[inline(always)]
+    pub fn length_squared(&self) -> f32
+    {
+        self.dot(self)
+    }
+
+    #[inline(always)]
+    pub fn is_normalized(&self) -> bool
+    {
+        math::approx_equal(self.length_squared(), 1.0, 1e-4)
+    }
+
+    // Returns `None` when the vector is zero, too small to normalize or not finite.
+    #[inline(always)]
+    pub fn try_normalize(&self) -> Option<Vec4>
+    {
+        let inv = 1.0 / self.length();
+        if inv.is_finite() && inv > 0.0
+        {
+            Some(self.mul_scale(inv))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    #[inline(always)]
+    pub fn normalize_or(&self, fallback: Vec4) -> Vec4
+    {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline(always)]
+    pub fn unit(&self) -> Vec4
+    {
+        let len = self.length();
+        if len > 0.0
         {
             self.mul_scale(1.0 / len)
         }
@@ -338,19 +1113,28 @@ impl Vec4
     #[inline(always)]
     pub fn distance(&self, other: &Vec4) -> f32
     {
-        ((self.x - other.x).powi(2) + (self.y - other.y).powi(2) + (self.z - other.z).powi(2) + (self.w - other.w).powi(2)).sqrt()
+        self.sub(other).length()
     }
 
     #[inline(always)]
     pub fn angle(&self, other: &Vec4) -> f32
     {
-        (self.dot(other) / (self.length() * other.length())).acos()
+        let denom = self.length() * other.length();
+        if denom <= 0.0
+        {
+            return 0.0;
+        }
+        acos(math::clamp(self.dot(other) / denom, -1.0, 1.0))
     }
 
     pub fn project(&self, other: &Vec4) -> Vec4
     {
-        let scale = self.dot(other) / other.dot(other);
-        other.mul_scale(scale)
+        let len_sq = other.length_squared();
+        if len_sq <= 0.0
+        {
+            return other.mul_scale(0.0);
+        }
+        other.mul_scale(self.dot(other) / len_sq)
     }
 
     pub fn reflect(&self, normal: &Vec4) -> Vec4
@@ -364,4 +1148,527 @@ impl Vec4
             w: self.w - 2.0 * dot * normal.w,
         }
     }
-}
\ No newline at end of file
+
+    #[inline(always)]
+    fn map(&self, f: impl Fn(f32) -> f32) -> Vec4
+    {
+        Vec4
+        {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+            w: f(self.w),
+        }
+    }
+
+    #[inline(always)]
+    fn zip_map(&self, other: &Vec4, f: impl Fn(f32, f32) -> f32) -> Vec4
+    {
+        Vec4
+        {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+            z: f(self.z, other.z),
+            w: f(self.w, other.w),
+        }
+    }
+
+    #[inline(always)]
+    pub fn min(&self, other: &Vec4) -> Vec4
+    {
+        self.zip_map(other, math::min)
+    }
+
+    #[inline(always)]
+    pub fn max(&self, other: &Vec4) -> Vec4
+    {
+        self.zip_map(other, math::max)
+    }
+
+    #[inline(always)]
+    pub fn clamp(&self, min: &Vec4, max: &Vec4) -> Vec4
+    {
+        self.max(min).min(max)
+    }
+
+    #[inline(always)]
+    pub fn abs(&self) -> Vec4
+    {
+        self.map(math::abs)
+    }
+
+    #[inline(always)]
+    pub fn signum(&self) -> Vec4
+    {
+        self.map(math::signum)
+    }
+
+    #[inline(always)]
+    pub fn floor(&self) -> Vec4
+    {
+        self.map(math::floor)
+    }
+
+    #[inline(always)]
+    pub fn ceil(&self) -> Vec4
+    {
+        self.map(math::ceil)
+    }
+
+    #[inline(always)]
+    pub fn round(&self) -> Vec4
+    {
+        self.map(math::round)
+    }
+
+    #[inline(always)]
+    pub fn fract(&self) -> Vec4
+    {
+        self.map(math::fract)
+    }
+
+    #[inline(always)]
+    pub fn recip(&self) -> Vec4
+    {
+        self.map(|v| 1.0 / v)
+    }
+
+    // Computes `self * a + b` per component with a single rounding.
+    #[inline(always)]
+    pub fn mul_add(&self, a: &Vec4, b: &Vec4) -> Vec4
+    {
+        Vec4
+        {
+            x: math::mul_add(self.x, a.x, b.x),
+            y: math::mul_add(self.y, a.y, b.y),
+            z: math::mul_add(self.z, a.z, b.z),
+            w: math::mul_add(self.w, a.w, b.w),
+        }
+    }
+
+    #[inline(always)]
+    pub fn powf(&self, n: f32) -> Vec4
+    {
+        self.map(|v| math::powf(v, n))
+    }
+
+    #[inline(always)]
+    pub fn exp(&self) -> Vec4
+    {
+        self.map(math::exp)
+    }
+
+    #[inline(always)]
+    pub fn lerp(&self, other: &Vec4, t: f32) -> Vec4
+    {
+        self.zip_map(other, |a, b| math::lerp(a, b, t))
+    }
+
+    // Returns 0.0 for components below `edge` and 1.0 otherwise, like GLSL `step(edge, self)`.
+    #[inline(always)]
+    pub fn step(&self, edge: &Vec4) -> Vec4
+    {
+        edge.zip_map(self, math::step)
+    }
+
+    #[inline(always)]
+    pub fn smoothstep(&self, edge0: &Vec4, edge1: &Vec4) -> Vec4
+    {
+        Vec4
+        {
+            x: math::smoothstep(edge0.x, edge1.x, self.x),
+            y: math::smoothstep(edge0.y, edge1.y, self.y),
+            z: math::smoothstep(edge0.z, edge1.z, self.z),
+            w: math::smoothstep(edge0.w, edge1.w, self.w),
+        }
+    }
+
+    #[inline(always)]
+    pub fn min_element(&self) -> f32
+    {
+        math::min(math::min(math::min(self.x, self.y), self.z), self.w)
+    }
+
+    #[inline(always)]
+    pub fn max_element(&self) -> f32
+    {
+        math::max(math::max(math::max(self.x, self.y), self.z), self.w)
+    }
+
+    #[inline(always)]
+    pub fn sum(&self) -> f32
+    {
+        self.x + self.y + self.z + self.w
+    }
+
+    #[inline(always)]
+    pub fn product(&self) -> f32
+    {
+        self.x * self.y * self.z * self.w
+    }
+
+    #[inline(always)]
+    pub fn is_finite(&self) -> bool
+    {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    #[inline(always)]
+    pub fn is_nan(&self) -> bool
+    {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    // Spherically interpolates between the directions of `self` and `other` while the
+    // length is interpolated linearly. Falls back to `lerp` when either vector is zero.
+    pub fn slerp(&self, other: &Vec4, t: f32) -> Vec4
+    {
+        let len_a = self.length();
+        let len_b = other.length();
+        if len_a <= 0.0 || len_b <= 0.0
+        {
+            return self.lerp(other, t);
+        }
+        let a = self.mul_scale(1.0 / len_a);
+        let b = other.mul_scale(1.0 / len_b);
+        a.slerp_unit(&b, t).mul_scale(math::lerp(len_a, len_b, t))
+    }
+
+    #[inline(always)]
+    pub fn nlerp(&self, other: &Vec4, t: f32) -> Vec4
+    {
+        self.lerp(other, t).unit()
+    }
+
+    pub fn move_towards(&self, target: &Vec4, max_distance: f32) -> Vec4
+    {
+        let delta = target.sub(self);
+        let distance = delta.length();
+        if distance <= max_distance || distance <= 0.0
+        {
+            *target
+        }
+        else
+        {
+            self.add(&delta.mul_scale(max_distance / distance))
+        }
+    }
+
+    // Turns `self` towards the direction of `target` by at most `max_angle`,
+    // keeping the length of `self`.
+    pub fn rotate_towards(&self, target: &Vec4, max_angle: impl Into<Radians>) -> Vec4
+    {
+        let max_angle = max_angle.into().0;
+        let len = self.length();
+        let len_target = target.length();
+        if len <= 0.0 || len_target <= 0.0
+        {
+            return *self;
+        }
+        let a = self.mul_scale(1.0 / len);
+        let b = target.mul_scale(1.0 / len_target);
+        let angle = acos(math::clamp(a.dot(&b), -1.0, 1.0));
+        if angle <= max_angle
+        {
+            return b.mul_scale(len);
+        }
+        a.slerp_unit(&b, max_angle / angle).mul_scale(len)
+    }
+
+    fn slerp_unit(&self, other: &Vec4, t: f32) -> Vec4
+    {
+        let cos_theta = math::clamp(self.dot(other), -1.0, 1.0);
+        if cos_theta > 1.0 - EPSILON
+        {
+            return self.lerp(other, t).unit();
+        }
+        if cos_theta < -1.0 + EPSILON
+        {
+            // Antiparallel: any great circle works, so rotate through a perpendicular direction.
+            let ortho = self.any_orthogonal();
+            return self.mul_scale(cos(t * PI)).add(&ortho.mul_scale(sin(t * PI)));
+        }
+        let theta = acos(cos_theta);
+        let sin_theta = sin(theta);
+        self.mul_scale(sin((1.0 - t) * theta) / sin_theta).add(&other.mul_scale(sin(t * theta) / sin_theta))
+    }
+
+    // A unit vector orthogonal to `self` (assumed unit length), built by removing the
+    // `self` component from the basis axis it is least aligned with.
+    fn any_orthogonal(&self) -> Vec4
+    {
+        let abs = self.abs();
+        let axis = if abs.x <= abs.y && abs.x <= abs.z && abs.x <= abs.w
+        {
+            Vec4::new(1.0, 0.0, 0.0, 0.0)
+        }
+        else if abs.y <= abs.z && abs.y <= abs.w
+        {
+            Vec4::new(0.0, 1.0, 0.0, 0.0)
+        }
+        else if abs.z <= abs.w
+        {
+            Vec4::new(0.0, 0.0, 1.0, 0.0)
+        }
+        else
+        {
+            Vec4::new(0.0, 0.0, 0.0, 1.0)
+        };
+        axis.sub(&self.mul_scale(self.dot(&axis))).unit()
+    }
+
+    pub fn dot_batch(a: &[Vec4], b: &[Vec4], out: &mut [f32]) -> Result<(), VectoriaError>
+    {
+        check_len(a.len(), b.len())?;
+        check_len(a.len(), out.len())?;
+
+        let mut a_chunks = a.chunks_exact(4);
+        let mut b_chunks = b.chunks_exact(4);
+        let mut out_chunks = out.chunks_exact_mut(4);
+        for ((a4, b4), out4) in (&mut a_chunks).zip(&mut b_chunks).zip(&mut out_chunks)
+        {
+            dot4_vec4(a4, b4, out4);
+        }
+        for ((a, b), out) in a_chunks.remainder().iter().zip(b_chunks.remainder()).zip(out_chunks.into_remainder())
+        {
+            *out = a.dot(b);
+        }
+        Ok(())
+    }
+
+    pub fn normalize_batch(vectors: &mut [Vec4])
+    {
+        let mut chunks = vectors.chunks_exact_mut(4);
+        for v4 in &mut chunks
+        {
+            normalize4_vec4(v4);
+        }
+        for v in chunks.into_remainder()
+        {
+            *v = v.unit();
+        }
+    }
+}
+
+// Four-wide kernels behind the batch functions. Each takes chunks of exactly four
+// elements; the remainders are handled by the scalar methods.
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn load_vec3x4(v: &[Vec3]) -> (__m128, __m128, __m128)
+{
+    (
+        _mm_set_ps(v[3].x, v[2].x, v[1].x, v[0].x),
+        _mm_set_ps(v[3].y, v[2].y, v[1].y, v[0].y),
+        _mm_set_ps(v[3].z, v[2].z, v[1].z, v[0].z),
+    )
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn load_vec4x4(v: &[Vec4]) -> (__m128, __m128, __m128, __m128)
+{
+    let mut x = _mm_loadu_ps(v.as_ptr() as *const f32);
+    let mut y = _mm_loadu_ps(v.as_ptr().add(1) as *const f32);
+    let mut z = _mm_loadu_ps(v.as_ptr().add(2) as *const f32);
+    let mut w = _mm_loadu_ps(v.as_ptr().add(3) as *const f32);
+    _MM_TRANSPOSE4_PS(&mut x, &mut y, &mut z, &mut w);
+    (x, y, z, w)
+}
+
+// Scales each lane by 1 / len, leaving lanes with zero length untouched.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn scale_nonzero(value: __m128, len: __m128) -> __m128
+{
+    let mask = _mm_cmpgt_ps(len, _mm_setzero_ps());
+    let scaled = _mm_mul_ps(value, _mm_div_ps(_mm_set1_ps(1.0), len));
+    _mm_or_ps(_mm_and_ps(mask, scaled), _mm_andnot_ps(mask, value))
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn dot4_vec3(a: &[Vec3], b: &[Vec3], out: &mut [f32])
+{
+    unsafe {
+        let (ax, ay, az) = load_vec3x4(a);
+        let (bx, by, bz) = load_vec3x4(b);
+        let dot = _mm_add_ps(_mm_add_ps(_mm_mul_ps(ax, bx), _mm_mul_ps(ay, by)), _mm_mul_ps(az, bz));
+        _mm_storeu_ps(out.as_mut_ptr(), dot);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn normalize4_vec3(v: &mut [Vec3])
+{
+    unsafe {
+        let (x, y, z) = load_vec3x4(v);
+        let len_sq = _mm_add_ps(_mm_add_ps(_mm_mul_ps(x, x), _mm_mul_ps(y, y)), _mm_mul_ps(z, z));
+        let len = _mm_sqrt_ps(len_sq);
+        let mut xs = [0.0; 4];
+        let mut ys = [0.0; 4];
+        let mut zs = [0.0; 4];
+        _mm_storeu_ps(xs.as_mut_ptr(), scale_nonzero(x, len));
+        _mm_storeu_ps(ys.as_mut_ptr(), scale_nonzero(y, len));
+        _mm_storeu_ps(zs.as_mut_ptr(), scale_nonzero(z, len));
+        for i in 0..4
+        {
+            v[i] = Vec3::new(xs[i], ys[i], zs[i]);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn dot4_vec4(a: &[Vec4], b: &[Vec4], out: &mut [f32])
+{
+    unsafe {
+        let (ax, ay, az, aw) = load_vec4x4(a);
+        let (bx, by, bz, bw) = load_vec4x4(b);
+        let dot = _mm_add_ps(
+            _mm_add_ps(_mm_mul_ps(ax, bx), _mm_mul_ps(ay, by)),
+            _mm_add_ps(_mm_mul_ps(az, bz), _mm_mul_ps(aw, bw)),
+        );
+        _mm_storeu_ps(out.as_mut_ptr(), dot);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn normalize4_vec4(v: &mut [Vec4])
+{
+    unsafe {
+        let (x, y, z, w) = load_vec4x4(v);
+        let len_sq = _mm_add_ps(
+            _mm_add_ps(_mm_mul_ps(x, x), _mm_mul_ps(y, y)),
+            _mm_add_ps(_mm_mul_ps(z, z), _mm_mul_ps(w, w)),
+        );
+        let len = _mm_sqrt_ps(len_sq);
+        let mut r0 = scale_nonzero(x, len);
+        let mut r1 = scale_nonzero(y, len);
+        let mut r2 = scale_nonzero(z, len);
+        let mut r3 = scale_nonzero(w, len);
+        _MM_TRANSPOSE4_PS(&mut r0, &mut r1, &mut r2, &mut r3);
+        _mm_storeu_ps(v.as_mut_ptr().add(0) as *mut f32, r0);
+        _mm_storeu_ps(v.as_mut_ptr().add(1) as *mut f32, r1);
+        _mm_storeu_ps(v.as_mut_ptr().add(2) as *mut f32, r2);
+        _mm_storeu_ps(v.as_mut_ptr().add(3) as *mut f32, r3);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+unsafe fn scale_nonzero(value: float32x4_t, len: float32x4_t) -> float32x4_t
+{
+    let mask = vcgtq_f32(len, vdupq_n_f32(0.0));
+    let scaled = vmulq_f32(value, vdivq_f32(vdupq_n_f32(1.0), len));
+    vbslq_f32(mask, scaled, value)
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn dot4_vec3(a: &[Vec3], b: &[Vec3], out: &mut [f32])
+{
+    unsafe {
+        let va = vld3q_f32(a.as_ptr() as *const f32);
+        let vb = vld3q_f32(b.as_ptr() as *const f32);
+        let dot = vaddq_f32(vaddq_f32(vmulq_f32(va.0, vb.0), vmulq_f32(va.1, vb.1)), vmulq_f32(va.2, vb.2));
+        vst1q_f32(out.as_mut_ptr(), dot);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn normalize4_vec3(v: &mut [Vec3])
+{
+    unsafe {
+        let ptr = v.as_mut_ptr() as *mut f32;
+        let xyz = vld3q_f32(ptr);
+        let len_sq = vaddq_f32(vaddq_f32(vmulq_f32(xyz.0, xyz.0), vmulq_f32(xyz.1, xyz.1)), vmulq_f32(xyz.2, xyz.2));
+        let len = vsqrtq_f32(len_sq);
+        let result = float32x4x3_t(
+            scale_nonzero(xyz.0, len),
+            scale_nonzero(xyz.1, len),
+            scale_nonzero(xyz.2, len),
+        );
+        vst3q_f32(ptr, result);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn dot4_vec4(a: &[Vec4], b: &[Vec4], out: &mut [f32])
+{
+    unsafe {
+        let va = vld4q_f32(a.as_ptr() as *const f32);
+        let vb = vld4q_f32(b.as_ptr() as *const f32);
+        let dot = vaddq_f32(
+            vaddq_f32(vmulq_f32(va.0, vb.0), vmulq_f32(va.1, vb.1)),
+            vaddq_f32(vmulq_f32(va.2, vb.2), vmulq_f32(va.3, vb.3)),
+        );
+        vst1q_f32(out.as_mut_ptr(), dot);
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn normalize4_vec4(v: &mut [Vec4])
+{
+    unsafe {
+        let ptr = v.as_mut_ptr() as *mut f32;
+        let xyzw = vld4q_f32(ptr);
+        let len_sq = vaddq_f32(
+            vaddq_f32(vmulq_f32(xyzw.0, xyzw.0), vmulq_f32(xyzw.1, xyzw.1)),
+            vaddq_f32(vmulq_f32(xyzw.2, xyzw.2), vmulq_f32(xyzw.3, xyzw.3)),
+        );
+        let len = vsqrtq_f32(len_sq);
+        let result = float32x4x4_t(
+            scale_nonzero(xyzw.0, len),
+            scale_nonzero(xyzw.1, len),
+            scale_nonzero(xyzw.2, len),
+            scale_nonzero(xyzw.3, len),
+        );
+        vst4q_f32(ptr, result);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn dot4_vec3(a: &[Vec3], b: &[Vec3], out: &mut [f32])
+{
+    for i in 0..4
+    {
+        out[i] = a[i].dot(&b[i]);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn normalize4_vec3(v: &mut [Vec3])
+{
+    for item in v.iter_mut()
+    {
+        *item = item.normalize();
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn dot4_vec4(a: &[Vec4], b: &[Vec4], out: &mut [f32])
+{
+    for i in 0..4
+    {
+        out[i] = a[i].dot(&b[i]);
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn normalize4_vec4(v: &mut [Vec4])
+{
+    for item in v.iter_mut()
+    {
+        *item = item.unit();
+    }
+}
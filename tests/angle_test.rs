@@ -0,0 +1,98 @@
+use vectoria::angle::*;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use vectoria::math::PI;
+    use vectoria::matrix::{ Mat3, Mat4 };
+    use vectoria::quat::Quat;
+    use vectoria::vector::{ Vec2, Vec3 };
+
+    fn close(a: f32, b: f32) -> bool
+    {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn test_conversions()
+    {
+        assert!(close(Degrees(180.0).to_radians().0, PI));
+        assert!(close(Radians(PI * 0.5).to_degrees().0, 90.0));
+        let r: Radians = Degrees(90.0).into();
+        assert!(close(r.0, PI * 0.5));
+        let d: Degrees = Radians::PI.into();
+        assert!(close(d.0, 180.0));
+    }
+
+    #[test]
+    fn test_arithmetic()
+    {
+        let a = Radians(1.0);
+        let b = Radians(0.25);
+        assert_eq!(a.add(&b), Radians(1.25));
+        assert_eq!(a.sub(&b), Radians(0.75));
+        assert_eq!(a.mul_scale(2.0), Radians(2.0));
+        assert_eq!(a.div_scale(4.0), Radians(0.25));
+        assert_eq!(a.neg(), Radians(-1.0));
+        assert!(Degrees(10.0) < Degrees(20.0));
+        assert!(close(Degrees(30.0).sin(), 0.5));
+    }
+
+    #[test]
+    fn test_wrapping()
+    {
+        assert!(close(Radians(3.0 * PI).wrap_signed().0, PI));
+        assert!(close(Radians(-PI).wrap_signed().0, PI));
+        assert!(close(Radians(-0.5).wrap_positive().0, 2.0 * PI - 0.5));
+        assert!(close(Radians(5.0 * PI).wrap_positive().0, PI));
+        let tiny = Radians(-1e-9).wrap_positive().0;
+        assert!((0.0..2.0 * PI).contains(&tiny));
+        assert!(close(Degrees(540.0).wrap_signed().0, 180.0));
+        assert!(close(Degrees(-180.0).wrap_signed().0, 180.0));
+        assert!(close(Degrees(-90.0).wrap_positive().0, 270.0));
+        assert_eq!(Degrees(360.0).wrap_positive().0, 0.0);
+    }
+
+    #[test]
+    fn test_shortest_difference_and_lerp()
+    {
+        let diff = Degrees(350.0).shortest_difference(&Degrees(10.0));
+        assert!(close(diff.0, 20.0));
+        let diff = Degrees(10.0).shortest_difference(&Degrees(350.0));
+        assert!(close(diff.0, -20.0));
+
+        let mid = Degrees(170.0).lerp_short(&Degrees(-170.0), 0.5);
+        assert!(close(mid.0, 180.0));
+        let quarter = Radians(-0.1).lerp_short(&Radians(0.3), 0.25);
+        assert!(close(quarter.0, 0.0));
+    }
+
+    #[test]
+    fn test_rotation_constructors_accept_angles()
+    {
+        let from_float = Mat4::rotate_z(PI * 0.5);
+        let from_degrees = Mat4::rotate_z(Degrees(90.0));
+        let from_radians = Mat4::rotate_z(Radians(PI * 0.5));
+        assert_eq!(from_float, from_radians);
+        for i in 0..4
+        {
+            for j in 0..4
+            {
+                assert!(close(from_float.data[i][j], from_degrees.data[i][j]));
+            }
+        }
+
+        let m = Mat3::rotate_x(Degrees(90.0));
+        assert!(close(m.data[2][1], 1.0));
+
+        let v = Vec2::new(1.0, 0.0).rotate(Degrees(90.0));
+        assert!(close(v.x, 0.0) && close(v.y, 1.0));
+
+        let axis = Vec3::new(0.0, 0.0, 1.0);
+        let q = Quat::from_axis_angle(&axis, Degrees(90.0));
+        let r = q.rotate(&Vec3::new(1.0, 0.0, 0.0));
+        let w = Vec3::new(1.0, 0.0, 0.0).rotate_around_axis(&axis, Degrees(90.0));
+        assert!(close(r.y, 1.0) && close(w.y, 1.0));
+    }
+}
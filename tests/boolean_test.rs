@@ -0,0 +1,209 @@
+mod common;
+
+use vectoria::geometry::boolean::*;
+use vectoria::geometry::{ Point, Polygon };
+use common::Rng;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn rect(x0: f64, y0: f64, x1: f64, y1: f64) -> Polygon
+    {
+        Polygon::new(vec![Point::new(x0, y0), Point::new(x1, y0), Point::new(x1, y1), Point::new(x0, y1)])
+    }
+
+    fn total_area(polygons: &[Polygon]) -> f64
+    {
+        polygons.iter().map(|p| p.area()).sum()
+    }
+
+    fn assert_area(polygons: &[Polygon], expected: f64)
+    {
+        let area = total_area(polygons);
+        assert!((area - expected).abs() < 1e-9, "area {} != {} for {:?}", area, expected, polygons);
+    }
+
+    #[test]
+    fn test_overlapping_squares()
+    {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let b = rect(1.0, 1.0, 3.0, 3.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 8);
+        assert_area(&union, 7.0);
+        assert_area(&a.intersection(&b), 1.0);
+        assert_area(&a.difference(&b), 3.0);
+        assert_area(&b.difference(&a), 3.0);
+        let xor = a.xor(&b);
+        assert_area(&xor, 6.0);
+        assert_eq!(xor.len(), 2);
+
+        let intersection = a.intersection(&b);
+        assert!(intersection[0].contains(&Point::new(1.5, 1.5)));
+        assert!(!intersection[0].contains(&Point::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn test_identical_polygons()
+    {
+        let a = rect(0.0, 0.0, 2.0, 2.0);
+        let union = a.union(&a);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 4);
+        assert_area(&union, 4.0);
+        assert_area(&a.intersection(&a), 4.0);
+        assert!(a.difference(&a).is_empty());
+        assert!(a.xor(&a).is_empty());
+    }
+
+    #[test]
+    fn test_touching_edges()
+    {
+        // Sharing a full edge: the union merges into one rectangle without the seam.
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let b = rect(1.0, 0.0, 2.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 4);
+        assert_area(&union, 2.0);
+        assert!(a.intersection(&b).is_empty());
+        assert_area(&a.difference(&b), 1.0);
+        assert_area(&a.xor(&b), 2.0);
+
+        // Sharing part of an edge.
+        let c = rect(1.0, 0.5, 2.0, 3.0);
+        let union = a.union(&c);
+        assert_eq!(union.len(), 1);
+        assert_area(&union, 3.5);
+        assert!(a.intersection(&c).is_empty());
+
+        // Touching at a single corner.
+        let d = rect(1.0, 1.0, 2.0, 2.0);
+        assert_area(&a.union(&d), 2.0);
+        assert!(a.intersection(&d).is_empty());
+        assert_area(&a.difference(&d), 1.0);
+    }
+
+    #[test]
+    fn test_collinear_edges()
+    {
+        // Top and bottom edges overlap collinearly.
+        let a = rect(0.0, 0.0, 2.0, 1.0);
+        let b = rect(1.0, 0.0, 3.0, 1.0);
+        let union = a.union(&b);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].exterior.len(), 4);
+        assert_area(&union, 3.0);
+        let intersection = a.intersection(&b);
+        assert_eq!(intersection.len(), 1);
+        assert_area(&intersection, 1.0);
+        assert_area(&a.difference(&b), 1.0);
+        assert_area(&a.xor(&b), 2.0);
+
+        // A vertex of one polygon lying on an edge of the other.
+        let triangle = Polygon::new(vec![Point::new(1.0, 1.0), Point::new(3.0, 0.0), Point::new(3.0, 2.0)]);
+        assert_area(&a.union(&triangle), 2.0 + 2.0 - 0.25);
+        assert_area(&a.intersection(&triangle), 0.25);
+    }
+
+    #[test]
+    fn test_holes()
+    {
+        let outer = rect(0.0, 0.0, 4.0, 4.0);
+        let inner = rect(1.0, 1.0, 3.0, 3.0);
+        let framed = outer.difference(&inner);
+        assert_eq!(framed.len(), 1);
+        assert_eq!(framed[0].holes.len(), 1);
+        assert_area(&framed, 12.0);
+        assert!(!framed[0].contains(&Point::new(2.0, 2.0)));
+        assert!(framed[0].contains(&Point::new(0.5, 2.0)));
+
+        // Filling the hole back in.
+        let filled = framed[0].union(&inner);
+        assert_eq!(filled.len(), 1);
+        assert!(filled[0].holes.is_empty());
+        assert_area(&filled, 16.0);
+
+        // An island inside the hole stays separate.
+        let island = rect(1.5, 1.5, 2.5, 2.5);
+        let with_island = framed[0].union(&island);
+        assert_eq!(with_island.len(), 2);
+        assert_area(&with_island, 13.0);
+
+        // Cutting across the frame.
+        let bar = rect(-1.0, 1.5, 5.0, 2.5);
+        assert_area(&framed[0].intersection(&bar), 2.0);
+        assert_area(&framed[0].difference(&bar), 10.0);
+        assert_area(&framed[0].union(&bar), 12.0 + 6.0 - 2.0);
+        assert_area(&framed[0].xor(&bar), 12.0 + 6.0 - 4.0);
+    }
+
+    #[test]
+    fn test_degenerate_inputs()
+    {
+        let a = rect(0.0, 0.0, 1.0, 1.0);
+        let flat = Polygon::new(vec![Point::new(0.0, 0.5), Point::new(2.0, 0.5), Point::new(1.0, 0.5)]);
+        assert_area(&a.union(&flat), 1.0);
+        assert_area(&a.difference(&flat), 1.0);
+        assert!(a.intersection(&flat).is_empty());
+
+        let far = rect(5.0, 5.0, 6.0, 6.0);
+        assert_eq!(a.union(&far).len(), 2);
+        assert!(a.intersection(&far).is_empty());
+        assert_eq!(boolean_op(&[], std::slice::from_ref(&a), BooleanOp::Union).len(), 1);
+        // Inputs passed through without the sweep are still cleaned.
+        let messy = Polygon::with_holes(
+            vec![Point::new(5.0, 5.0), Point::new(5.5, 5.0), Point::new(6.0, 5.0), Point::new(6.0, 5.0), Point::new(6.0, 6.0), Point::new(5.0, 6.0)],
+            vec![vec![Point::new(5.2, 5.2), Point::new(5.4, 5.2), Point::new(5.3, 5.2)]],
+        );
+        for result in [a.union(&messy), boolean_op(&[], std::slice::from_ref(&messy), BooleanOp::Union)]
+        {
+            let cleaned = result.iter().find(|p| p.exterior.iter().all(|q| q.x >= 5.0)).unwrap();
+            assert_eq!(cleaned.exterior.len(), 4);
+            assert!(cleaned.holes.is_empty());
+        }
+        assert!(boolean_op(std::slice::from_ref(&a), &[], BooleanOp::Intersection).is_empty());
+
+        // Clockwise input gives normalized output.
+        let mut clockwise = rect(0.5, 0.5, 1.5, 1.5);
+        clockwise.exterior.reverse();
+        let union = a.union(&clockwise);
+        assert_area(&union, 1.75);
+        assert!(vectoria::geometry::polygon::ring_signed_area(&union[0].exterior) > 0.0);
+    }
+
+    #[test]
+    fn test_random_area_identities()
+    {
+        let mut rng = Rng(0x9e3779b97f4a7c15);
+        for _ in 0..200
+        {
+            let shape = |rng: &mut Rng| {
+                let (cx, cy) = (rng.next() * 4.0, rng.next() * 4.0);
+                let sides = 3 + (rng.next() * 5.0) as usize;
+                let radius = 0.5 + rng.next() * 2.0;
+                let phase = rng.next() * std::f64::consts::TAU;
+                Polygon::new(
+                    (0..sides)
+                        .map(|i| {
+                            let angle = phase + i as f64 * std::f64::consts::TAU / sides as f64;
+                            Point::new(cx + radius * angle.cos(), cy + radius * angle.sin())
+                        })
+                        .collect(),
+                )
+            };
+            let a = shape(&mut rng);
+            let b = shape(&mut rng);
+            let union = total_area(&a.union(&b));
+            let intersection = total_area(&a.intersection(&b));
+            let difference = total_area(&a.difference(&b));
+            let xor = total_area(&a.xor(&b));
+            assert!((union - (a.area() + b.area() - intersection)).abs() < 1e-7, "{:?} {:?}", a, b);
+            assert!((difference - (a.area() - intersection)).abs() < 1e-7, "{:?} {:?}", a, b);
+            assert!((xor - (union - intersection)).abs() < 1e-7, "{:?} {:?}", a, b);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+// Helpers shared by the integration tests; each test crate uses only some of them.
+#![allow(dead_code)]
+
+use vectoria::geometry::Point;
+use vectoria::vector::Vec3;
+
+// Xorshift, so the randomized checks are reproducible.
+pub struct Rng(pub u64);
+
+impl Rng
+{
+    pub fn next(&mut self) -> f64
+    {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// The counter-clockwise square with its minimum corner at `(x, y)`.
+pub fn square(x: f64, y: f64, size: f64) -> Vec<Point>
+{
+    vec![Point::new(x, y), Point::new(x + size, y), Point::new(x + size, y + size), Point::new(x, y + size)]
+}
+
+pub fn assert_vec3_close(a: &Vec3, b: &Vec3)
+{
+    assert!(a.sub(b).length() < 1e-5, "{:?} != {:?}", a, b);
+}
@@ -0,0 +1,138 @@
+use vectoria::coordinates::*;
+use vectoria::geometry::{ Point, Point3D };
+use vectoria::vector::{ Vec2, Vec3 };
+use vectoria::VectoriaError;
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::f64::consts::{ FRAC_PI_2, PI };
+
+    fn close(a: f64, b: f64) -> bool
+    {
+        (a - b).abs() < 1e-9
+    }
+
+    fn assert_identity3(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3])
+    {
+        for i in 0..3
+        {
+            for j in 0..3
+            {
+                let mut sum = 0.0;
+                for k in 0..3
+                {
+                    sum += a[i][k] * b[k][j];
+                }
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(close(sum, expected), "{:?} * {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polar_roundtrip()
+    {
+        let p = Point::new(-3.0, 4.0);
+        let polar = Polar::from_point(&p);
+        assert!(close(polar.radius, 5.0));
+        assert!(polar.angle > FRAC_PI_2 && polar.angle < PI);
+        let back = polar.to_point();
+        assert!(close(back.x, p.x) && close(back.y, p.y));
+
+        let v = Polar::from_vec2(&Vec2::new(0.0, 2.0));
+        assert!(close(v.angle, FRAC_PI_2));
+        let back = v.to_vec2();
+        assert!(back.x.abs() < 1e-6 && (back.y - 2.0).abs() < 1e-6);
+        assert!(close(Polar::from_cartesian(-1.0, 0.0).angle, PI));
+    }
+
+    #[test]
+    fn test_singular_points()
+    {
+        assert_eq!(Polar::from_cartesian(-0.0, -0.0), Polar::new(0.0, 0.0));
+        assert_eq!(Spherical::from_cartesian(0.0, -0.0, 0.0), Spherical::new(0.0, 0.0, 0.0));
+        let pole = Spherical::from_cartesian(0.0, 0.0, -2.0);
+        assert_eq!(pole.azimuth, 0.0);
+        assert!(close(pole.elevation, -FRAC_PI_2));
+        assert_eq!(Cylindrical::from_cartesian(-0.0, 0.0, 5.0), Cylindrical::new(0.0, 0.0, 5.0));
+        assert_eq!(Polar::new(0.0, 1.0).jacobian_from_cartesian(), Err(VectoriaError::Singular));
+        assert_eq!(pole.jacobian_from_cartesian(), Err(VectoriaError::Singular));
+        assert_eq!(Cylindrical::new(0.0, 0.3, 1.0).jacobian_from_cartesian(), Err(VectoriaError::Singular));
+    }
+
+    #[test]
+    fn test_spherical_roundtrip()
+    {
+        let points = [Point3D::new(1.0, 2.0, 3.0), Point3D::new(-4.0, 0.5, -1.0), Point3D::new(0.0, -3.0, 0.0)];
+        for p in points.iter()
+        {
+            let s = Spherical::from_point3d(p);
+            assert!(s.azimuth > -PI && s.azimuth <= PI);
+            assert!(s.elevation >= -FRAC_PI_2 && s.elevation <= FRAC_PI_2);
+            let back = s.to_point3d();
+            assert!(back.distance(p) < 1e-9);
+        }
+        let s = Spherical::from_vec3(&Vec3::new(1.0, 1.0, 0.0));
+        assert!(close(s.azimuth, PI / 4.0) && close(s.elevation, 0.0));
+        assert!(s.to_vec3().sub(&Vec3::new(1.0, 1.0, 0.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_cylindrical_roundtrip()
+    {
+        let p = Point3D::new(-1.0, -1.0, 7.5);
+        let c = Cylindrical::from_point3d(&p);
+        assert!(close(c.radius, 2.0f64.sqrt()));
+        assert!(close(c.azimuth, -3.0 * PI / 4.0));
+        assert!(close(c.height, 7.5));
+        assert!(c.to_point3d().distance(&p) < 1e-9);
+        assert!(Cylindrical::from_vec3(&Vec3::new(0.0, 2.0, 1.0)).to_vec3().sub(&Vec3::new(0.0, 2.0, 1.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_polar_jacobians()
+    {
+        let polar = Polar::new(2.0, 0.7);
+        let j = polar.jacobian_to_cartesian();
+        let h = 1e-6;
+        let (x0, y0) = polar.to_cartesian();
+        let (x1, y1) = Polar::new(2.0 + h, 0.7).to_cartesian();
+        let (x2, y2) = Polar::new(2.0, 0.7 + h).to_cartesian();
+        assert!(((x1 - x0) / h - j[0][0]).abs() < 1e-5 && ((y1 - y0) / h - j[1][0]).abs() < 1e-5);
+        assert!(((x2 - x0) / h - j[0][1]).abs() < 1e-5 && ((y2 - y0) / h - j[1][1]).abs() < 1e-5);
+        let inv = polar.jacobian_from_cartesian().unwrap();
+        assert!(close(j[0][0] * inv[0][0] + j[0][1] * inv[1][0], 1.0));
+        assert!(close(j[0][0] * inv[0][1] + j[0][1] * inv[1][1], 0.0));
+        assert!(close(j[1][0] * inv[0][0] + j[1][1] * inv[1][0], 0.0));
+        assert!(close(j[1][0] * inv[0][1] + j[1][1] * inv[1][1], 1.0));
+    }
+
+    #[test]
+    fn test_spherical_jacobians()
+    {
+        let s = Spherical::new(3.0, -2.0, 0.4);
+        let j = s.jacobian_to_cartesian();
+        let h = 1e-6;
+        let base = s.to_cartesian();
+        let shifted = [
+            Spherical::new(3.0 + h, -2.0, 0.4).to_cartesian(),
+            Spherical::new(3.0, -2.0 + h, 0.4).to_cartesian(),
+            Spherical::new(3.0, -2.0, 0.4 + h).to_cartesian(),
+        ];
+        for (col, p) in shifted.iter().enumerate()
+        {
+            assert!(((p.0 - base.0) / h - j[0][col]).abs() < 1e-5);
+            assert!(((p.1 - base.1) / h - j[1][col]).abs() < 1e-5);
+            assert!(((p.2 - base.2) / h - j[2][col]).abs() < 1e-5);
+        }
+        assert_identity3(&j, &s.jacobian_from_cartesian().unwrap());
+    }
+
+    #[test]
+    fn test_cylindrical_jacobians()
+    {
+        let c = Cylindrical::new(1.5, 2.2, -4.0);
+        assert_identity3(&c.jacobian_to_cartesian(), &c.jacobian_from_cartesian().unwrap());
+    }
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use vectoria::geometry::delaunay::*;
+use vectoria::geometry::{ Point, Rectangle };
+use common::Rng;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // Empty circumcircles, counter-clockwise triangles, and `2n - 2 - h` triangles for `n`
+    // vertices with `h` of them on the hull.
+    fn assert_delaunay(delaunay: &Delaunay)
+    {
+        let vertices = delaunay.vertices();
+        let triangles = delaunay.triangles();
+        for triangle in delaunay.to_triangles()
+        {
+            assert!(triangle.signed_area() > 0.0);
+            let circle = triangle.circumcircle().unwrap();
+            for v in vertices
+            {
+                assert!(circle.center.distance(v) >= circle.radius * (1.0 - 1e-9), "{:?} in {:?}", v, triangle);
+            }
+        }
+        let mut hull_edges = 0;
+        for t in &triangles
+        {
+            for i in 0..3
+            {
+                let (a, b) = (t[i], t[(i + 1) % 3]);
+                let twin = triangles.iter().filter(|u| (0..3).any(|j| u[j] == b && u[(j + 1) % 3] == a)).count();
+                assert!(twin <= 1);
+                hull_edges += 1 - twin;
+            }
+        }
+        assert_eq!(triangles.len(), 2 * vertices.len() - 2 - hull_edges);
+    }
+
+    #[test]
+    fn test_square_with_center()
+    {
+        let points = [Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 2.0), Point::new(1.0, 1.0)];
+        let delaunay = Delaunay::from_points(&points);
+        assert_eq!(delaunay.triangles().len(), 4);
+        assert_delaunay(&delaunay);
+        let mut neighbors = delaunay.neighbors(4);
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![0, 1, 2, 3]);
+        assert_eq!(delaunay.neighbors(0).len(), 3);
+    }
+
+    #[test]
+    fn test_random_points()
+    {
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        let points: Vec<Point> = (0..300).map(|_| Point::new(rng.next() * 10.0, rng.next() * 10.0)).collect();
+        let delaunay = Delaunay::from_points(&points);
+        assert_eq!(delaunay.vertices().len(), 300);
+        assert_delaunay(&delaunay);
+
+        // On an integer grid every cell is cocircular.
+        let grid: Vec<Point> = (0..64).map(|i| Point::new((i % 8) as f64, (i / 8) as f64)).collect();
+        let delaunay = Delaunay::from_points(&grid);
+        assert_eq!(delaunay.triangles().len(), 2 * 7 * 7);
+        assert_delaunay(&delaunay);
+    }
+
+    #[test]
+    fn test_incremental_insertion()
+    {
+        let mut delaunay = Delaunay::new();
+        // Collinear points only pile up until one leaves the line.
+        assert_eq!(delaunay.insert(Point::new(0.0, 0.0)), 0);
+        assert_eq!(delaunay.insert(Point::new(2.0, 0.0)), 1);
+        assert_eq!(delaunay.insert(Point::new(1.0, 0.0)), 2);
+        assert_eq!(delaunay.insert(Point::new(-1.0, 0.0)), 3);
+        assert_eq!(delaunay.insert(Point::new(2.0, 0.0)), 1);
+        assert!(delaunay.triangles().is_empty());
+        assert_eq!(delaunay.insert(Point::new(0.5, 1.0)), 4);
+        assert_eq!(delaunay.triangles().len(), 3);
+        assert_delaunay(&delaunay);
+
+        // Points outside the hull, on a hull edge, and repeated ones.
+        let mut rng = Rng(99);
+        for _ in 0..100
+        {
+            delaunay.insert(Point::new(rng.next() * 20.0 - 10.0, rng.next() * 20.0 - 10.0));
+        }
+        delaunay.insert(Point::new(-20.0, 0.0));
+        delaunay.insert(Point::new(20.0, 0.0));
+        delaunay.insert(Point::new(0.0, 0.0));
+        assert_eq!(delaunay.insert(Point::new(20.0, 0.0)), delaunay.vertices().len() - 1);
+        assert_eq!(delaunay.vertices().len(), 107);
+        assert_delaunay(&delaunay);
+    }
+
+    #[test]
+    fn test_locate_and_nearest()
+    {
+        let mut rng = Rng(7);
+        let points: Vec<Point> = (0..200).map(|_| Point::new(rng.next(), rng.next())).collect();
+        let delaunay = Delaunay::from_points(&points);
+        for _ in 0..200
+        {
+            let query = Point::new(rng.next() * 1.4 - 0.2, rng.next() * 1.4 - 0.2);
+            let expected = (0..points.len()).min_by(|&a, &b| points[a].distance(&query).total_cmp(&points[b].distance(&query)));
+            assert_eq!(delaunay.nearest(&query), expected);
+        }
+
+        let [a, b, c] = delaunay.locate(&Point::new(0.5, 0.5)).unwrap();
+        let triangle = vectoria::geometry::Triangle::new(points[a], points[b], points[c]);
+        assert!(triangle.contains(&Point::new(0.5, 0.5)));
+        assert_eq!(delaunay.locate(&Point::new(5.0, 5.0)), None);
+        assert_eq!(Delaunay::new().nearest(&Point::new(0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_voronoi()
+    {
+        let points = [Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 2.0), Point::new(1.0, 1.0)];
+        let delaunay = Delaunay::from_points(&points);
+        let bounds = Rectangle::new(Point::new(-1.0, -1.0), 4.0, 4.0);
+        let cells = delaunay.voronoi(&bounds);
+        assert_eq!(cells.len(), 5);
+        let total: f64 = cells.iter().flatten().map(|c| c.area()).sum();
+        assert!((total - 16.0).abs() < 1e-9);
+        // The center's cell is the diamond between the four bisectors.
+        assert!((cells[4].as_ref().unwrap().area() - 2.0).abs() < 1e-9);
+        for (cell, site) in cells.iter().zip(points.iter())
+        {
+            assert!(cell.as_ref().unwrap().contains(site));
+        }
+
+        // A site far outside the bounds has no cell there.
+        let mut far = points.to_vec();
+        far.push(Point::new(100.0, 1.0));
+        let cells = Delaunay::from_points(&far).voronoi(&bounds);
+        assert!(cells[5].is_none());
+
+        // Collinear sites split the bounds into strips.
+        let line = Delaunay::from_points(&[Point::new(0.0, 1.0), Point::new(1.0, 1.0), Point::new(3.0, 1.0)]);
+        let strips = line.voronoi(&bounds);
+        assert!((strips[0].as_ref().unwrap().area() - 1.5 * 4.0).abs() < 1e-9);
+        assert!((strips[1].as_ref().unwrap().area() - 1.5 * 4.0).abs() < 1e-9);
+        assert!((strips[2].as_ref().unwrap().area() - 1.0 * 4.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use vectoria::dual_quat::*;
+use vectoria::matrix::Mat4;
+use vectoria::quat::Quat;
+use vectoria::vector::Vec3;
+use vectoria::VectoriaError;
+use common::assert_vec3_close;
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::f32::consts::{ FRAC_1_SQRT_2, FRAC_PI_2 };
+
+    fn sample() -> DualQuat
+    {
+        let rotation = Quat::from_axis_angle(&Vec3::new(1.0, 2.0, -1.0), 1.2);
+        DualQuat::from_rotation_translation(&rotation, &Vec3::new(3.0, -1.0, 2.0))
+    }
+
+    #[test]
+    fn test_transform_point()
+    {
+        let rotation = Quat::from_axis_angle(&Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        let dq = DualQuat::from_rotation_translation(&rotation, &Vec3::new(1.0, 2.0, 3.0));
+        assert_vec3_close(&dq.transform_point(&Vec3::new(1.0, 0.0, 0.0)), &Vec3::new(1.0, 3.0, 3.0));
+        assert_vec3_close(&dq.transform_vector(&Vec3::new(1.0, 0.0, 0.0)), &Vec3::new(0.0, 1.0, 0.0));
+        assert_vec3_close(&dq.translation(), &Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_mat4_roundtrip()
+    {
+        let m = Mat4::translate(1.0, -2.0, 0.5).mul(&Mat4::rotate_y(0.8)).mul(&Mat4::rotate_x(-0.3));
+        let dq = DualQuat::from_mat4(&m).unwrap();
+        let back = dq.to_mat4();
+        for i in 0..4
+        {
+            for j in 0..4
+            {
+                assert!((m.data[i][j] - back.data[i][j]).abs() < 1e-5);
+            }
+        }
+        assert_eq!(DualQuat::from_mat4(&Mat4::scale(2.0, 1.0, 1.0)), Err(VectoriaError::Singular));
+        // Volume-preserving scale and shear, and a reflection.
+        assert_eq!(DualQuat::from_mat4(&Mat4::scale(2.0, 0.5, 1.0)), Err(VectoriaError::Singular));
+        let mut shear = Mat4::new_identity();
+        shear.data[0][1] = 0.5;
+        assert_eq!(DualQuat::from_mat4(&shear), Err(VectoriaError::Singular));
+        assert_eq!(DualQuat::from_mat4(&Mat4::scale(1.0, 1.0, -1.0)), Err(VectoriaError::Singular));
+    }
+
+    #[test]
+    fn test_composition_and_inverse()
+    {
+        let a = sample();
+        let b = DualQuat::from_rotation_translation(&Quat::from_axis_angle(&Vec3::new(0.0, 1.0, 0.0), -0.7), &Vec3::new(0.0, 4.0, 1.0));
+        let p = Vec3::new(0.5, 1.5, -2.0);
+        assert_vec3_close(&a.mul(&b).transform_point(&p), &a.transform_point(&b.transform_point(&p)));
+        assert_vec3_close(&a.inverse().transform_point(&a.transform_point(&p)), &p);
+        let composed = a.mul(&b).to_mat4().transform_point(&p);
+        let matrices = a.to_mat4().mul(&b.to_mat4()).transform_point(&p);
+        assert_vec3_close(&composed, &matrices);
+    }
+
+    #[test]
+    fn test_sclerp_endpoints_and_screw()
+    {
+        let a = DualQuat::identity();
+        let b = sample();
+        let p = Vec3::new(1.0, 1.0, 1.0);
+        assert_vec3_close(&a.sclerp(&b, 0.0).transform_point(&p), &p);
+        assert_vec3_close(&a.sclerp(&b, 1.0).transform_point(&p), &b.transform_point(&p));
+        let half = a.sclerp(&b, 0.5);
+        assert_vec3_close(&half.mul(&half).transform_point(&p), &b.transform_point(&p));
+    }
+
+    #[test]
+    fn test_sclerp_rotation_about_offset_axis()
+    {
+        // A quarter turn about the z axis through (1, 0, 0) moves the origin to (1, -1, 0);
+        // halfway there it has swept an eighth of a circle around that axis.
+        let rotation = Quat::from_axis_angle(&Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        let target = DualQuat::from_rotation_translation(&rotation, &Vec3::new(1.0, -1.0, 0.0));
+        let half = DualQuat::identity().sclerp(&target, 0.5);
+        let c = FRAC_1_SQRT_2;
+        assert_vec3_close(&half.transform_point(&Vec3::new(0.0, 0.0, 0.0)), &Vec3::new(1.0 - c, -c, 0.0));
+    }
+
+    #[test]
+    fn test_sclerp_pure_translation()
+    {
+        let target = DualQuat::from_translation(&Vec3::new(4.0, 0.0, -2.0));
+        let mid = DualQuat::identity().sclerp(&target, 0.25);
+        assert_vec3_close(&mid.translation(), &Vec3::new(1.0, 0.0, -0.5));
+    }
+
+    #[test]
+    fn test_blend()
+    {
+        let a = DualQuat::from_translation(&Vec3::new(2.0, 0.0, 0.0));
+        let b = DualQuat::from_translation(&Vec3::new(0.0, 2.0, 0.0));
+        let blended = DualQuat::blend(&[a, b.mul_scale(-1.0)], &[0.5, 0.5]).unwrap();
+        assert_vec3_close(&blended.translation(), &Vec3::new(1.0, 1.0, 0.0));
+
+        let r = sample();
+        let same = DualQuat::blend(&[r, r], &[0.3, 0.7]).unwrap();
+        assert_vec3_close(&same.transform_point(&Vec3::new(1.0, 0.0, 0.0)), &r.transform_point(&Vec3::new(1.0, 0.0, 0.0)));
+
+        assert_eq!(DualQuat::blend(&[a], &[0.5, 0.5]), Err(VectoriaError::DimensionMismatch { expected: 1, found: 2 }));
+        assert_eq!(DualQuat::blend(&[], &[]), Err(VectoriaError::ZeroLength));
+    }
+}
@@ -0,0 +1,124 @@
+use vectoria::angle::Degrees;
+use vectoria::euler::*;
+use vectoria::matrix::{ Mat3, Mat4 };
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::f32::consts::{ FRAC_PI_2, PI };
+
+    fn assert_mat3_close(a: &Mat3, b: &Mat3, tolerance: f32)
+    {
+        for i in 0..3
+        {
+            for j in 0..3
+            {
+                assert!((a.data[i][j] - b.data[i][j]).abs() < tolerance, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    fn is_proper(order: EulerRot) -> bool
+    {
+        matches!(
+            order,
+            EulerRot::XYX | EulerRot::XZX | EulerRot::YXY | EulerRot::YZY | EulerRot::ZXZ | EulerRot::ZYZ
+                | EulerRot::XYXEx | EulerRot::XZXEx | EulerRot::YXYEx | EulerRot::YZYEx | EulerRot::ZXZEx | EulerRot::ZYZEx
+        )
+    }
+
+    #[test]
+    fn test_intrinsic_matches_composition()
+    {
+        let m = Mat3::from_euler(EulerRot::XYZ, 0.1, 0.2, 0.3);
+        let expected = Mat3::rotate_x(0.1).mul(&Mat3::rotate_y(0.2)).mul(&Mat3::rotate_z(0.3));
+        assert_mat3_close(&m, &expected, 1e-6);
+    }
+
+    #[test]
+    fn test_extrinsic_matches_composition()
+    {
+        let m = Mat3::from_euler(EulerRot::XYZEx, 0.1, 0.2, 0.3);
+        let expected = Mat3::rotate_z(0.3).mul(&Mat3::rotate_y(0.2)).mul(&Mat3::rotate_x(0.1));
+        assert_mat3_close(&m, &expected, 1e-6);
+        let intrinsic = Mat3::from_euler(EulerRot::ZYX, 0.3, 0.2, 0.1);
+        assert_mat3_close(&m, &intrinsic, 1e-6);
+    }
+
+    #[test]
+    fn test_from_euler_degrees()
+    {
+        let m = Mat3::from_euler(EulerRot::XYZ, Degrees(90.0), Degrees(-45.0), Degrees(30.0));
+        assert_mat3_close(&m, &Mat3::from_euler(EulerRot::XYZ, FRAC_PI_2, -PI / 4.0, PI / 6.0), 1e-6);
+        let m4 = Mat4::from_euler(EulerRot::ZYX, Degrees(90.0), Degrees(-45.0), Degrees(30.0));
+        assert_mat3_close(&Mat3::from_mat4(&m4), &Mat3::from_euler(EulerRot::ZYX, FRAC_PI_2, -PI / 4.0, PI / 6.0), 1e-6);
+    }
+
+    #[test]
+    fn test_angle_roundtrip_all_orders()
+    {
+        let outer = [-3.0, -1.7, -0.4, 0.0, 0.9, 2.5];
+        for order in EulerRot::ALL.iter()
+        {
+            let middle: &[f32] = if is_proper(*order) { &[0.3, 1.0, 2.0, 2.9] } else { &[-1.4, -0.5, 0.0, 0.7, 1.3] };
+            for &a in outer.iter()
+            {
+                for &b in middle.iter()
+                {
+                    for &c in outer.iter()
+                    {
+                        let m = Mat3::from_euler(*order, a, b, c);
+                        let (ra, rb, rc) = m.to_euler(*order);
+                        assert!((ra - a).abs() < 1e-3, "{:?} a: {} != {}", order, ra, a);
+                        assert!((rb - b).abs() < 1e-3, "{:?} b: {} != {}", order, rb, b);
+                        assert!((rc - c).abs() < 1e-3, "{:?} c: {} != {}", order, rc, c);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_roundtrip_out_of_range_angles()
+    {
+        for order in EulerRot::ALL.iter()
+        {
+            let m = Mat3::from_euler(*order, 4.0, -2.5, 7.0);
+            let (a, b, c) = m.to_euler(*order);
+            assert_mat3_close(&Mat3::from_euler(*order, a, b, c), &m, 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_gimbal_lock()
+    {
+        for order in EulerRot::ALL.iter()
+        {
+            let locked: &[f32] = if is_proper(*order) { &[0.0, PI] } else { &[FRAC_PI_2, -FRAC_PI_2] };
+            for &b in locked.iter()
+            {
+                let m = Mat3::from_euler(*order, 0.7, b, -0.4);
+                let (ra, rb, rc) = m.to_euler(*order);
+                assert!(ra.is_finite() && rb.is_finite() && rc.is_finite());
+                assert_mat3_close(&Mat3::from_euler(*order, ra, rb, rc), &m, 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mat4_euler()
+    {
+        let m = Mat4::from_euler(EulerRot::ZYX, 0.5, -0.25, 1.0);
+        assert_eq!(m.data[3], [0.0, 0.0, 0.0, 1.0]);
+        let expected = Mat4::rotate_z(0.5).mul(&Mat4::rotate_y(-0.25)).mul(&Mat4::rotate_x(1.0));
+        for i in 0..4
+        {
+            for j in 0..4
+            {
+                assert!((m.data[i][j] - expected.data[i][j]).abs() < 1e-6);
+            }
+        }
+        let (a, b, c) = m.mul(&Mat4::translate(1.0, 2.0, 3.0)).to_euler(EulerRot::ZYX);
+        assert!((a - 0.5).abs() < 1e-5 && (b + 0.25).abs() < 1e-5 && (c - 1.0).abs() < 1e-5);
+    }
+}
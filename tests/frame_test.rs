@@ -0,0 +1,72 @@
+mod common;
+
+use vectoria::frame::*;
+use vectoria::geometry::Point3D;
+use vectoria::matrix::Mat3;
+use vectoria::vector::Vec3;
+use vectoria::VectoriaError;
+use common::assert_vec3_close;
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_from_normal()
+    {
+        let normal = Vec3::new(0.0, 3.0, 4.0);
+        let frame = Frame3::from_normal(Vec3::new(1.0, 2.0, 3.0), &normal).unwrap();
+        assert_vec3_close(&frame.z_axis, &Vec3::new(0.0, 0.6, 0.8));
+        assert!(frame.x_axis.dot(&frame.z_axis).abs() < 1e-6);
+        assert!(frame.y_axis.dot(&frame.z_axis).abs() < 1e-6);
+        assert_vec3_close(&frame.x_axis.cross(&frame.y_axis), &frame.z_axis);
+        assert_eq!(Frame3::from_normal(Vec3::new(0.0, 0.0, 0.0), &Vec3::new(0.0, 0.0, 0.0)), Err(VectoriaError::ZeroLength));
+    }
+
+    #[test]
+    fn test_point_roundtrip()
+    {
+        let frame = Frame3::from_normal(Vec3::new(1.0, -2.0, 0.5), &Vec3::new(1.0, 1.0, 1.0)).unwrap();
+        let world = Vec3::new(3.0, 4.0, -5.0);
+        let local = frame.point_to_local(&world);
+        assert_vec3_close(&frame.point_to_world(&local), &world);
+        assert_vec3_close(&frame.point_to_local(&frame.origin), &Vec3::new(0.0, 0.0, 0.0));
+        let v = Vec3::new(0.0, 1.0, 0.0);
+        assert_vec3_close(&frame.vector_to_world(&frame.vector_to_local(&v)), &v);
+    }
+
+    #[test]
+    fn test_point3d_roundtrip()
+    {
+        let frame = Frame3::from_normal(Vec3::new(1.0, 2.0, 3.0), &Vec3::new(0.0, 1.0, 0.0)).unwrap();
+        let point = Point3D::new(10.0, 20.0, 30.0);
+        let back = frame.point3d_to_world(&frame.point3d_to_local(&point));
+        assert!(back.distance(&point) < 1e-5);
+        let local = frame.point3d_to_local(&point);
+        assert!((local.z - 18.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_from_basis_orthonormalizes()
+    {
+        let basis = Mat3 { data: [[2.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 3.0]] };
+        let frame = Frame3::from_basis(Vec3::new(0.0, 0.0, 0.0), &basis).unwrap();
+        assert_vec3_close(&frame.x_axis, &Vec3::new(1.0, 0.0, 0.0));
+        assert_vec3_close(&frame.y_axis, &Vec3::new(0.0, 1.0, 0.0));
+        assert_vec3_close(&frame.z_axis, &Vec3::new(0.0, 0.0, 1.0));
+        let mirror = Mat3 { data: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]] };
+        assert_eq!(Frame3::from_basis(Vec3::new(0.0, 0.0, 0.0), &mirror), Err(VectoriaError::Singular));
+    }
+
+    #[test]
+    fn test_matrices()
+    {
+        let frame = Frame3::from_normal(Vec3::new(4.0, 5.0, 6.0), &Vec3::new(-1.0, 2.0, 0.5)).unwrap();
+        let model = frame.to_model_matrix();
+        let view = frame.to_view_matrix();
+        let local = Vec3::new(1.0, 2.0, 3.0);
+        assert_vec3_close(&model.transform_point(&local), &frame.point_to_world(&local));
+        let world = model.transform_point(&local);
+        assert_vec3_close(&view.transform_point(&world), &local);
+    }
+}
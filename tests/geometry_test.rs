@@ -1,4 +1,6 @@
 use vectoria::geometry::*;
+use vectoria::matrix::{ Mat3, Mat4 };
+use vectoria::vector::{ Vec2, Vec3 };
 #[cfg(test)]
 mod tests
 {
@@ -104,4 +106,292 @@ mod tests
         let cube = Cube::new(center, 2.0);
         assert_eq!(cube.surface_area(), 24.0);
     }
-}
\ No newline at end of file
+
+    fn assert_point_close(a: &Point, b: &Point)
+    {
+        assert!(a.distance(b) < 1e-6, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_point_vector_conversions()
+    {
+        let p = Point::new(1.5, -2.0);
+        let v: Vec2 = p.into();
+        assert_eq!(v, Vec2::new(1.5, -2.0));
+        assert_eq!(Point::from(v), p);
+        let q = Point3D::new(1.0, 2.0, 3.0);
+        assert_eq!(Point3D::from_vec3(&q.to_vec3()), q);
+    }
+
+    #[test]
+    fn test_point_arithmetic()
+    {
+        let a = Point::new(1.0, 2.0);
+        let b = Point::new(4.0, 6.0);
+        let d = b.sub(&a);
+        assert_eq!(d, Vec2::new(3.0, 4.0));
+        assert_eq!(a.add_vector(&d), b);
+        assert_eq!(b.sub_vector(&d), a);
+        assert_eq!(a.lerp(&b, 0.5), Point::new(2.5, 4.0));
+        assert_eq!(Point::centroid(&[a, b, Point::new(1.0, 1.0)]), Some(Point::new(2.0, 3.0)));
+        assert_eq!(Point::centroid(&[]), None);
+
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(2.0, 4.0, 6.0);
+        assert_eq!(b.sub(&a), Vec3::new(2.0, 4.0, 6.0));
+        assert_eq!(a.add_vector(&Vec3::new(1.0, 1.0, 1.0)), Point3D::new(1.0, 1.0, 1.0));
+        assert_eq!(a.lerp(&b, 0.25), Point3D::new(0.5, 1.0, 1.5));
+        assert_eq!(Point3D::centroid(&[a, b]), Some(Point3D::new(1.0, 2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_transform_points_and_shapes()
+    {
+        let mut m = Mat3::rotate_z(std::f32::consts::FRAC_PI_2);
+        m.data[0][2] = 1.0;
+        m.data[1][2] = 2.0;
+        assert_point_close(&Point::new(1.0, 0.0).transform(&m), &Point::new(1.0, 3.0));
+
+        let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0));
+        let moved = triangle.transform(&m);
+        assert_point_close(&moved.a, &Point::new(1.0, 2.0));
+        assert_point_close(&moved.c, &Point::new(0.0, 2.0));
+        assert!((moved.area() - triangle.area()).abs() < 1e-6);
+
+        let line = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0)).transform(&m);
+        assert_point_close(&line.end, &Point::new(1.0, 4.0));
+
+        let mut circle = Circle::new(Point::new(0.0, 0.0), 1.0);
+        circle.translate(2.0, 3.0);
+        assert!(circle.contains(&Point::new(2.5, 3.0)));
+        let mut rectangle = Rectangle::new(Point::new(0.0, 0.0), 1.0, 1.0);
+        rectangle.translate(5.0, 5.0);
+        assert!(rectangle.contains(&Point::new(5.5, 5.5)));
+        let turned = Rectangle::new(Point::new(0.0, 0.0), 2.0, 1.0).transform(&m);
+        assert_point_close(&turned.top_left, &Point::new(0.0, 2.0));
+        assert!((turned.width - 1.0).abs() < 1e-6 && (turned.height - 2.0).abs() < 1e-6);
+        let mut doubling = m;
+        for row in doubling.data.iter_mut().take(2)
+        {
+            row[0] *= 2.0;
+            row[1] *= 2.0;
+        }
+        assert!((Circle::new(Point::new(0.0, 0.0), 1.5).transform(&doubling).radius - 3.0).abs() < 1e-6);
+
+        let m4 = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::rotate_z(std::f32::consts::FRAC_PI_2));
+        let p = Point3D::new(1.0, 0.0, 0.0).transform(&m4);
+        assert!(p.distance(&Point3D::new(1.0, 3.0, 3.0)) < 1e-6);
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.0), 1.0).transform(&m4);
+        assert!(sphere.contains(&Point3D::new(1.0, 2.0, 3.5)));
+        let mut cube = Cube::new(Point3D::new(0.0, 0.0, 0.0), 2.0);
+        cube.translate(10.0, 0.0, 0.0);
+        assert!(cube.contains(&Point3D::new(10.5, 0.0, 0.0)));
+        let scaled = Sphere::new(Point3D::new(0.0, 0.0, 0.0), 1.0).transform(&Mat4::scale(3.0, 3.0, 3.0));
+        assert!((scaled.radius - 3.0).abs() < 1e-6);
+        let cube = Cube::new(Point3D::new(0.0, 0.0, 0.0), 2.0);
+        let bounds = cube.transform(&Mat4::rotate_z(std::f32::consts::FRAC_PI_4));
+        assert!((bounds.max.x - 2.0f64.sqrt()).abs() < 1e-6 && (bounds.min.y + 2.0f64.sqrt()).abs() < 1e-6);
+        assert!((bounds.max.z - 1.0).abs() < 1e-6);
+        let moved = cube.transform(&m4);
+        assert!(moved.min.distance(&Point3D::new(0.0, 1.0, 2.0)) < 1e-6 && moved.max.distance(&Point3D::new(2.0, 3.0, 4.0)) < 1e-6);
+    }
+
+    fn total_area(shapes: &[&dyn Shape2D]) -> f64
+    {
+        shapes.iter().map(|s| s.area()).sum()
+    }
+
+    #[test]
+    fn test_shape2d_generic()
+    {
+        let circle = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let rectangle = Rectangle::new(Point::new(0.0, 0.0), 2.0, 3.0);
+        let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        let total = total_area(&[&circle, &rectangle, &triangle]);
+        assert!((total - (std::f64::consts::PI + 6.0 + 6.0)).abs() < 1e-9);
+        assert_eq!(Shape2D::perimeter(&triangle), 12.0);
+        let thin = Triangle::new(Point::new(0.0, 0.0), Point::new(1e8, 1.0), Point::new(2e8, 2.0 + 1e-7));
+        let total = total_area(&[&circle, &thin]);
+        assert!((total - (std::f64::consts::PI + 5.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_shape2d_circle()
+    {
+        let circle = Circle::new(Point::new(1.0, 1.0), 2.0);
+        let bounds = circle.bounding_box();
+        assert_eq!((bounds.top_left, bounds.width, bounds.height), (Point::new(-1.0, -1.0), 4.0, 4.0));
+        assert_point_close(&Shape2D::closest_point(&circle, &Point::new(5.0, 1.0)), &Point::new(3.0, 1.0));
+        assert_eq!(circle.signed_distance(&Point::new(1.0, 1.0)), -2.0);
+        assert_eq!(circle.signed_distance(&Point::new(1.0, 4.0)), 1.0);
+        assert_eq!(Shape2D::centroid(&circle), Point::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_shape2d_rectangle()
+    {
+        let rectangle = Rectangle::new(Point::new(0.0, 0.0), 4.0, 2.0);
+        assert_eq!(Shape2D::centroid(&rectangle), Point::new(2.0, 1.0));
+        assert!((rectangle.bounding_circle().radius - 5.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(Shape2D::closest_point(&rectangle, &Point::new(1.0, 0.5)), Point::new(1.0, 0.0));
+        assert_eq!(Shape2D::closest_point(&rectangle, &Point::new(6.0, 3.0)), Point::new(4.0, 2.0));
+        assert_eq!(rectangle.signed_distance(&Point::new(1.0, 0.5)), -0.5);
+        assert_eq!(rectangle.signed_distance(&Point::new(7.0, 6.0)), 5.0);
+        assert_eq!(rectangle.signed_distance(&Point::new(4.0, 1.0)), 0.0);
+    }
+
+    #[test]
+    fn test_shape2d_triangle()
+    {
+        let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 4.0));
+        let reversed = Triangle::new(triangle.a, triangle.c, triangle.b);
+        for t in [&triangle, &reversed]
+        {
+            assert!(Shape2D::contains(t, &Point::new(1.0, 1.0)));
+            assert!(Shape2D::contains(t, &Point::new(2.0, 2.0)));
+            assert!(!Shape2D::contains(t, &Point::new(3.0, 3.0)));
+        }
+        let bounds = triangle.bounding_box();
+        assert_eq!((bounds.width, bounds.height), (4.0, 4.0));
+        // Right triangle: the minimal circle sits on the hypotenuse.
+        let circle = triangle.bounding_circle();
+        assert_point_close(&circle.center, &Point::new(2.0, 2.0));
+        assert!((circle.radius - 8.0f64.sqrt()).abs() < 1e-12);
+        let acute = Triangle::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 3.0));
+        let circle = acute.bounding_circle();
+        for p in [&acute.a, &acute.b, &acute.c]
+        {
+            assert!((circle.center.distance(p) - circle.radius).abs() < 1e-9);
+        }
+        assert_point_close(&Shape2D::closest_point(&triangle, &Point::new(1.0, -1.0)), &Point::new(1.0, 0.0));
+        assert!((triangle.signed_distance(&Point::new(1.0, 1.0)) + 1.0).abs() < 1e-12);
+        assert!((triangle.signed_distance(&Point::new(-2.0, 1.0)) - 2.0).abs() < 1e-12);
+        assert_point_close(&Shape2D::centroid(&triangle), &Point::new(4.0 / 3.0, 4.0 / 3.0));
+    }
+
+    #[test]
+    fn test_shape3d()
+    {
+        let sphere = Sphere::new(Point3D::new(0.0, 0.0, 0.0), 2.0);
+        let cube = Cube::new(Point3D::new(0.0, 0.0, 0.0), 2.0);
+        let shapes: [&dyn Shape3D; 2] = [&sphere, &cube];
+        let volume: f64 = shapes.iter().map(|s| s.volume()).sum();
+        assert!((volume - (32.0 / 3.0 * std::f64::consts::PI + 8.0)).abs() < 1e-9);
+
+        assert_eq!(sphere.bounding_box(), Box3D::new(Point3D::new(-2.0, -2.0, -2.0), Point3D::new(2.0, 2.0, 2.0)));
+        assert_eq!(sphere.signed_distance(&Point3D::new(0.0, 3.0, 0.0)), 1.0);
+        assert_eq!(Shape3D::closest_point(&sphere, &Point3D::new(0.0, 0.0, -5.0)), Point3D::new(0.0, 0.0, -2.0));
+
+        assert!((cube.bounding_sphere().radius - 3.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(cube.signed_distance(&Point3D::new(0.0, 0.0, 0.5)), -0.5);
+        assert_eq!(cube.signed_distance(&Point3D::new(4.0, 5.0, 0.0)), 5.0);
+        assert_eq!(Shape3D::closest_point(&cube, &Point3D::new(0.2, 0.0, 0.9)), Point3D::new(0.2, 0.0, 1.0));
+
+        let bounds = Box3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 2.0, 3.0));
+        assert_eq!(bounds.volume(), 6.0);
+        assert_eq!(bounds.surface_area(), 22.0);
+        assert!(Shape3D::contains(&bounds, &Point3D::new(0.5, 1.0, 2.9)));
+        assert_eq!(Shape3D::centroid(&bounds), Point3D::new(0.5, 1.0, 1.5));
+    }
+
+    #[test]
+    fn test_triangle_orientation_and_barycentric()
+    {
+        let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 4.0));
+        assert_eq!(triangle.signed_area(), 8.0);
+        assert_eq!(triangle.winding(), Winding::CounterClockwise);
+        let reversed = Triangle::new(triangle.a, triangle.c, triangle.b);
+        assert_eq!(reversed.signed_area(), -8.0);
+        assert_eq!(reversed.winding(), Winding::Clockwise);
+
+        let (u, v, w) = triangle.barycentric(&Point::new(1.0, 2.0)).unwrap();
+        assert!((u - 0.25).abs() < 1e-12 && (v - 0.25).abs() < 1e-12 && (w - 0.5).abs() < 1e-12);
+        assert!(triangle.contains(&Point::new(2.0, 2.0)));
+        assert!(!reversed.contains(&Point::new(-0.1, 1.0)));
+
+        let flat = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 2.0));
+        assert_eq!(flat.winding(), Winding::Collinear);
+        assert_eq!(flat.area(), 0.0);
+        // Thin enough that Heron's formula goes negative under the square root.
+        let thin = Triangle::new(Point::new(0.0, 0.0), Point::new(1e8, 1.0), Point::new(2e8, 2.0 + 1e-7));
+        assert!((thin.area() - 5.0).abs() < 1e-6);
+        assert_eq!(flat.barycentric(&Point::new(0.0, 0.0)), None);
+        assert!(flat.circumcenter().is_none() && flat.incircle().is_none() && flat.orthocenter().is_none());
+        // A degenerate triangle holds only the points of its longest edge.
+        assert!(flat.contains(&Point::new(1.5, 1.5)));
+        assert!(!flat.contains(&Point::new(5.0, 5.0)) && !flat.contains(&Point::new(1.0, 0.0)));
+        assert!(flat.signed_distance(&Point::new(5.0, 5.0)) > 0.0);
+        let point = Triangle::new(Point::new(1.0, 2.0), Point::new(1.0, 2.0), Point::new(1.0, 2.0));
+        assert!(point.contains(&Point::new(1.0, 2.0)));
+        assert!(!point.contains(&Point::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_triangle_centers()
+    {
+        let triangle = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        assert_point_close(&triangle.centroid(), &Point::new(4.0 / 3.0, 1.0));
+        assert_point_close(&triangle.circumcenter().unwrap(), &Point::new(2.0, 1.5));
+        assert!((triangle.circumcircle().unwrap().radius - 2.5).abs() < 1e-12);
+        // Right angle at `a`: the orthocenter is that vertex.
+        assert_point_close(&triangle.orthocenter().unwrap(), &triangle.a);
+        // 3-4-5 triangle: inradius (3 + 4 - 5) / 2 = 1.
+        let incircle = triangle.incircle().unwrap();
+        assert_point_close(&incircle.center, &Point::new(1.0, 1.0));
+        assert!((incircle.radius - 1.0).abs() < 1e-12);
+
+        let scalene = Triangle::new(Point::new(-1.0, 0.0), Point::new(3.0, 0.5), Point::new(0.5, 4.0));
+        let h = scalene.orthocenter().unwrap();
+        let along = |p: &Point, q: &Point, r: &Point| (h.x - p.x) * (r.x - q.x) + (h.y - p.y) * (r.y - q.y);
+        assert!(along(&scalene.a, &scalene.b, &scalene.c).abs() < 1e-9);
+        assert!(along(&scalene.b, &scalene.c, &scalene.a).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_triangle_angles_and_classification()
+    {
+        let right = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        let (a, b, c) = right.angles();
+        assert!((a - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((a + b + c - std::f64::consts::PI).abs() < 1e-12);
+        assert_eq!(right.classify(), TriangleKind::Right);
+
+        let equilateral = Triangle::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 3.0f64.sqrt()));
+        let (a, _, _) = equilateral.angles();
+        assert!((a - std::f64::consts::FRAC_PI_3).abs() < 1e-12);
+        assert_eq!(equilateral.classify(), TriangleKind::Acute);
+
+        let obtuse = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(-1.0, 1.0));
+        assert_eq!(obtuse.classify(), TriangleKind::Obtuse);
+        let degenerate = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(3.0, 0.0));
+        assert_eq!(degenerate.classify(), TriangleKind::Degenerate);
+        let point = Triangle::new(Point::new(1.0, 1.0), Point::new(1.0, 1.0), Point::new(1.0, 1.0));
+        assert_eq!(point.classify(), TriangleKind::Degenerate);
+    }
+
+    #[test]
+    fn test_triangle3d()
+    {
+        let triangle = Triangle3D::new(Point3D::new(0.0, 0.0, 1.0), Point3D::new(2.0, 0.0, 1.0), Point3D::new(0.0, 2.0, 1.0));
+        assert_eq!(triangle.area(), 2.0);
+        assert_eq!(triangle.area_vector(), Vec3::new(0.0, 0.0, 2.0));
+        assert_eq!(triangle.normal(), Some(Vec3::new(0.0, 0.0, 1.0)));
+        assert_eq!(triangle.perimeter(), 4.0 + 8.0f64.sqrt());
+        let (u, v, w) = triangle.barycentric(&Point3D::new(0.5, 0.5, 7.0)).unwrap();
+        assert!((u - 0.5).abs() < 1e-12 && (v - 0.25).abs() < 1e-12 && (w - 0.25).abs() < 1e-12);
+
+        let close = |p: Point3D, expected: Point3D| assert!(triangle.closest_point(&p).distance(&expected) < 1e-12);
+        close(Point3D::new(0.5, 0.5, 5.0), Point3D::new(0.5, 0.5, 1.0));
+        close(Point3D::new(-1.0, -1.0, 0.0), Point3D::new(0.0, 0.0, 1.0));
+        close(Point3D::new(3.0, -1.0, 1.0), Point3D::new(2.0, 0.0, 1.0));
+        close(Point3D::new(1.0, -3.0, 1.0), Point3D::new(1.0, 0.0, 1.0));
+        close(Point3D::new(-2.0, 1.0, 2.0), Point3D::new(0.0, 1.0, 1.0));
+        close(Point3D::new(2.0, 2.0, 1.0), Point3D::new(1.0, 1.0, 1.0));
+        close(Point3D::new(0.0, 5.0, 1.0), Point3D::new(0.0, 2.0, 1.0));
+
+        let degenerate = Triangle3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0), Point3D::new(2.0, 2.0, 2.0));
+        assert_eq!(degenerate.normal(), None);
+        assert_eq!(degenerate.area(), 0.0);
+        assert_point_close(&Point::new(triangle.centroid().x, triangle.centroid().y), &Point::new(2.0 / 3.0, 2.0 / 3.0));
+    }
+}
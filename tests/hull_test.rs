@@ -0,0 +1,125 @@
+mod common;
+
+use vectoria::geometry::hull::*;
+use vectoria::geometry::{ Point, Point3D };
+use vectoria::vector::{ Vec2, Vec3 };
+use common::Rng;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_hull_2d()
+    {
+        // Corners, a point inside, points along edges and a repeated corner.
+        let points = [
+            Point::new(1.0, 1.0),
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 1.0),
+            Point::new(2.0, 2.0),
+        ];
+        assert_eq!(convex_hull_indices(&points), vec![1, 2, 4, 5]);
+        let hull = ConvexHull::new(&points);
+        assert_eq!(hull.area(), 4.0);
+        assert_eq!(hull.perimeter(), 8.0);
+        assert!(hull.to_polygon().is_convex());
+        assert!(hull.contains(&Point::new(1.0, 1.0)));
+        assert!(hull.contains(&Point::new(2.0, 1.0)));
+        assert!(!hull.contains(&Point::new(2.1, 1.0)));
+        assert_eq!(hull.support(&Vec2::new(1.0, 1.0)), Some(Point::new(2.0, 2.0)));
+        assert_eq!(hull.support(&Vec2::new(-1.0, 0.1)), Some(Point::new(0.0, 2.0)));
+
+        let from_vectors = ConvexHull::from_vec2(&[Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0), Vec2::new(0.2, 0.2)]);
+        assert_eq!(from_vectors.vertices.len(), 3);
+        assert_eq!(from_vectors.area(), 0.5);
+    }
+
+    #[test]
+    fn test_degenerate_2d()
+    {
+        assert!(ConvexHull::new(&[]).vertices.is_empty());
+        assert_eq!(ConvexHull::new(&[Point::new(1.0, 1.0), Point::new(1.0, 1.0)]).vertices, vec![Point::new(1.0, 1.0)]);
+
+        let line = ConvexHull::new(&[Point::new(0.0, 0.0), Point::new(3.0, 3.0), Point::new(1.0, 1.0), Point::new(2.0, 2.0)]);
+        assert_eq!(line.vertices, vec![Point::new(0.0, 0.0), Point::new(3.0, 3.0)]);
+        assert_eq!(line.area(), 0.0);
+        assert!(line.contains(&Point::new(1.5, 1.5)));
+        assert!(!line.contains(&Point::new(1.5, 1.0)));
+        assert_eq!(ConvexHull::new(&[]).support(&Vec2::new(1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_random_2d()
+    {
+        let mut rng = Rng(31);
+        let points: Vec<Point> = (0..500).map(|_| Point::new(rng.next(), rng.next())).collect();
+        let hull = ConvexHull::new(&points);
+        assert!(hull.to_polygon().is_convex());
+        assert!(hull.area() > 0.9 && hull.area() <= 1.0);
+        assert!(points.iter().all(|p| hull.contains(p)));
+    }
+
+    #[test]
+    fn test_hull_3d()
+    {
+        let mut points = Vec::new();
+        for i in 0..27
+        {
+            points.push(Point3D::new((i % 3) as f64 - 1.0, ((i / 3) % 3) as f64 - 1.0, (i / 9) as f64 - 1.0));
+        }
+        let hull = ConvexHull3D::new(&points).unwrap();
+        // Only the 8 corners survive; edge and face midpoints lie on the hull.
+        assert_eq!(hull.vertex_indices(), vec![0, 2, 6, 8, 18, 20, 24, 26]);
+        assert_eq!(hull.faces.len(), 12);
+        assert!((hull.volume() - 8.0).abs() < 1e-12);
+        assert!((hull.surface_area() - 24.0).abs() < 1e-12);
+        for face in &hull.faces
+        {
+            let center = hull.points[face.vertices[0]];
+            let outward = face.normal.x as f64 * center.x + face.normal.y as f64 * center.y + face.normal.z as f64 * center.z;
+            assert!(outward > 0.0);
+            assert!((face.normal.length() - 1.0).abs() < 1e-6);
+        }
+        assert!(hull.contains(&Point3D::new(0.0, 0.0, 0.0)));
+        assert!(hull.contains(&Point3D::new(1.0, 0.5, -1.0)));
+        assert!(!hull.contains(&Point3D::new(1.0, 0.5, -1.01)));
+        assert_eq!(hull.support(&Vec3::new(1.0, 1.0, 1.0)), Point3D::new(1.0, 1.0, 1.0));
+        assert_eq!(hull.support(&Vec3::new(-1.0, 0.1, -0.1)), Point3D::new(-1.0, 1.0, -1.0));
+    }
+
+    #[test]
+    fn test_degenerate_3d()
+    {
+        assert!(ConvexHull3D::new(&[]).is_none());
+        let flat = [Point3D::new(0.0, 0.0, 1.0), Point3D::new(1.0, 0.0, 1.0), Point3D::new(0.0, 1.0, 1.0), Point3D::new(1.0, 1.0, 1.0)];
+        assert!(ConvexHull3D::new(&flat).is_none());
+
+        let tetrahedron = ConvexHull3D::from_vec3(&[Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)]).unwrap();
+        assert_eq!(tetrahedron.faces.len(), 4);
+        assert!((tetrahedron.volume() - 1.0 / 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_random_3d()
+    {
+        let mut rng = Rng(5);
+        let points: Vec<Point3D> = (0..400)
+            .map(|_| {
+                let (u, v, r) = (rng.next() * std::f64::consts::TAU, rng.next() * 2.0 - 1.0, rng.next().cbrt());
+                let s = (1.0 - v * v).sqrt();
+                Point3D::new(r * s * u.cos(), r * s * u.sin(), r * v)
+            })
+            .collect();
+        let hull = ConvexHull3D::new(&points).unwrap();
+        assert!(points.iter().all(|p| hull.contains(p)));
+        // A closed triangulated surface of genus zero.
+        assert_eq!(hull.faces.len(), 2 * hull.vertex_indices().len() - 4);
+        assert!(hull.volume() > 0.0 && hull.volume() < 4.0 / 3.0 * std::f64::consts::PI);
+    }
+}
@@ -0,0 +1,154 @@
+use vectoria::geometry::intersection::*;
+use vectoria::geometry::{ Circle, Line, Point, Rectangle };
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line
+    {
+        Line::new(Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    fn assert_near(p: &Point, x: f64, y: f64)
+    {
+        assert!((p.x - x).abs() < 1e-12 && (p.y - y).abs() < 1e-12, "{:?} != ({}, {})", p, x, y);
+    }
+
+    #[test]
+    fn test_segment_intersection()
+    {
+        let a = segment(0.0, 0.0, 4.0, 4.0);
+        assert_eq!(a.intersection(&segment(0.0, 4.0, 4.0, 0.0)), SegmentIntersection::Point(Point::new(2.0, 2.0)));
+        assert_eq!(a.intersection(&segment(0.0, 1.0, 1.0, 2.0)), SegmentIntersection::Disjoint);
+        assert_eq!(a.intersection(&segment(5.0, 0.0, 3.0, 2.0)), SegmentIntersection::Disjoint);
+        // Touching at an endpoint, and a T junction.
+        assert_eq!(a.intersection(&segment(4.0, 4.0, 6.0, 0.0)), SegmentIntersection::Point(Point::new(4.0, 4.0)));
+        assert_eq!(a.intersection(&segment(1.0, 1.0, 3.0, -1.0)), SegmentIntersection::Point(Point::new(1.0, 1.0)));
+        // A crossing with inexact coordinates.
+        match segment(0.1, 0.3, 0.7, 0.2).intersection(&segment(0.2, 0.0, 0.4, 0.9))
+        {
+            SegmentIntersection::Point(p) => assert!(segment(0.1, 0.3, 0.7, 0.2).distance_to_point(&p) < 1e-15),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_collinear_segments()
+    {
+        let a = segment(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(a.intersection(&segment(3.0, 0.0, 1.0, 0.0)), SegmentIntersection::Overlap(Point::new(1.0, 0.0), Point::new(3.0, 0.0)));
+        assert_eq!(a.intersection(&segment(6.0, 0.0, 2.0, 0.0)), SegmentIntersection::Overlap(Point::new(2.0, 0.0), Point::new(4.0, 0.0)));
+        assert_eq!(a.intersection(&segment(-1.0, 0.0, 5.0, 0.0)), SegmentIntersection::Overlap(Point::new(0.0, 0.0), Point::new(4.0, 0.0)));
+        assert_eq!(a.intersection(&segment(4.0, 0.0, 7.0, 0.0)), SegmentIntersection::Point(Point::new(4.0, 0.0)));
+        assert_eq!(a.intersection(&segment(5.0, 0.0, 7.0, 0.0)), SegmentIntersection::Disjoint);
+        // Reversed direction follows the first segment.
+        let reversed = segment(4.0, 0.0, 0.0, 0.0);
+        assert_eq!(reversed.intersection(&segment(1.0, 0.0, 3.0, 0.0)), SegmentIntersection::Overlap(Point::new(3.0, 0.0), Point::new(1.0, 0.0)));
+        // Degenerate segments.
+        assert_eq!(a.intersection(&segment(2.0, 0.0, 2.0, 0.0)), SegmentIntersection::Point(Point::new(2.0, 0.0)));
+        assert_eq!(segment(2.0, 1.0, 2.0, 1.0).intersection(&a), SegmentIntersection::Disjoint);
+    }
+
+    #[test]
+    fn test_line_intersection_and_distance()
+    {
+        let a = segment(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(a.line_intersection(&segment(5.0, 0.0, 4.0, 1.0)), Some(Point::new(2.5, 2.5)));
+        assert_eq!(a.line_intersection(&segment(0.0, 1.0, 1.0, 2.0)), None);
+        assert_eq!(a.line_intersection(&segment(3.0, 3.0, 3.0, 3.0)), None);
+
+        let b = segment(0.0, 0.0, 4.0, 0.0);
+        assert_eq!(b.closest_point(&Point::new(1.0, 3.0)), Point::new(1.0, 0.0));
+        assert_eq!(b.closest_point(&Point::new(-2.0, 1.0)), Point::new(0.0, 0.0));
+        assert_eq!(b.distance_to_point(&Point::new(7.0, 4.0)), 5.0);
+        assert_eq!(b.distance_to_point(&Point::new(2.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_segment_circle()
+    {
+        let circle = Circle::new(Point::new(0.0, 0.0), 5.0);
+        assert_eq!(segment(-10.0, 3.0, 10.0, 3.0).circle_intersections(&circle), vec![Point::new(-4.0, 3.0), Point::new(4.0, 3.0)]);
+        assert_eq!(segment(10.0, 3.0, -10.0, 3.0).circle_intersections(&circle), vec![Point::new(4.0, 3.0), Point::new(-4.0, 3.0)]);
+        assert_eq!(segment(0.0, 0.0, 0.0, 10.0).circle_intersections(&circle), vec![Point::new(0.0, 5.0)]);
+        assert_eq!(segment(-1.0, 5.0, 1.0, 5.0).circle_intersections(&circle), vec![Point::new(0.0, 5.0)]);
+        assert!(segment(-1.0, 0.0, 1.0, 0.0).circle_intersections(&circle).is_empty());
+        assert!(segment(-9.0, 6.0, 9.0, 6.0).circle_intersections(&circle).is_empty());
+    }
+
+    #[test]
+    fn test_circle_circle()
+    {
+        let a = Circle::new(Point::new(0.0, 0.0), 5.0);
+        assert_eq!(a.intersections(&Circle::new(Point::new(8.0, 0.0), 5.0)), vec![Point::new(4.0, 3.0), Point::new(4.0, -3.0)]);
+        assert_eq!(a.intersections(&Circle::new(Point::new(8.0, 0.0), 3.0)), vec![Point::new(5.0, 0.0)]);
+        assert_eq!(a.intersections(&Circle::new(Point::new(2.0, 0.0), 3.0)), vec![Point::new(5.0, 0.0)]);
+        assert!(a.intersections(&Circle::new(Point::new(11.0, 0.0), 5.0)).is_empty());
+        assert!(a.intersections(&Circle::new(Point::new(1.0, 0.0), 1.0)).is_empty());
+        assert!(a.intersections(&a.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_tangents_from_point()
+    {
+        let circle = Circle::new(Point::new(0.0, 0.0), 3.0);
+        let tangents = circle.tangents_from_point(&Point::new(5.0, 0.0));
+        assert_eq!(tangents.len(), 2);
+        assert_near(&tangents[0].end, 1.8, -2.4);
+        assert_near(&tangents[1].end, 1.8, 2.4);
+        assert!(tangents.iter().all(|t| t.start == Point::new(5.0, 0.0) && (t.length() - 4.0).abs() < 1e-12));
+
+        let on = circle.tangents_from_point(&Point::new(0.0, 3.0));
+        assert_eq!(on.len(), 1);
+        assert_eq!(on[0].end, Point::new(-3.0, 3.0));
+        assert!(circle.tangents_from_point(&Point::new(1.0, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn test_common_tangents()
+    {
+        // Radius-perpendicular at both ends is what makes a common tangent.
+        let touches = |circle: &Circle, p: &Point, q: &Point| {
+            let (rx, ry) = (p.x - circle.center.x, p.y - circle.center.y);
+            ((rx * rx + ry * ry).sqrt() - circle.radius).abs() < 1e-9 && (rx * (q.x - p.x) + ry * (q.y - p.y)).abs() < 1e-9
+        };
+        let a = Circle::new(Point::new(0.0, 0.0), 1.0);
+        let cases = [(Circle::new(Point::new(5.0, 0.0), 2.0), 4), (Circle::new(Point::new(3.0, 0.0), 2.0), 3), (Circle::new(Point::new(2.0, 0.0), 2.0), 2), (Circle::new(Point::new(1.0, 0.0), 2.0), 1)];
+        for (b, count) in cases
+        {
+            let tangents = a.common_tangents(&b);
+            assert_eq!(tangents.len(), count, "{:?}", b);
+            for t in &tangents
+            {
+                assert!(touches(&a, &t.start, &t.end) && touches(&b, &t.end, &t.start), "{:?}", t);
+            }
+        }
+        // The outer pair comes first, with the tangency points on the same side.
+        let outer = a.common_tangents(&Circle::new(Point::new(5.0, 0.0), 1.0));
+        assert_near(&outer[0].start, 0.0, 1.0);
+        assert_near(&outer[0].end, 5.0, 1.0);
+        assert_near(&outer[1].start, 0.0, -1.0);
+        assert!(a.common_tangents(&Circle::new(Point::new(0.0, 0.0), 2.0)).is_empty());
+        assert!(a.common_tangents(&Circle::new(Point::new(0.5, 0.0), 3.0)).is_empty());
+    }
+
+    #[test]
+    fn test_clip_line()
+    {
+        let rect = Rectangle::new(Point::new(0.0, 0.0), 4.0, 2.0);
+        let clipped = rect.clip_line(&segment(-2.0, 1.0, 6.0, 1.0)).unwrap();
+        assert_eq!((clipped.start, clipped.end), (Point::new(0.0, 1.0), Point::new(4.0, 1.0)));
+        let diagonal = rect.clip_line(&segment(-1.0, -1.0, 3.0, 3.0)).unwrap();
+        assert_eq!((diagonal.start, diagonal.end), (Point::new(0.0, 0.0), Point::new(2.0, 2.0)));
+        // Inside segments come back untouched, and edges count as inside.
+        let inside = rect.clip_line(&segment(1.0, 0.5, 3.0, 1.5)).unwrap();
+        assert_eq!((inside.start, inside.end), (Point::new(1.0, 0.5), Point::new(3.0, 1.5)));
+        let edge = rect.clip_line(&segment(-1.0, 2.0, 1.0, 2.0)).unwrap();
+        assert_eq!((edge.start, edge.end), (Point::new(0.0, 2.0), Point::new(1.0, 2.0)));
+        assert!(rect.clip_line(&segment(-1.0, 3.0, 5.0, 3.0)).is_none());
+        assert!(rect.clip_line(&segment(3.0, -2.0, 6.0, 1.0)).is_none());
+        assert!(rect.clip_line(&segment(5.0, 1.0, 5.0, 1.0)).is_none());
+    }
+}
@@ -77,4 +77,38 @@ mod tests
         assert!((tan(0.0) - 0.0).abs() < EPSILON);
         assert!((tan(std::f32::consts::PI / 4.0) - 1.0).abs() < EPSILON);
     }
+
+    #[test]
+    fn test_sqrt()
+    {
+        assert_eq!(sqrt(9.0), 3.0);
+        assert_eq!(sqrt_f64(16.0), 4.0);
+    }
+
+    #[test]
+    fn test_inverse_trig()
+    {
+        assert!((acos(0.0) - PI / 2.0).abs() < EPSILON);
+        assert!((asin(1.0) - PI / 2.0).abs() < EPSILON);
+        assert!((atan(1.0) - PI / 4.0).abs() < EPSILON);
+        assert!((atan2(1.0, -1.0) - 3.0 * PI / 4.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_exp_ln_pow()
+    {
+        assert!((exp(1.0) - E).abs() < EPSILON);
+        assert!((ln(E) - 1.0).abs() < EPSILON);
+        assert!((powf(2.0, 10.0) - 1024.0).abs() < EPSILON);
+        assert_eq!(hypot(3.0, 4.0), 5.0);
+    }
+
+    #[test]
+    fn test_rounding()
+    {
+        assert_eq!(floor(-1.5), -2.0);
+        assert_eq!(ceil(-1.5), -1.0);
+        assert_eq!(round(2.5), 3.0);
+        assert_eq!(trunc(-2.7), -2.0);
+    }
 }
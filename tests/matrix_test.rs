@@ -1,4 +1,9 @@
-use vectoria::matrix::Mat4;
+mod common;
+
+use vectoria::matrix::{ Mat3, Mat4 };
+use vectoria::vector::Vec3;
+use vectoria::VectoriaError;
+use common::assert_vec3_close;
 #[cfg(test)]
 mod tests
 {
@@ -66,4 +71,166 @@ mod tests
         let inv = mat.invert().unwrap();
         assert_eq!(inv.data, mat.data);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_transform_point()
+    {
+        let mat = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::scale(2.0, 2.0, 2.0));
+        let result = mat.transform_point(&Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(result, Vec3::new(3.0, 4.0, 5.0));
+        let result = mat.transform_vector(&Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(result, Vec3::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_transform_points_batch()
+    {
+        let mat = Mat4::translate(1.0, -2.0, 0.5).mul(&Mat4::rotate_y(0.7)).mul(&Mat4::scale(1.0, 2.0, 3.0));
+        for len in 0..7
+        {
+            let points: Vec<Vec3> = (0..len).map(|i| Vec3::new(i as f32, 1.0 - i as f32, 0.25)).collect();
+            let mut out = vec![Vec3::new(0.0, 0.0, 0.0); len];
+            mat.transform_points(&points, &mut out).unwrap();
+            for i in 0..len
+            {
+                assert_vec3_close(&out[i], &mat.transform_point(&points[i]));
+            }
+            mat.transform_vectors(&points, &mut out).unwrap();
+            for i in 0..len
+            {
+                assert_vec3_close(&out[i], &mat.transform_vector(&points[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_batch()
+    {
+        let a = vec![Mat4::rotate_x(0.3), Mat4::translate(1.0, 2.0, 3.0), Mat4::scale(2.0, 3.0, 4.0)];
+        let b = vec![Mat4::translate(-1.0, 0.0, 2.0), Mat4::rotate_z(1.1), Mat4::rotate_y(-0.4)];
+        let mut out = vec![Mat4::new_zero(); 3];
+        Mat4::mul_batch(&a, &b, &mut out).unwrap();
+        for i in 0..3
+        {
+            let expected = a[i].mul(&b[i]);
+            for r in 0..4
+            {
+                for c in 0..4
+                {
+                    assert!((out[i].data[r][c] - expected.data[r][c]).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    // Small xorshift generator so the property tests are reproducible without extra dependencies.
+    struct Rng(u32);
+
+    impl Rng
+    {
+        fn next_f32(&mut self) -> f32
+        {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 17;
+            self.0 ^= self.0 << 5;
+            (self.0 as f32 / u32::MAX as f32) * 200.0 - 100.0
+        }
+
+        fn mat3(&mut self) -> Mat3
+        {
+            let mut m = Mat3::new_zero();
+            for row in m.data.iter_mut()
+            {
+                for value in row.iter_mut()
+                {
+                    *value = self.next_f32();
+                }
+            }
+            m
+        }
+    }
+
+    #[test]
+    fn test_mat3_mul_auto_matches_mul()
+    {
+        let mut rng = Rng(0x9e3779b9);
+        for _ in 0..1000
+        {
+            let a = rng.mat3();
+            let b = rng.mat3();
+            // Both paths accumulate the same products in the same order, so they agree exactly.
+            assert_eq!(a.mul_auto(&b), a.mul(&b), "{:?} * {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_mat3_mul_auto_identity()
+    {
+        let mut rng = Rng(12345);
+        for _ in 0..100
+        {
+            let a = rng.mat3();
+            assert_eq!(a.mul_auto(&Mat3::new_identity()), a);
+            assert_eq!(Mat3::new_identity().mul_auto(&a), a);
+        }
+    }
+
+    #[test]
+    fn test_invert_singular()
+    {
+        assert_eq!(Mat4::new_zero().invert(), Err(VectoriaError::Singular));
+        assert_eq!(Mat3::new_zero().invert(), Err(VectoriaError::Singular));
+        let mut mat = Mat4::new_identity();
+        mat.data[0][0] = f32::NAN;
+        assert_eq!(mat.invert(), Err(VectoriaError::NotFinite));
+    }
+
+    #[test]
+    fn test_invert_roundtrip()
+    {
+        let mat = Mat4::translate(1.0, 2.0, 3.0).mul(&Mat4::rotate_x(0.5)).mul(&Mat4::scale(2.0, 1.0, 0.5));
+        let product = mat.mul(&mat.invert().unwrap());
+        let identity = Mat4::new_identity();
+        for i in 0..4
+        {
+            for j in 0..4
+            {
+                assert!((product.data[i][j] - identity.data[i][j]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_mismatch()
+    {
+        let mut out = vec![Mat4::new_zero(); 1];
+        let result = Mat4::mul_batch(&[Mat4::new_identity()], &[], &mut out);
+        assert_eq!(result, Err(VectoriaError::DimensionMismatch { expected: 1, found: 0 }));
+    }
+
+    #[test]
+    fn test_mat3_orthonormalize()
+    {
+        let m = Mat3 { data: [[1.0, 1.0, 0.3], [0.0, 1.0, 2.0], [0.2, 0.0, 1.0]] };
+        let q = m.orthonormalize().unwrap();
+        for i in 0..3
+        {
+            for j in 0..3
+            {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((q.column(i).dot(&q.column(j)) - expected).abs() < 1e-5);
+            }
+        }
+        let first = m.column(0).normalize();
+        assert!(q.column(0).sub(&first).length() < 1e-6);
+
+        let dependent = Mat3::from_columns(&Vec3::new(1.0, 0.0, 0.0), &Vec3::new(2.0, 0.0, 0.0), &Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(dependent.orthonormalize(), Err(VectoriaError::Singular));
+
+        // Dependent only up to rounding, and nearly dependent: the residue is not a direction.
+        let rounded = Mat3::from_columns(&Vec3::new(0.1, 0.2, 0.3), &Vec3::new(0.2, 0.4, 0.6), &Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(rounded.orthonormalize(), Err(VectoriaError::Singular));
+        let nearly = Mat3::from_columns(&Vec3::new(1.0, 1.0, 0.0), &Vec3::new(1.0000001, 1.0, 0.0), &Vec3::new(0.0, 0.0, 1.0));
+        assert_eq!(nearly.orthonormalize(), Err(VectoriaError::Singular));
+    }
+}
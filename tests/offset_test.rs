@@ -0,0 +1,111 @@
+mod common;
+
+use vectoria::geometry::offset::*;
+use vectoria::geometry::{ Point, Polygon };
+use common::square;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn total_area(polygons: &[Polygon]) -> f64
+    {
+        polygons.iter().map(|p| p.area()).sum()
+    }
+
+    #[test]
+    fn test_miter_outward()
+    {
+        let polygon = Polygon::new(square(0.0, 0.0, 2.0));
+        let grown = polygon.offset(1.0, JoinType::Miter { limit: 2.0 });
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].exterior.len(), 4);
+        assert!((total_area(&grown) - 16.0).abs() < 1e-9);
+        assert!(grown[0].contains(&Point::new(-1.0, -1.0)));
+
+        // A right angle needs a miter of sqrt(2), so a tighter limit squares it off.
+        let squared = polygon.offset(1.0, JoinType::Miter { limit: 1.2 });
+        assert_eq!(squared[0].exterior.len(), 8);
+        assert!(!squared[0].contains(&Point::new(-0.9, -0.9)));
+    }
+
+    #[test]
+    fn test_round_and_square_outward()
+    {
+        let polygon = Polygon::new(square(0.0, 0.0, 2.0));
+        let round = total_area(&polygon.offset(1.0, JoinType::Round { tolerance: 1e-4 }));
+        let exact = 4.0 + 8.0 + core::f64::consts::PI;
+        assert!(round < exact && exact - round < 1e-3, "{}", round);
+
+        let square_join = polygon.offset(1.0, JoinType::Square);
+        let area = total_area(&square_join);
+        assert!(area > round && area < 16.0);
+        // Each corner is cut at distance 1 from the vertex.
+        assert!((area - (16.0 - 4.0 * (1.0 - core::f64::consts::FRAC_1_SQRT_2) * (2.0 - 2.0f64.sqrt()))).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inward()
+    {
+        let polygon = Polygon::new(square(0.0, 0.0, 4.0));
+        let shrunk = polygon.offset(-1.0, JoinType::Miter { limit: 2.0 });
+        assert_eq!(shrunk.len(), 1);
+        assert!((total_area(&shrunk) - 4.0).abs() < 1e-9);
+        assert!(shrunk[0].contains(&Point::new(1.0, 1.0)));
+        assert!(!shrunk[0].contains(&Point::new(0.9, 2.0)));
+
+        // Convex corners stay sharp when shrinking, whatever the join.
+        let round = polygon.offset(-1.0, JoinType::Round { tolerance: 1e-3 });
+        assert!((total_area(&round) - 4.0).abs() < 1e-9);
+
+        // Shrinking past the half width leaves nothing.
+        assert!(polygon.offset(-2.5, JoinType::Square).is_empty());
+        assert_eq!(polygon.offset(0.0, JoinType::Square)[0], polygon);
+    }
+
+    #[test]
+    fn test_reflex_corners()
+    {
+        // L shape: the reflex corner at (1, 1) gets the join when shrinking, not growing.
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(3.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ]);
+        let grown = l_shape.offset(0.5, JoinType::Miter { limit: 2.0 });
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].exterior.len(), 6);
+        assert!((total_area(&grown) - (4.0 * 4.0 - 2.0 * 2.0)).abs() < 1e-9);
+
+        let shrunk = l_shape.offset(-0.25, JoinType::Round { tolerance: 1e-4 });
+        assert_eq!(shrunk.len(), 1);
+        // Two overlapping arms of width 0.5, plus the part of the inner corner square that
+        // is at least 0.25 from the reflex vertex.
+        let arms = 2.5 * 0.5 * 2.0 - 0.5 * 0.5;
+        let corner = 0.25 * 0.25 * (1.0 - core::f64::consts::PI / 4.0);
+        assert!((total_area(&shrunk) - (arms + corner)).abs() < 1e-4, "{}", total_area(&shrunk));
+    }
+
+    #[test]
+    fn test_holes()
+    {
+        let frame = Polygon::with_holes(square(0.0, 0.0, 6.0), vec![square(2.0, 2.0, 2.0)]);
+        let grown = frame.offset(0.5, JoinType::Miter { limit: 2.0 });
+        assert_eq!(grown.len(), 1);
+        assert_eq!(grown[0].holes.len(), 1);
+        assert!((total_area(&grown) - (49.0 - 1.0)).abs() < 1e-9);
+
+        // Growing by the hole's half width closes it.
+        let closed = frame.offset(1.0, JoinType::Miter { limit: 2.0 });
+        assert!(closed[0].holes.is_empty());
+        assert!((total_area(&closed) - 64.0).abs() < 1e-9);
+
+        let shrunk = frame.offset(-0.5, JoinType::Miter { limit: 2.0 });
+        assert_eq!(shrunk[0].holes.len(), 1);
+        assert!((total_area(&shrunk) - (25.0 - 9.0)).abs() < 1e-9);
+    }
+}
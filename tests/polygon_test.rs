@@ -0,0 +1,201 @@
+mod common;
+
+use vectoria::geometry::polygon::*;
+use vectoria::geometry::{ Point, Shape2D, Winding };
+use common::square;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn assert_point_close(a: &Point, b: &Point)
+    {
+        assert!(a.distance(b) < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_area_perimeter_orientation()
+    {
+        let polygon = Polygon::new(square(0.0, 0.0, 4.0));
+        assert_eq!(polygon.area(), 16.0);
+        assert_eq!(polygon.perimeter(), 16.0);
+        assert_eq!(polygon.orientation(), Winding::CounterClockwise);
+        assert_eq!(ring_signed_area(&polygon.exterior), 16.0);
+
+        let mut reversed = polygon.clone();
+        reversed.exterior.reverse();
+        assert_eq!(reversed.orientation(), Winding::Clockwise);
+        assert_eq!(reversed.area(), 16.0);
+
+        let mut holed = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        assert_eq!(holed.area(), 12.0);
+        assert_eq!(holed.perimeter(), 24.0);
+        holed.normalize_orientation();
+        assert!(ring_signed_area(&holed.holes[0]) < 0.0);
+        assert_eq!(holed.area(), 12.0);
+    }
+
+    #[test]
+    fn test_centroid()
+    {
+        let polygon = Polygon::new(square(2.0, 2.0, 2.0));
+        assert_point_close(&polygon.centroid().unwrap(), &Point::new(3.0, 3.0));
+
+        // An L shape: two unit squares side by side plus one on top of the left one.
+        let l_shape = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]);
+        assert_point_close(&l_shape.centroid().unwrap(), &Point::new(5.0 / 6.0, 5.0 / 6.0));
+
+        // The hole sits off-center, pulling the centroid to the right.
+        let holed = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(0.0, 0.0, 2.0)]);
+        assert_point_close(&holed.centroid().unwrap(), &Point::new(7.0 / 3.0, 7.0 / 3.0));
+
+        let flat = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(2.0, 0.0)]);
+        assert_eq!(flat.centroid(), None);
+    }
+
+    #[test]
+    fn test_convexity()
+    {
+        assert!(Polygon::new(square(0.0, 0.0, 1.0)).is_convex());
+        let mut clockwise = square(0.0, 0.0, 1.0);
+        clockwise.reverse();
+        assert!(Polygon::new(clockwise).is_convex());
+
+        let with_collinear = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 1.0)];
+        assert!(Polygon::new(with_collinear).is_convex());
+
+        let dart = vec![Point::new(0.0, 0.0), Point::new(2.0, 1.0), Point::new(4.0, 0.0), Point::new(2.0, 3.0)];
+        assert!(!Polygon::new(dart).is_convex());
+
+        // A pentagram turns the same way at every vertex but winds twice.
+        let star: Vec<Point> = (0..5)
+            .map(|i| {
+                let angle = (i * 2 % 5) as f64 * std::f64::consts::TAU / 5.0;
+                Point::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert!(!Polygon::new(star).is_convex());
+        assert!(!Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 1.0)]).is_convex());
+    }
+
+    #[test]
+    fn test_point_in_polygon()
+    {
+        let holed = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        assert!(holed.contains(&Point::new(0.5, 0.5)));
+        assert!(!holed.contains(&Point::new(2.0, 2.0)));
+        assert!(!holed.contains(&Point::new(5.0, 2.0)));
+        assert_eq!(holed.winding_number(&Point::new(0.5, 2.0)), 1);
+        assert_eq!(holed.winding_number(&Point::new(2.0, 2.0)), 0);
+
+        // Boundary points, including vertices and hole edges, are inside.
+        assert!(holed.contains(&Point::new(4.0, 2.0)));
+        assert!(holed.contains(&Point::new(0.0, 0.0)));
+        assert!(holed.contains(&Point::new(1.0, 2.0)));
+
+        // A ray through a vertex must not double count.
+        let diamond = Polygon::new(vec![Point::new(0.0, -1.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0), Point::new(-1.0, 0.0)]);
+        assert!(diamond.contains(&Point::new(0.0, 0.0)));
+        assert!(!diamond.contains(&Point::new(-2.0, 0.0)));
+        assert_eq!(ring_winding_number(&diamond.exterior, &Point::new(0.5, 0.0)), 1);
+
+        let mut clockwise = diamond.clone();
+        clockwise.exterior.reverse();
+        assert_eq!(clockwise.winding_number(&Point::new(0.0, 0.0)), -1);
+    }
+
+    #[test]
+    fn test_self_intersections()
+    {
+        let polygon = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        assert!(polygon.is_simple());
+
+        let bowtie = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0), Point::new(2.0, 0.0), Point::new(0.0, 2.0)]);
+        assert_eq!(bowtie.self_intersections(), vec![(0, 2)]);
+
+        // A hole poking out of the exterior.
+        let leaky = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(3.0, 1.0, 2.0)]);
+        assert!(!leaky.is_simple());
+
+        // A spike that doubles back along its own edge.
+        let spike = Polygon::new(vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0)]);
+        assert!(spike.self_intersections().contains(&(0, 1)));
+    }
+
+    #[test]
+    fn test_shape2d_for_polygon()
+    {
+        let polygon = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        let bounds = polygon.bounding_box();
+        assert_eq!((bounds.top_left, bounds.width, bounds.height), (Point::new(0.0, 0.0), 4.0, 4.0));
+        assert!((polygon.bounding_circle().radius - 8.0f64.sqrt()).abs() < 1e-12);
+        assert_eq!(polygon.signed_distance(&Point::new(0.5, 2.0)), -0.5);
+        assert_eq!(polygon.signed_distance(&Point::new(2.0, 2.0)), 1.0);
+        assert_eq!(polygon.signed_distance(&Point::new(6.0, 2.0)), 2.0);
+        assert_point_close(&Shape2D::closest_point(&polygon, &Point::new(1.5, 2.0)), &Point::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_polyline_resample()
+    {
+        let polyline = Polyline::new(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(3.0, 2.0)]);
+        assert_eq!(polyline.length(), 5.0);
+        assert_point_close(&polyline.point_at(4.0).unwrap(), &Point::new(3.0, 1.0));
+        assert_point_close(&polyline.point_at(10.0).unwrap(), &Point::new(3.0, 2.0));
+
+        let resampled = polyline.resample(2.0);
+        let expected = [Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(3.0, 1.0), Point::new(3.0, 2.0)];
+        assert_eq!(resampled.points.len(), expected.len());
+        for (a, b) in resampled.points.iter().zip(expected.iter())
+        {
+            assert_point_close(a, b);
+        }
+
+        let exact = polyline.resample(1.0);
+        assert_eq!(exact.points.len(), 6);
+        assert_point_close(&exact.points[5], &Point::new(3.0, 2.0));
+    }
+
+    #[test]
+    fn test_polyline_simplify()
+    {
+        let points = vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.1),
+            Point::new(2.0, -0.1),
+            Point::new(3.0, 5.0),
+            Point::new(4.0, 6.0),
+            Point::new(5.0, 7.0),
+            Point::new(6.0, 8.1),
+            Point::new(7.0, 9.0),
+        ];
+        let polyline = Polyline::new(points);
+
+        let simplified = polyline.simplify_douglas_peucker(0.5);
+        assert_eq!(simplified.points, vec![Point::new(0.0, 0.0), Point::new(2.0, -0.1), Point::new(3.0, 5.0), Point::new(7.0, 9.0)]);
+        // Only the exactly collinear (4, 6) is dropped at zero tolerance.
+        assert_eq!(polyline.simplify_douglas_peucker(0.0).points.len(), 7);
+
+        let simplified = polyline.simplify_visvalingam(0.5);
+        assert_eq!(simplified.points, vec![Point::new(0.0, 0.0), Point::new(2.0, -0.1), Point::new(3.0, 5.0), Point::new(7.0, 9.0)]);
+        assert_eq!(polyline.simplify_visvalingam(1e9).points, vec![Point::new(0.0, 0.0), Point::new(7.0, 9.0)]);
+    }
+
+    #[test]
+    fn test_polyline_self_intersections()
+    {
+        let zigzag = Polyline::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 0.0)]);
+        assert!(zigzag.self_intersections().is_empty());
+
+        let crossing = Polyline::new(vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0), Point::new(2.0, 0.0), Point::new(0.0, 2.0)]);
+        assert_eq!(crossing.self_intersections(), vec![(0, 2)]);
+    }
+}
@@ -0,0 +1,115 @@
+use vectoria::geometry::predicates::*;
+use vectoria::geometry::{ Point, Point3D, Triangle, Winding };
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const ULP: f64 = f64::EPSILON / 2.0;
+
+    fn sign(x: f64) -> i32
+    {
+        if x > 0.0 { 1 } else if x < 0.0 { -1 } else { 0 }
+    }
+
+    #[test]
+    fn test_orient2d()
+    {
+        let (a, b) = (Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+        assert_eq!(orient2d(&a, &b, &Point::new(0.5, 1.0)), 1.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(0.5, -1.0)), -1.0);
+        assert_eq!(orient2d(&a, &b, &Point::new(7.0, 0.0)), 0.0);
+
+        // Points a few ulps around (0.5, 0.5) against the line y = x, where plain f64
+        // evaluation returns a noisy pattern of signs. On the integer grid scaled by 2^53
+        // the exact sign is `j - i`.
+        let (q, r) = (Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+        for i in 0..64i32
+        {
+            for j in 0..64
+            {
+                let p = Point::new(0.5 + i as f64 * ULP, 0.5 + j as f64 * ULP);
+                assert_eq!(sign(orient2d(&p, &q, &r)), (j - i).signum(), "{} {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_orient3d()
+    {
+        let (a, b, c) = (Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0), Point3D::new(0.0, 1.0, 0.0));
+        // Below the counter-clockwise triangle is positive.
+        assert!(orient3d(&a, &b, &c, &Point3D::new(0.2, 0.2, -1.0)) > 0.0);
+        assert!(orient3d(&a, &b, &c, &Point3D::new(0.2, 0.2, 1.0)) < 0.0);
+        assert_eq!(orient3d(&a, &b, &c, &Point3D::new(5.0, -3.0, 0.0)), 0.0);
+
+        // The plane x = y through the z axis; the exact value is `y - x`.
+        let (b, c) = (Point3D::new(1.0, 1.0, 0.0), Point3D::new(0.0, 0.0, 1.0));
+        for i in 0..32i32
+        {
+            for j in 0..32
+            {
+                let d = Point3D::new(0.5 + i as f64 * ULP, 0.5 + j as f64 * ULP, 7.3 + (i * j) as f64 * 0.1);
+                assert_eq!(sign(orient3d(&a, &b, &c, &d)), (j - i).signum(), "{} {}", i, j);
+                assert_eq!(sign(orient3d(&b, &a, &c, &d)), (i - j).signum());
+            }
+        }
+    }
+
+    #[test]
+    fn test_incircle()
+    {
+        let (a, b, c) = (Point::new(1.0, 0.0), Point::new(0.0, 1.0), Point::new(-1.0, 0.0));
+        assert!(incircle(&a, &b, &c, &Point::new(0.0, 0.0)) > 0.0);
+        assert!(incircle(&a, &b, &c, &Point::new(2.0, 2.0)) < 0.0);
+        assert_eq!(incircle(&a, &b, &c, &Point::new(0.0, -1.0)), 0.0);
+        assert!(incircle(&a, &c, &b, &Point::new(0.0, 0.0)) < 0.0);
+
+        // Around the bottom of the unit circle, `(i u, j u - 1)` with `u = 2^-52` is inside
+        // exactly when `(i^2 + j^2) u < 2 j`, which for small `i, j` means `j > 0`, and on
+        // the circle only at `i = j = 0`.
+        let u = f64::EPSILON;
+        for i in 0..32i32
+        {
+            for j in 0..32
+            {
+                let d = Point::new(i as f64 * u, j as f64 * u - 1.0);
+                let expected = if j > 0 { 1 } else if i == 0 { 0 } else { -1 };
+                assert_eq!(sign(incircle(&a, &b, &c, &d)), expected, "{} {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_insphere()
+    {
+        // `a, b, c` appear clockwise from `d`, as `insphere` expects.
+        let (a, b, c, d) = (Point3D::new(0.0, 1.0, 0.0), Point3D::new(1.0, 0.0, 0.0), Point3D::new(-1.0, 0.0, 0.0), Point3D::new(0.0, 0.0, 1.0));
+        assert!(orient3d(&a, &b, &c, &d) > 0.0);
+        assert!(insphere(&a, &b, &c, &d, &Point3D::new(0.0, 0.0, 0.0)) > 0.0);
+        assert!(insphere(&a, &b, &c, &d, &Point3D::new(0.0, 2.0, 0.0)) < 0.0);
+        assert_eq!(insphere(&a, &b, &c, &d, &Point3D::new(0.0, -1.0, 0.0)), 0.0);
+
+        // Same boundary test as for the circle, at the bottom of the unit sphere.
+        let u = f64::EPSILON;
+        for i in 0..16
+        {
+            for j in 0..16
+            {
+                let e = Point3D::new(i as f64 * u, (i * j) as f64 * u, j as f64 * u - 1.0);
+                let expected = if j > 0 { 1 } else if i == 0 { 0 } else { -1 };
+                assert_eq!(sign(insphere(&a, &b, &c, &d, &e)), expected, "{} {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangle_winding()
+    {
+        // Nearly collinear triangles still get an exact winding.
+        let thin = Triangle::new(Point::new(0.5, 0.5), Point::new(12.0, 12.0), Point::new(24.0, 24.0 + 8.0 * f64::EPSILON * 24.0));
+        assert_eq!(thin.winding(), Winding::CounterClockwise);
+        assert_eq!(Triangle::new(Point::new(0.5, 0.5), Point::new(12.0, 12.0), Point::new(24.0, 24.0)).winding(), Winding::Collinear);
+    }
+}
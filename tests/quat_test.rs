@@ -0,0 +1,53 @@
+mod common;
+
+use vectoria::matrix::Mat3;
+use vectoria::quat::*;
+use vectoria::vector::Vec3;
+use common::assert_vec3_close;
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_rotate_matches_matrix()
+    {
+        let q = Quat::from_axis_angle(&Vec3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        assert_vec3_close(&q.rotate(&Vec3::new(1.0, 0.0, 0.0)), &Vec3::new(0.0, 1.0, 0.0));
+        let m = q.to_mat3();
+        let r = Mat3::rotate_z(FRAC_PI_2);
+        for i in 0..3
+        {
+            for j in 0..3
+            {
+                assert!((m.data[i][j] - r.data[i][j]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mat3_roundtrip()
+    {
+        let axes = [Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.5, 0.0), Vec3::new(0.0, 0.0, -1.0)];
+        for axis in axes.iter()
+        {
+            for &angle in [0.3, 1.5, 3.0, -2.8].iter()
+            {
+                let q = Quat::from_axis_angle(axis, angle);
+                let back = Quat::from_mat3(&q.to_mat3());
+                assert!((back.dot(&q).abs() - 1.0).abs() < 1e-5, "{:?} != {:?}", back, q);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_composes()
+    {
+        let a = Quat::from_axis_angle(&Vec3::new(1.0, 0.0, 0.0), 0.4);
+        let b = Quat::from_axis_angle(&Vec3::new(0.0, 1.0, 0.0), -1.1);
+        let v = Vec3::new(0.3, -2.0, 1.0);
+        assert_vec3_close(&a.mul(&b).rotate(&v), &a.rotate(&b.rotate(&v)));
+        assert_vec3_close(&a.mul(&a.conjugate()).vector(), &Vec3::new(0.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use vectoria::matrix::Mat3;
+use vectoria::rotor::*;
+use vectoria::vector::{ Vec2, Vec3 };
+use common::assert_vec3_close;
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::f32::consts::{ FRAC_PI_2, PI };
+
+    fn assert_mat3_close(a: &Mat3, b: &Mat3)
+    {
+        for i in 0..3
+        {
+            for j in 0..3
+            {
+                assert!((a.data[i][j] - b.data[i][j]).abs() < 1e-5, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wedge_and_geometric_product()
+    {
+        let u = Vec3::new(1.0, 2.0, 3.0);
+        let v = Vec3::new(4.0, 5.0, 6.0);
+        assert_eq!(u.wedge(&v), Bivector3::new(-3.0, -6.0, -3.0));
+        assert_eq!(v.wedge(&u), Bivector3::new(3.0, 6.0, 3.0));
+        let g = u.geometric_product(&v);
+        assert_eq!(g.a, 32.0);
+        assert_eq!(g.b, u.wedge(&v));
+        let g2 = Vec2::new(1.0, 0.0).geometric_product(&Vec2::new(0.0, 2.0));
+        assert_eq!(g2, Rotor2::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn test_rotor3_plane_angle_matches_matrices()
+    {
+        let xy = Vec3::new(1.0, 0.0, 0.0).wedge(&Vec3::new(0.0, 1.0, 0.0));
+        let yz = Vec3::new(0.0, 1.0, 0.0).wedge(&Vec3::new(0.0, 0.0, 1.0));
+        let zx = Vec3::new(0.0, 0.0, 1.0).wedge(&Vec3::new(1.0, 0.0, 0.0));
+        assert_mat3_close(&Rotor3::from_plane_angle(&xy, 0.7).to_mat3(), &Mat3::rotate_z(0.7));
+        assert_mat3_close(&Rotor3::from_plane_angle(&yz, -1.2).to_mat3(), &Mat3::rotate_x(-1.2));
+        assert_mat3_close(&Rotor3::from_plane_angle(&zx, 2.0).to_mat3(), &Mat3::rotate_y(2.0));
+    }
+
+    #[test]
+    fn test_rotor3_from_vectors()
+    {
+        let pairs = [
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)),
+            (Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, -3.0)),
+            (Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, 2.0, 0.0)),
+        ];
+        for (from, to) in pairs.iter()
+        {
+            let r = Rotor3::from_vectors(from, to);
+            assert!((r.length() - 1.0).abs() < 1e-5);
+            assert_vec3_close(&r.rotate(&from.normalize()), &to.normalize());
+        }
+    }
+
+    #[test]
+    fn test_rotor3_composition()
+    {
+        let a = Rotor3::from_plane_angle(&Bivector3::new(1.0, 0.5, -0.2), 0.9);
+        let b = Rotor3::from_plane_angle(&Bivector3::new(0.0, 1.0, 1.0), -0.4);
+        let v = Vec3::new(0.3, -1.0, 2.0);
+        assert_vec3_close(&a.mul(&b).rotate(&v), &a.rotate(&b.rotate(&v)));
+        assert_mat3_close(&a.mul(&b).to_mat3(), &a.to_mat3().mul(&b.to_mat3()));
+        assert_vec3_close(&a.reverse().rotate(&a.rotate(&v)), &v);
+    }
+
+    #[test]
+    fn test_rotor3_slerp()
+    {
+        let plane = Bivector3::new(0.0, 0.0, 1.0);
+        let a = Rotor3::identity();
+        let b = Rotor3::from_plane_angle(&plane, FRAC_PI_2);
+        let mid = a.slerp(&b, 0.5);
+        let expected = Rotor3::from_plane_angle(&plane, FRAC_PI_2 / 2.0);
+        assert!((mid.dot(&expected) - 1.0).abs() < 1e-5);
+        assert!((a.slerp(&b, 1.0).dot(&b) - 1.0).abs() < 1e-5);
+        assert!((a.slerp(&b.mul_scale(-1.0), 0.5).dot(&expected).abs() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rotor2()
+    {
+        let r = Rotor2::from_angle(FRAC_PI_2);
+        let v = r.rotate(&Vec2::new(1.0, 0.0));
+        assert!(v.x.abs() < 1e-6 && (v.y - 1.0).abs() < 1e-6);
+        assert!((r.angle() - FRAC_PI_2).abs() < 1e-6);
+        assert_mat3_close(&Rotor2::from_angle(0.8).to_mat3(), &Mat3::rotate_z(0.8));
+
+        let composed = Rotor2::from_angle(0.3).mul(&Rotor2::from_angle(0.5));
+        assert!((composed.angle() - 0.8).abs() < 1e-6);
+
+        let from = Vec2::new(2.0, 0.0);
+        let to = Vec2::new(-1.0, -1.0);
+        let rotated = Rotor2::from_vectors(&from, &to).rotate(&from.unit());
+        assert!(rotated.sub(&to.unit()).length() < 1e-5);
+        let opposite = Rotor2::from_vectors(&from, &Vec2::new(-1.0, 0.0));
+        assert!((opposite.angle().abs() - PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rotor2_slerp()
+    {
+        let a = Rotor2::from_angle(0.2);
+        let b = Rotor2::from_angle(1.0);
+        assert!((a.slerp(&b, 0.25).angle() - 0.4).abs() < 1e-5);
+        let wrap = Rotor2::from_angle(3.0).slerp(&Rotor2::from_angle(-3.0), 0.5);
+        assert!((wrap.angle().abs() - PI).abs() < 1e-5);
+    }
+}
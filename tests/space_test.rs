@@ -0,0 +1,72 @@
+use vectoria::space::*;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use core::mem::size_of;
+    use vectoria::matrix::Mat4;
+    use vectoria::vector;
+
+    fn assert_close(a: &vector::Vec3, b: &vector::Vec3)
+    {
+        assert!(a.sub(b).length() < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_zero_cost()
+    {
+        assert_eq!(size_of::<Vec3<World>>(), size_of::<vector::Vec3>());
+        assert_eq!(size_of::<Point3<View>>(), size_of::<vector::Vec3>());
+        assert_eq!(size_of::<Transform<Object, World>>(), size_of::<Mat4>());
+    }
+
+    #[test]
+    fn test_point_vector_arithmetic()
+    {
+        let a: Point3<World> = Point3::new(1.0, 2.0, 3.0);
+        let b: Point3<World> = Point3::new(4.0, 6.0, 3.0);
+        let d = b.sub(&a);
+        assert_eq!(d, Vec3::new(3.0, 4.0, 0.0));
+        assert_eq!(d.length(), 5.0);
+        assert_eq!(a.add_vector(&d), b);
+        assert_eq!(b.sub_vector(&d), a);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(d.dot(&Vec3::new(1.0, 0.0, 0.0)), 3.0);
+    }
+
+    #[test]
+    fn test_compose_transforms()
+    {
+        let model: Transform<Object, World> = Transform::from_raw(Mat4::translate(1.0, 0.0, 0.0));
+        let view: Transform<World, View> = Transform::from_raw(Mat4::rotate_z(core::f32::consts::FRAC_PI_2));
+        let model_view = model.then(&view);
+        assert_eq!(model_view, view.mul(&model));
+
+        let p: Point3<Object> = Point3::origin();
+        let in_view: Point3<View> = model_view.transform_point(&p);
+        assert_close(in_view.raw(), &vector::Vec3::new(0.0, 1.0, 0.0));
+
+        let v: Vec3<Object> = Vec3::new(1.0, 0.0, 0.0);
+        let v_view: Vec3<View> = model_view.transform_vector(&v);
+        assert_close(v_view.raw(), &vector::Vec3::new(0.0, 1.0, 0.0));
+
+        let back: Transform<View, Object> = model_view.invert().unwrap();
+        assert_close(back.transform_point(&in_view).raw(), p.raw());
+
+        let identity: Transform<World, World> = Transform::identity();
+        assert_eq!(identity.then(&view), view);
+    }
+
+    #[test]
+    fn test_escape_hatches()
+    {
+        let v: Vec3<World> = Vec3::from_raw(vector::Vec3::new(1.0, 2.0, 3.0));
+        let raw: vector::Vec3 = v.into_raw();
+        assert_eq!(raw, vector::Vec3::new(1.0, 2.0, 3.0));
+        let relabeled: Vec3<View> = v.cast();
+        assert_eq!(relabeled.raw(), v.raw());
+        let t: Transform<World, View> = Transform::from_raw(Mat4::new_identity());
+        assert_eq!(t.into_raw(), Mat4::new_identity());
+    }
+}
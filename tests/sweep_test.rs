@@ -0,0 +1,133 @@
+mod common;
+
+use vectoria::geometry::sweep::*;
+use vectoria::geometry::{ Line, Point, SegmentIntersection };
+use common::Rng;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn segment(x1: f64, y1: f64, x2: f64, y2: f64) -> Line
+    {
+        Line::new(Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    // The sweep finds exactly the pairs the pairwise test does.
+    fn assert_matches_pairwise(segments: &[Line])
+    {
+        let mut found: Vec<(usize, usize)> = segment_intersections(segments).iter().flat_map(|p| p.pairs().collect::<Vec<_>>()).collect();
+        found.sort_unstable();
+        found.dedup();
+        let mut expected = Vec::new();
+        for i in 0..segments.len()
+        {
+            for j in i + 1..segments.len()
+            {
+                if segments[i].intersection(&segments[j]) != SegmentIntersection::Disjoint
+                {
+                    expected.push((i, j));
+                }
+            }
+        }
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_crossings()
+    {
+        let segments = [segment(0.0, 0.0, 4.0, 4.0), segment(0.0, 4.0, 4.0, 0.0), segment(0.0, 1.0, 4.0, 1.0), segment(5.0, 5.0, 6.0, 6.0)];
+        let points = segment_intersections(&segments);
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0], IntersectionPoint { point: Point::new(1.0, 1.0), segments: vec![0, 2] });
+        assert_eq!(points[1], IntersectionPoint { point: Point::new(2.0, 2.0), segments: vec![0, 1] });
+        assert_eq!(points[2], IntersectionPoint { point: Point::new(3.0, 1.0), segments: vec![1, 2] });
+        assert!(segment_intersections(&[]).is_empty());
+        assert!(segment_intersections(&segments[3..]).is_empty());
+    }
+
+    #[test]
+    fn test_degenerate_configurations()
+    {
+        // A vertical segment, a shared endpoint, a T junction and a collinear overlap.
+        let segments = [
+            segment(2.0, -1.0, 2.0, 3.0),
+            segment(0.0, 0.0, 4.0, 0.0),
+            segment(4.0, 0.0, 5.0, 2.0),
+            segment(2.0, 3.0, 1.0, 5.0),
+            segment(3.0, 0.0, 6.0, 0.0),
+        ];
+        let points = segment_intersections(&segments);
+        let summary: Vec<(Point, Vec<usize>)> = points.into_iter().map(|p| (p.point, p.segments)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Point::new(2.0, 0.0), vec![0, 1]),
+                (Point::new(2.0, 3.0), vec![0, 3]),
+                (Point::new(3.0, 0.0), vec![1, 4]),
+                (Point::new(4.0, 0.0), vec![1, 2, 4]),
+            ]
+        );
+        assert_matches_pairwise(&segments);
+    }
+
+    #[test]
+    fn test_rounded_order()
+    {
+        // The lower crossing rounds to just right of x = 3 and the upper one to exactly 3;
+        // the rounded points still come back in order.
+        let segments = [
+            segment(3.0, -1.0, 3.0, 2.0),
+            segment(0.8373773636912676, 0.40941737740735573, 5.8430574117859555, 0.48702066083085827),
+            segment(0.9283401865704937, 0.9937875301021116, 4.6102558067076815, 0.9373134546382764),
+        ];
+        let points = segment_intersections(&segments);
+        assert_eq!(points.len(), 2);
+        assert!((points[0].point.x, points[0].point.y) <= (points[1].point.x, points[1].point.y));
+    }
+
+    #[test]
+    fn test_concurrent_segments()
+    {
+        // Many segments crossing at one point are reported once, together.
+        let mut rng = Rng(17);
+        let segments: Vec<Line> = (0..24)
+            .map(|_| {
+                let (x, y) = (rng.next() * 2.0 - 1.0, rng.next() * 2.0 - 1.0);
+                segment(-x, -y, x, y)
+            })
+            .collect();
+        let points = segment_intersections(&segments);
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].point, Point::new(0.0, 0.0));
+        assert_eq!(points[0].segments, (0..24).collect::<Vec<_>>());
+        assert_eq!(points[0].pairs().count(), 24 * 23 / 2);
+    }
+
+    #[test]
+    fn test_random_segments()
+    {
+        let mut rng = Rng(0x2545f4914f6cdd1d);
+        for round in 0..200
+        {
+            let segments: Vec<Line> = (0..30)
+                .map(|_| {
+                    if round % 2 == 0
+                    {
+                        segment(rng.next(), rng.next(), rng.next(), rng.next())
+                    }
+                    else
+                    {
+                        // Small integer coordinates, full of collinear and shared points.
+                        let mut coordinate = || (rng.next() * 6.0).floor();
+                        segment(coordinate(), coordinate(), coordinate(), coordinate())
+                    }
+                })
+                .collect();
+            assert_matches_pairwise(&segments);
+            let points = segment_intersections(&segments);
+            assert!(points.windows(2).all(|w| (w[0].point.x, w[0].point.y) <= (w[1].point.x, w[1].point.y)));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+mod common;
+
+use vectoria::geometry::triangulation::*;
+use vectoria::geometry::{ Point, Polygon, Triangle };
+use common::square;
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    // Every triangle is counter-clockwise, lies inside the polygon, and together they
+    // cover its area exactly once.
+    fn assert_valid(polygon: &Polygon, indices: &[[usize; 3]])
+    {
+        let triangles = triangles_from_indices(&polygon.vertices(), indices);
+        let mut total = 0.0;
+        for triangle in &triangles
+        {
+            assert!(triangle.signed_area() > 0.0, "{:?}", triangle);
+            assert!(polygon.contains(&triangle.centroid()), "{:?}", triangle);
+            total += triangle.signed_area();
+        }
+        assert!((total - polygon.area()).abs() < 1e-9, "area {} != {}", total, polygon.area());
+    }
+
+    // No vertex lies strictly inside the circumcircle of a triangle across an
+    // unconstrained edge.
+    fn assert_locally_delaunay(polygon: &Polygon, indices: &[[usize; 3]])
+    {
+        let vertices = polygon.vertices();
+        for (i, a) in indices.iter().enumerate()
+        {
+            for b in &indices[i + 1..]
+            {
+                let shared = a.iter().filter(|v| b.contains(v)).count();
+                if shared != 2
+                {
+                    continue;
+                }
+                let apex = *b.iter().find(|v| !a.contains(v)).unwrap();
+                let circle = Triangle::new(vertices[a[0]], vertices[a[1]], vertices[a[2]]).circumcircle().unwrap();
+                assert!(circle.center.distance(&vertices[apex]) >= circle.radius - 1e-9, "{:?} {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convex()
+    {
+        let polygon = Polygon::new(square(0.0, 0.0, 2.0));
+        let indices = polygon.triangulate();
+        assert_eq!(indices.len(), 2);
+        assert_valid(&polygon, &indices);
+
+        let mut clockwise = polygon.clone();
+        clockwise.exterior.reverse();
+        assert_valid(&clockwise, &clockwise.triangulate());
+        assert!(Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0)]).triangulate().is_empty());
+    }
+
+    #[test]
+    fn test_concave()
+    {
+        // A comb with three teeth.
+        let comb = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(5.0, 0.0),
+            Point::new(5.0, 3.0),
+            Point::new(4.0, 3.0),
+            Point::new(4.0, 1.0),
+            Point::new(3.0, 1.0),
+            Point::new(3.0, 3.0),
+            Point::new(2.0, 3.0),
+            Point::new(2.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 3.0),
+            Point::new(0.0, 3.0),
+        ]);
+        let indices = comb.triangulate();
+        assert_eq!(indices.len(), 10);
+        assert_valid(&comb, &indices);
+
+        let triangles = comb.triangles();
+        assert_eq!(triangles.len(), 10);
+        let area: f64 = triangles.iter().map(|t| t.area()).sum();
+        assert!((area - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_collinear_vertices()
+    {
+        // Midpoints on every side; none of them may end up in a zero-area triangle.
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 1.0),
+            Point::new(2.0, 2.0),
+            Point::new(1.0, 2.0),
+            Point::new(0.0, 2.0),
+            Point::new(0.0, 1.0),
+        ]);
+        let indices = polygon.triangulate();
+        assert_eq!(indices.len(), 6);
+        assert_valid(&polygon, &indices);
+    }
+
+    #[test]
+    fn test_holes()
+    {
+        let polygon = Polygon::with_holes(square(0.0, 0.0, 4.0), vec![square(1.0, 1.0, 2.0)]);
+        let indices = polygon.triangulate();
+        assert_eq!(indices.len(), 8);
+        assert_valid(&polygon, &indices);
+        // Hole vertices are indexed after the exterior's.
+        assert!(indices.iter().flatten().any(|&i| i >= 4));
+        assert!(indices.iter().flatten().all(|&i| i < 8));
+
+        // Several holes, including one whose bridge has to pass another.
+        let polygon = Polygon::with_holes(
+            square(0.0, 0.0, 10.0),
+            vec![square(1.0, 4.0, 2.0), square(5.0, 4.0, 2.0), square(5.0, 1.0, 1.0), square(1.0, 7.0, 1.0)],
+        );
+        let indices = polygon.triangulate();
+        assert_eq!(indices.len(), 20 + 8 - 2);
+        assert_valid(&polygon, &indices);
+
+        // A hole sharing its rightmost x with an exterior vertex on the bridge ray.
+        let notched = Polygon::with_holes(
+            vec![Point::new(0.0, 0.0), Point::new(6.0, 0.0), Point::new(6.0, 6.0), Point::new(3.0, 3.0), Point::new(0.0, 6.0)],
+            vec![vec![Point::new(1.0, 2.0), Point::new(2.0, 3.0), Point::new(1.0, 4.0)]],
+        );
+        assert_valid(&notched, &notched.triangulate());
+    }
+
+    #[test]
+    fn test_constrained_delaunay()
+    {
+        // Points on a convex arc: clipping ears fans out into slivers that the Delaunay
+        // flips have to repair.
+        let arc: Vec<Point> = (0..12)
+            .map(|i| {
+                let angle = i as f64 * std::f64::consts::PI / 11.0;
+                Point::new(3.0 * angle.cos(), angle.sin())
+            })
+            .collect();
+        let polygon = Polygon::new(arc);
+        let indices = polygon.triangulate_delaunay();
+        assert_eq!(indices.len(), 10);
+        assert_valid(&polygon, &indices);
+        assert_locally_delaunay(&polygon, &indices);
+
+        // Constraints stay: the notch's edges remain even where flipping would help.
+        let polygon = Polygon::with_holes(
+            vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0), Point::new(2.0, 0.5), Point::new(0.0, 4.0)],
+            vec![vec![Point::new(0.5, 0.2), Point::new(0.8, 0.3), Point::new(0.6, 0.4)]],
+        );
+        let indices = polygon.triangulate_delaunay();
+        assert_eq!(indices.len(), 5 + 3);
+        assert_valid(&polygon, &indices);
+        let edge_kept = |a: usize, b: usize| indices.iter().any(|t| t.contains(&a) && t.contains(&b));
+        assert!(edge_kept(2, 3) && edge_kept(3, 4) && edge_kept(5, 6));
+        assert_eq!(polygon.delaunay_triangles().len(), indices.len());
+    }
+}
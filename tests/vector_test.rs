@@ -1,4 +1,9 @@
+mod common;
+
+use vectoria::angle::Degrees;
 use vectoria::vector::*;
+use vectoria::VectoriaError;
+use common::assert_vec3_close;
 #[cfg(test)]
 mod tests
 {
@@ -222,4 +227,332 @@ mod tests
         let result = v.reflect(&normal);
         assert_eq!(result, Vec4::new(1.0, 1.0, 0.0, 0.0));
     }
+
+    #[test]
+    fn test_vec3_dot_batch()
+    {
+        for len in 0..10
+        {
+            let a: Vec<Vec3> = (0..len).map(|i| Vec3::new(i as f32, 1.0, -2.0)).collect();
+            let b: Vec<Vec3> = (0..len).map(|i| Vec3::new(2.0, i as f32, 0.5)).collect();
+            let mut out = vec![0.0; len];
+            Vec3::dot_batch(&a, &b, &mut out).unwrap();
+            for i in 0..len
+            {
+                assert_eq!(out[i], a[i].dot(&b[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_vec3_normalize_batch()
+    {
+        for len in 0..10
+        {
+            let original: Vec<Vec3> = (0..len).map(|i| Vec3::new(i as f32, 2.0 * i as f32, 2.0)).collect();
+            let mut vectors = original.clone();
+            vectors.push(Vec3::new(0.0, 0.0, 0.0));
+            Vec3::normalize_batch(&mut vectors);
+            for i in 0..len
+            {
+                assert_eq!(vectors[i], original[i].normalize());
+            }
+            assert_eq!(vectors[len], Vec3::new(0.0, 0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_vec4_dot_batch()
+    {
+        for len in 0..10
+        {
+            let a: Vec<Vec4> = (0..len).map(|i| Vec4::new(i as f32, 1.0, -2.0, 3.0)).collect();
+            let b: Vec<Vec4> = (0..len).map(|i| Vec4::new(2.0, i as f32, 0.5, -1.0)).collect();
+            let mut out = vec![0.0; len];
+            Vec4::dot_batch(&a, &b, &mut out).unwrap();
+            for i in 0..len
+            {
+                assert_eq!(out[i], a[i].dot(&b[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_vec4_normalize_batch()
+    {
+        for len in 0..10
+        {
+            let original: Vec<Vec4> = (0..len).map(|i| Vec4::new(i as f32, 1.0, 2.0, 2.0)).collect();
+            let mut vectors = original.clone();
+            vectors.insert(0, Vec4::new(0.0, 0.0, 0.0, 0.0));
+            Vec4::normalize_batch(&mut vectors);
+            assert_eq!(vectors[0], Vec4::new(0.0, 0.0, 0.0, 0.0));
+            for i in 0..len
+            {
+                let expected = original[i].unit();
+                assert!((vectors[i + 1].x - expected.x).abs() < 1e-6);
+                assert!((vectors[i + 1].y - expected.y).abs() < 1e-6);
+                assert!((vectors[i + 1].z - expected.z).abs() < 1e-6);
+                assert!((vectors[i + 1].w - expected.w).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_swizzles()
+    {
+        let v2 = Vec2::new(1.0, 2.0);
+        assert_eq!(v2.yx(), Vec2::new(2.0, 1.0));
+        assert_eq!(v2.xxyy(), Vec4::new(1.0, 1.0, 2.0, 2.0));
+
+        let v3 = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v3.zyx(), Vec3::new(3.0, 2.0, 1.0));
+        assert_eq!(v3.xz(), Vec2::new(1.0, 3.0));
+        assert_eq!(v3.zzzx(), Vec4::new(3.0, 3.0, 3.0, 1.0));
+
+        let v4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v4.wzyx(), Vec4::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(v4.xyz(), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v4.ww(), Vec2::new(4.0, 4.0));
+    }
+
+    #[test]
+    fn test_with_components()
+    {
+        assert_eq!(Vec2::new(1.0, 2.0).with_y(5.0), Vec2::new(1.0, 5.0));
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0).with_x(0.0), Vec3::new(0.0, 2.0, 3.0));
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0).with_w(9.0), Vec4::new(1.0, 2.0, 3.0, 9.0));
+    }
+
+    #[test]
+    fn test_extend_truncate()
+    {
+        let v2 = Vec2::new(1.0, 2.0);
+        let v3 = v2.extend(3.0);
+        let v4 = v3.extend(4.0);
+        assert_eq!(v3, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v4, Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v4.truncate(), v3);
+        assert_eq!(v3.truncate(), v2);
+    }
+
+    #[test]
+    fn test_componentwise_min_max_clamp()
+    {
+        let a = Vec3::new(1.0, 5.0, -2.0);
+        let b = Vec3::new(3.0, 2.0, -4.0);
+        assert_eq!(a.min(&b), Vec3::new(1.0, 2.0, -4.0));
+        assert_eq!(a.max(&b), Vec3::new(3.0, 5.0, -2.0));
+        let lo = Vec3::new(0.0, 0.0, 0.0);
+        let hi = Vec3::new(2.0, 2.0, 2.0);
+        assert_eq!(a.clamp(&lo, &hi), Vec3::new(1.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_componentwise_rounding()
+    {
+        let v = Vec4::new(-1.5, 2.25, 0.5, -0.0);
+        assert_eq!(v.abs(), Vec4::new(1.5, 2.25, 0.5, 0.0));
+        assert_eq!(v.floor(), Vec4::new(-2.0, 2.0, 0.0, -0.0));
+        assert_eq!(v.ceil(), Vec4::new(-1.0, 3.0, 1.0, -0.0));
+        assert_eq!(v.round(), Vec4::new(-2.0, 2.0, 1.0, -0.0));
+        assert_eq!(v.fract(), Vec4::new(0.5, 0.25, 0.5, 0.0));
+        assert_eq!(Vec2::new(-3.0, 4.0).signum(), Vec2::new(-1.0, 1.0));
+        assert_eq!(Vec2::new(2.0, 4.0).recip(), Vec2::new(0.5, 0.25));
+    }
+
+    #[test]
+    fn test_componentwise_arithmetic()
+    {
+        let v = Vec2::new(2.0, 3.0);
+        assert_eq!(v.mul_add(&Vec2::new(2.0, 2.0), &Vec2::new(1.0, -1.0)), Vec2::new(5.0, 5.0));
+        assert_eq!(v.powf(2.0), Vec2::new(4.0, 9.0));
+        let e = Vec2::new(0.0, 1.0).exp();
+        assert_eq!(e.x, 1.0);
+        assert!((e.y - std::f32::consts::E).abs() < 1e-6);
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).lerp(&Vec3::new(2.0, 4.0, 6.0), 0.5), Vec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn test_step_smoothstep()
+    {
+        let v = Vec3::new(0.0, 0.5, 1.0);
+        assert_eq!(v.step(&Vec3::new(0.5, 0.5, 0.5)), Vec3::new(0.0, 1.0, 1.0));
+        let s = v.smoothstep(&Vec3::new(0.0, 0.0, 0.0), &Vec3::new(1.0, 1.0, 1.0));
+        assert_eq!(s, Vec3::new(0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_reductions()
+    {
+        let v = Vec4::new(2.0, -1.0, 4.0, 0.5);
+        assert_eq!(v.min_element(), -1.0);
+        assert_eq!(v.max_element(), 4.0);
+        assert_eq!(v.sum(), 5.5);
+        assert_eq!(v.product(), -4.0);
+        assert!(v.is_finite());
+        assert!(!v.is_nan());
+        let bad = Vec3::new(1.0, f32::NAN, 0.0);
+        assert!(bad.is_nan());
+        assert!(!bad.is_finite());
+        assert!(!Vec2::new(f32::INFINITY, 0.0).is_finite());
+    }
+
+    #[test]
+    fn test_vec3_slerp()
+    {
+        let a = Vec3::new(1.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 2.0, 0.0);
+        let half = std::f32::consts::FRAC_1_SQRT_2 * 1.5;
+        assert_vec3_close(&a.slerp(&b, 0.5), &Vec3::new(half, half, 0.0));
+        assert_vec3_close(&a.slerp(&b, 0.0), &a);
+        assert_vec3_close(&a.slerp(&b, 1.0), &b);
+
+        let opposite = a.slerp(&Vec3::new(-1.0, 0.0, 0.0), 0.5);
+        assert!((opposite.length() - 1.0).abs() < 1e-5);
+        assert!(opposite.dot(&a).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_vec2_slerp_nlerp()
+    {
+        let a = Vec2::new(1.0, 0.0);
+        let b = Vec2::new(-1.0, 0.0);
+        let mid = a.slerp(&b, 0.5);
+        assert!((mid.x).abs() < 1e-5 && (mid.y - 1.0).abs() < 1e-5);
+        let n = a.nlerp(&Vec2::new(0.0, 1.0), 0.5);
+        assert!((n.length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_vec4_slerp_antiparallel()
+    {
+        let a = Vec4::new(0.0, 0.0, 1.0, 0.0);
+        let mid = a.slerp(&Vec4::new(0.0, 0.0, -1.0, 0.0), 0.5);
+        assert!((mid.length() - 1.0).abs() < 1e-5);
+        assert!(mid.dot(&a).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_move_towards()
+    {
+        let a = Vec3::new(0.0, 0.0, 0.0);
+        let b = Vec3::new(10.0, 0.0, 0.0);
+        assert_eq!(a.move_towards(&b, 3.0), Vec3::new(3.0, 0.0, 0.0));
+        assert_eq!(a.move_towards(&b, 30.0), b);
+        assert_eq!(Vec2::new(1.0, 1.0).move_towards(&Vec2::new(1.0, 4.0), 1.0), Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_rotate_towards()
+    {
+        let a = Vec3::new(2.0, 0.0, 0.0);
+        let b = Vec3::new(0.0, 5.0, 0.0);
+        let step = a.rotate_towards(&b, std::f32::consts::FRAC_PI_4);
+        let c = std::f32::consts::FRAC_1_SQRT_2 * 2.0;
+        assert_vec3_close(&step, &Vec3::new(c, c, 0.0));
+        assert_vec3_close(&a.rotate_towards(&b, 10.0), &Vec3::new(0.0, 2.0, 0.0));
+        assert_vec3_close(&a.rotate_towards(&b, Degrees(45.0)), &Vec3::new(c, c, 0.0));
+        let turned = Vec2::new(2.0, 0.0).rotate_towards(&Vec2::new(0.0, 5.0), Degrees(45.0));
+        assert!((turned.x - c).abs() < 1e-5 && (turned.y - c).abs() < 1e-5);
+        let turned = Vec4::new(2.0, 0.0, 0.0, 0.0).rotate_towards(&Vec4::new(0.0, 5.0, 0.0, 0.0), Degrees(45.0));
+        assert!((turned.x - c).abs() < 1e-5 && (turned.y - c).abs() < 1e-5 && turned.z == 0.0 && turned.w == 0.0);
+    }
+
+    #[test]
+    fn test_vec2_rotate_perp()
+    {
+        let v = Vec2::new(1.0, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+        assert!(v.x.abs() < 1e-6 && (v.y - 1.0).abs() < 1e-6);
+        assert_eq!(Vec2::new(2.0, 3.0).perp(), Vec2::new(-3.0, 2.0));
+        assert_eq!(Vec2::new(1.0, 0.0).perp_dot(&Vec2::new(0.0, 1.0)), 1.0);
+        assert_eq!(Vec2::new(0.0, 1.0).perp_dot(&Vec2::new(1.0, 0.0)), -1.0);
+    }
+
+    #[test]
+    fn test_rotate_around_axis()
+    {
+        let v = Vec3::new(1.0, 0.0, 0.0);
+        let rotated = v.rotate_around_axis(&Vec3::new(0.0, 0.0, 2.0), std::f32::consts::FRAC_PI_2);
+        assert_vec3_close(&rotated, &Vec3::new(0.0, 1.0, 0.0));
+        let on_axis = Vec3::new(0.0, 0.0, 3.0).rotate_around_axis(&Vec3::new(0.0, 0.0, 1.0), 1.0);
+        assert_vec3_close(&on_axis, &Vec3::new(0.0, 0.0, 3.0));
+    }
+
+    #[test]
+    fn test_any_orthonormal_pair()
+    {
+        let normals = [
+            Vec3::new(0.0, 0.0, 1.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 2.0, 3.0).normalize(),
+            Vec3::new(-0.3, 0.9, -0.1).normalize(),
+        ];
+        for n in normals.iter()
+        {
+            let (b1, b2) = n.any_orthonormal_pair();
+            assert!((b1.length() - 1.0).abs() < 1e-5);
+            assert!((b2.length() - 1.0).abs() < 1e-5);
+            assert!(b1.dot(n).abs() < 1e-5);
+            assert!(b2.dot(n).abs() < 1e-5);
+            assert!(b1.dot(&b2).abs() < 1e-5);
+            assert_vec3_close(&b1.cross(&b2), n);
+        }
+    }
+
+    #[test]
+    fn test_try_normalize()
+    {
+        assert_eq!(Vec3::new(0.0, 3.0, 4.0).try_normalize(), Some(Vec3::new(0.0, 0.6, 0.8)));
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec2::new(f32::NAN, 1.0).try_normalize(), None);
+        assert_eq!(Vec4::new(1e-30, 0.0, 0.0, 0.0).try_normalize(), None);
+        let fallback = Vec3::new(0.0, 1.0, 0.0);
+        assert_eq!(Vec3::new(0.0, 0.0, 0.0).normalize_or(fallback), fallback);
+        assert_eq!(Vec2::new(0.0, -2.0).normalize_or(Vec2::new(1.0, 0.0)), Vec2::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn test_length_squared_is_normalized()
+    {
+        assert_eq!(Vec4::new(1.0, 2.0, 2.0, 4.0).length_squared(), 25.0);
+        assert!(Vec3::new(1.0, 2.0, 3.0).normalize().is_normalized());
+        assert!(!Vec2::new(1.0, 1.0).is_normalized());
+    }
+
+    #[test]
+    fn test_angle_edge_cases()
+    {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert!(v.angle(&v.mul_scale(3.0)) < 1e-3);
+        assert!((v.angle(&v.mul_scale(-1.0)) - std::f32::consts::PI).abs() < 1e-3);
+        assert_eq!(v.angle(&Vec3::new(0.0, 0.0, 0.0)), 0.0);
+        assert_eq!(Vec2::new(0.0, 0.0).angle(&Vec2::new(1.0, 0.0)), 0.0);
+        assert!(!Vec4::new(0.1, 0.2, 0.3, 0.4).angle(&Vec4::new(0.1, 0.2, 0.3, 0.4)).is_nan());
+    }
+
+    #[test]
+    fn test_project_onto_zero()
+    {
+        assert_eq!(Vec3::new(1.0, 2.0, 3.0).project(&Vec3::new(0.0, 0.0, 0.0)), Vec3::new(0.0, 0.0, 0.0));
+        assert_eq!(Vec2::new(1.0, 2.0).project(&Vec2::new(0.0, 0.0)), Vec2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_dot_batch_mismatch()
+    {
+        let a = [Vec3::new(1.0, 0.0, 0.0); 3];
+        let b = [Vec3::new(1.0, 0.0, 0.0); 2];
+        let mut out = [0.0; 3];
+        assert_eq!(Vec3::dot_batch(&a, &b, &mut out), Err(VectoriaError::DimensionMismatch { expected: 3, found: 2 }));
+    }
+
+    #[test]
+    fn test_orthonormal_basis()
+    {
+        let (t, b, n) = Vec3::new(0.0, 0.0, -5.0).orthonormal_basis();
+        assert_vec3_close(&n, &Vec3::new(0.0, 0.0, -1.0));
+        assert_vec3_close(&t.cross(&b), &n);
+        assert!(t.dot(&b).abs() < 1e-6);
+    }
 }